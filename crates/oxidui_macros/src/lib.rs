@@ -1,18 +1,60 @@
+//! Procedural macros for OxidUi.
+//!
+//! - [`rsx!`] — declare an element tree with JSX-like syntax.
+//! - [`scss!`] — declare a `const`-evaluable `Style` with CSS-like syntax.
+//!
+//! The macros emit absolute `::oxidui::…` paths, so they are meant to be
+//! used through the `oxidui` crate, which re-exports them.
+
 use proc_macro::TokenStream;
 
+mod rsx;
+mod scss;
+
+/// Declare an element tree with JSX-like syntax.
+///
+/// ```rust,ignore
+/// let ui = rsx! {
+///     <Box id="root" style={panel}>
+///         <Text>"hello"</Text>
+///         {footer}
+///     </Box>
+/// };
+/// ```
+///
+/// - `<Tag …>…</Tag>` and `<Tag … />` become `Element::new("Tag")`.
+/// - `name="lit"` / `name={expr}` become `.with_name(value)` calls.
+/// - String literals become text children; `{expr}` children are passed to
+///   `.with_child(expr)` as-is.
+///
+/// Unbalanced or mismatched closing tags are compile errors pointing at
+/// the offending tag.
 #[proc_macro]
 pub fn rsx(input: TokenStream) -> TokenStream {
-    // For now just return the input unchanged
-    input
+    rsx::expand(input.into()).into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::rsx;
-
-    #[test]
-    fn dummy_test() {
-        let result = rsx(proc_macro::TokenStream::new());
-        assert_eq!(result.to_string(), "");
-    }
+/// Declare a [`Style`] with CSS-like syntax.
+///
+/// ```rust,ignore
+/// const PANEL: Style = scss! {
+///     width: 50%;
+///     padding: 0 1;
+///     flex-direction: column;
+///     border: rounded cyan;
+///     font-style: bold italic;
+/// };
+/// ```
+///
+/// Values use the same grammar as runtime stylesheets
+/// (`oxidui_style::declaration`). Only declared fields are `Some`; the
+/// expansion is a plain struct literal, so it is usable in `const` items.
+///
+/// Unknown properties and malformed values are compile errors pointing at
+/// the offending token.
+///
+/// [`Style`]: oxidui_style::Style
+#[proc_macro]
+pub fn scss(input: TokenStream) -> TokenStream {
+    scss::expand(input.into()).into()
 }
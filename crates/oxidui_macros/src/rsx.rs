@@ -0,0 +1,329 @@
+//! `rsx!` — JSX-like element trees.
+//!
+//! The input is parsed into a small typed tree ([`Node`] / [`Element`] /
+//! [`Attr`]) and then lowered into builder calls on `::oxidui::element::Element`:
+//!
+//! ```text
+//! <Box id="root" style={s}>            ::oxidui::element::Element::new("Box")
+//!     <Text>"hi"</Text>          →         .with_id("root")
+//! </Box>                                   .with_style(s)
+//!                                          .with_child(
+//!                                              ::oxidui::element::Element::new("Text")
+//!                                                  .with_child("hi"))
+//! ```
+//!
+//! Attributes map to `with_<name>` builder methods, so an unknown attribute
+//! is reported by the compiler as a missing method at the attribute's span.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, Lit, LitStr, Token, braced};
+
+/// One child of an element, or the macro root.
+pub enum Node {
+    /// `<Tag …>…</Tag>` or `<Tag … />`.
+    Element(Element),
+    /// A string literal — becomes a text node.
+    Text(LitStr),
+    /// `{expr}` — any value convertible into a node.
+    Expr(Expr),
+}
+
+/// A parsed `<Tag attr=value …>children</Tag>` element.
+pub struct Element {
+    pub tag: Ident,
+    pub attrs: Vec<Attr>,
+    pub children: Vec<Node>,
+}
+
+/// A single `name=value` attribute.
+pub struct Attr {
+    pub name: Ident,
+    pub value: AttrValue,
+}
+
+/// Right-hand side of an attribute.
+pub enum AttrValue {
+    /// `name="text"`, `name=3`, … — any Rust literal.
+    Lit(Lit),
+    /// `name={expr}` — an arbitrary braced expression.
+    Expr(Expr),
+}
+
+/// The whole macro input: exactly one root element.
+pub struct Root(pub Element);
+
+impl Parse for Root {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("rsx! expects a root element, e.g. `<Box></Box>`"));
+        }
+        if !input.peek(Token![<]) {
+            return Err(input.error("rsx! root must be an element"));
+        }
+        if input.peek2(Token![/]) {
+            return Err(unexpected_closing_tag(input)?);
+        }
+        let root = input.parse::<Element>()?;
+        if !input.is_empty() {
+            if input.peek(Token![<]) && input.peek2(Token![/]) {
+                return Err(unexpected_closing_tag(input)?);
+            }
+            return Err(input.error("rsx! expects a single root element"));
+        }
+        Ok(Root(root))
+    }
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![<]>()?;
+        let tag = input.call(Ident::parse_any)?;
+
+        let mut attrs = Vec::new();
+        while !(input.peek(Token![>]) || input.peek(Token![/])) {
+            if input.is_empty() {
+                return Err(syn::Error::new(
+                    tag.span(),
+                    format!("unterminated opening tag `<{tag}`"),
+                ));
+            }
+            attrs.push(input.parse()?);
+        }
+
+        // Self-closing `<Tag />`.
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(Self {
+                tag,
+                attrs,
+                children: Vec::new(),
+            });
+        }
+        input.parse::<Token![>]>()?;
+
+        let mut children = Vec::new();
+        loop {
+            if input.is_empty() {
+                return Err(syn::Error::new(
+                    tag.span(),
+                    format!("unclosed element `<{tag}>`: expected `</{tag}>`"),
+                ));
+            }
+            if input.peek(Token![<]) && input.peek2(Token![/]) {
+                break;
+            }
+            children.push(input.parse()?);
+        }
+
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let close = input.call(Ident::parse_any)?;
+        if close != tag {
+            let mut err = syn::Error::new(
+                close.span(),
+                format!("mismatched closing tag: expected `</{tag}>`, found `</{close}>`"),
+            );
+            err.combine(syn::Error::new(
+                tag.span(),
+                format!("`<{tag}>` opened here"),
+            ));
+            return Err(err);
+        }
+        input.parse::<Token![>]>()?;
+
+        Ok(Self {
+            tag,
+            attrs,
+            children,
+        })
+    }
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![<]) {
+            input.parse().map(Node::Element)
+        } else if input.peek(LitStr) {
+            input.parse().map(Node::Text)
+        } else if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            content.parse().map(Node::Expr)
+        } else {
+            Err(input.error("expected an element, a string literal or a `{expression}`"))
+        }
+    }
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.call(Ident::parse_any)?;
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            AttrValue::Expr(content.parse()?)
+        } else if input.peek(Lit) {
+            AttrValue::Lit(input.parse()?)
+        } else {
+            return Err(input.error(format!(
+                "expected a literal or `{{expression}}` as the value of `{name}`"
+            )));
+        };
+        Ok(Self { name, value })
+    }
+}
+
+/// Build the error for a stray `</Tag>` that has no matching opener.
+fn unexpected_closing_tag(input: ParseStream) -> syn::Result<syn::Error> {
+    input.parse::<Token![<]>()?;
+    input.parse::<Token![/]>()?;
+    let close = input.call(Ident::parse_any)?;
+    Ok(syn::Error::new(
+        close.span(),
+        format!("unexpected closing tag `</{close}>` with no matching opening tag"),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Lowering
+// ---------------------------------------------------------------------------
+
+impl Element {
+    fn to_tokens(&self) -> TokenStream {
+        let tag = self.tag.unraw().to_string();
+        let span = self.tag.span();
+        let mut out = quote_spanned! {span=>
+            ::oxidui::element::Element::new(#tag)
+        };
+        for attr in &self.attrs {
+            let method = format_ident!("with_{}", attr.name.unraw(), span = attr.name.span());
+            let value = match &attr.value {
+                AttrValue::Lit(lit) => quote!(#lit),
+                AttrValue::Expr(expr) => quote!(#expr),
+            };
+            out = quote!(#out.#method(#value));
+        }
+        for child in &self.children {
+            let child = child.to_tokens();
+            out = quote!(#out.with_child(#child));
+        }
+        out
+    }
+}
+
+impl Node {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Node::Element(el) => el.to_tokens(),
+            Node::Text(lit) => quote!(#lit),
+            Node::Expr(expr) => quote!(#expr),
+        }
+    }
+}
+
+/// Parse and lower an `rsx!` invocation. Errors come back as `compile_error!`.
+pub fn expand(input: TokenStream) -> TokenStream {
+    match syn::parse2::<Root>(input) {
+        Ok(Root(root)) => root.to_tokens(),
+        Err(err) => {
+            // Wrapped in a block: a combined error is several
+            // `compile_error!` invocations, which is not an expression.
+            let err = err.to_compile_error();
+            quote!({ #err })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use quote::quote;
+
+    fn expanded(input: proc_macro2::TokenStream) -> String {
+        expand(input).to_string()
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(
+            expanded(quote!(<Box></Box>)),
+            quote!(::oxidui::element::Element::new("Box")).to_string()
+        );
+    }
+
+    #[test]
+    fn self_closing_element() {
+        assert_eq!(
+            expanded(quote!(<Spacer />)),
+            quote!(::oxidui::element::Element::new("Spacer")).to_string()
+        );
+    }
+
+    #[test]
+    fn attributes_become_builder_calls() {
+        assert_eq!(
+            expanded(quote!(<Box id="root" style={s}></Box>)),
+            quote!(
+                ::oxidui::element::Element::new("Box")
+                    .with_id("root")
+                    .with_style(s)
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn children_and_text() {
+        assert_eq!(
+            expanded(quote!(<Box><Text>"hi"</Text>{extra}</Box>)),
+            quote!(
+                ::oxidui::element::Element::new("Box")
+                    .with_child(::oxidui::element::Element::new("Text").with_child("hi"))
+                    .with_child(extra)
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn keyword_attribute_names() {
+        assert_eq!(
+            expanded(quote!(<Input type="text" />)),
+            quote!(::oxidui::element::Element::new("Input").with_type("text")).to_string()
+        );
+    }
+
+    #[test]
+    fn mismatched_closing_tag() {
+        let out = expanded(quote!(<Box><Text>"hi"</Box></Text>));
+        assert!(out.contains("mismatched closing tag: expected `</Text>`, found `</Box>`"));
+        assert!(out.contains("`<Text>` opened here"));
+    }
+
+    #[test]
+    fn unclosed_element() {
+        let out = expanded(quote!(<Box><Text>"hi"</Text>));
+        assert!(out.contains("unclosed element `<Box>`"));
+    }
+
+    #[test]
+    fn stray_closing_tag() {
+        let out = expanded(quote!(<Box></Box></Box>));
+        assert!(out.contains("unexpected closing tag `</Box>`"));
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(expanded(quote!()).contains("rsx! expects a root element"));
+    }
+
+    #[test]
+    fn multiple_roots() {
+        assert!(expanded(quote!(<A></A><B></B>)).contains("single root element"));
+    }
+}
@@ -0,0 +1,634 @@
+//! `scss!` — compile-time style declarations.
+//!
+//! The input is a list of `property: value;` declarations. Each value is
+//! turned back into text and run through [`oxidui_style::declaration`],
+//! the same grammar the runtime stylesheet parser uses, so the two
+//! front-ends accept exactly the same language. The resulting
+//! [`Style`] is then emitted as a `const`-evaluable struct literal:
+//!
+//! ```text
+//! scss! { color: red; padding: 1 2; }
+//!     → ::oxidui::style::Style {
+//!           color:   Some(Color::Named(NamedColor::Red)),
+//!           padding: Some(Edges::new(Unit::Cells(1), Unit::Cells(2), …)),
+//!           ..::oxidui::style::Style::new()
+//!       }
+//! ```
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use oxidui_style::Style;
+use oxidui_style::border::{Border, BorderCollapse, BorderLabel, BorderStyle, Edges};
+use oxidui_style::color::{Color, NamedColor};
+use oxidui_style::declaration::{self, DeclarationErrorKind};
+use oxidui_style::font::FontStyle;
+use oxidui_style::grid::{GridAreas, GridPlacement, TrackList};
+use oxidui_style::layout::{
+    Align, Display, FlexDirection, Justify, LineClamp, Overflow, Position, ScrollbarPart,
+    TextAlign, TextOverflow, WhiteSpace, WordBreak, ZIndex,
+};
+use oxidui_style::number::{Float, Int};
+use oxidui_style::str::Str;
+use oxidui_style::unit::Unit;
+use proc_macro2::{Delimiter, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+
+/// Value text rebuilt from tokens, with the span of every piece.
+#[derive(Default)]
+struct SourceText {
+    text: String,
+    spans: Vec<(Range<usize>, Span)>,
+}
+
+impl SourceText {
+    fn push(&mut self, s: &str, span: Span) {
+        let start = self.text.len();
+        self.text.push_str(s);
+        self.spans.push((start..self.text.len(), span));
+    }
+
+    /// Span of the first token overlapping `range`; `fallback` if none.
+    fn span_of(&self, range: &Range<usize>, fallback: Span) -> Span {
+        self.spans
+            .iter()
+            .find(|(r, _)| r.start <= range.start && range.start < r.end.max(r.start + 1))
+            .map_or(fallback, |(_, span)| *span)
+    }
+
+    /// Append `tokens`, re-inserting the whitespace CSS needs.
+    ///
+    /// Token streams carry no whitespace, so it is reconstructed: pieces
+    /// are separated by a space except where CSS syntax glues them
+    /// (`50%`, `#ff0000`, `row-reverse`, `-1`, `rgb(…)`, `a, b`).
+    fn extend(&mut self, tokens: &[TokenTree]) {
+        for (i, tt) in tokens.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|p| &tokens[p]);
+            let next = tokens.get(i + 1);
+            if prev.is_some_and(|p| needs_space(p, tt, next)) {
+                self.text.push(' ');
+            }
+            match tt {
+                TokenTree::Group(g) => {
+                    let (open, close) = match g.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::None => ("", ""),
+                    };
+                    let start = self.text.len();
+                    self.text.push_str(open);
+                    let inner: Vec<_> = g.stream().into_iter().collect();
+                    self.extend(&inner);
+                    self.text.push_str(close);
+                    self.spans.push((start..self.text.len(), g.span()));
+                }
+                other => self.push(&other.to_string(), other.span()),
+            }
+        }
+    }
+}
+
+fn is_punct(tt: &TokenTree, ch: char) -> bool {
+    matches!(tt, TokenTree::Punct(p) if p.as_char() == ch)
+}
+
+fn needs_space(prev: &TokenTree, cur: &TokenTree, next: Option<&TokenTree>) -> bool {
+    // `#ff0000`, `-1`, `row-reverse`
+    if is_punct(prev, '#') || is_punct(prev, '-') {
+        return false;
+    }
+    // `50%`, `a, b`
+    if is_punct(cur, '%') || is_punct(cur, ',') {
+        return false;
+    }
+    // Kebab-case keyword: a `-` between two identifiers.
+    if is_punct(cur, '-')
+        && matches!(prev, TokenTree::Ident(_))
+        && matches!(next, Some(TokenTree::Ident(_)))
+    {
+        return false;
+    }
+    // Functional notation: `rgb(…)`.
+    if matches!(prev, TokenTree::Ident(_))
+        && matches!(cur, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis)
+    {
+        return false;
+    }
+    true
+}
+
+/// One `property: value` pair as written in the macro input.
+struct Declaration {
+    property: String,
+    property_span: Span,
+    value: SourceText,
+    colon_span: Span,
+}
+
+/// Split the input on top-level `;` and parse each `name: value` pair.
+fn declarations(input: TokenStream) -> syn::Result<Vec<Declaration>> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut out = Vec::new();
+    for decl in tokens.split(|tt| is_punct(tt, ';')) {
+        if decl.is_empty() {
+            continue;
+        }
+        let Some(colon) = decl.iter().position(|tt| is_punct(tt, ':')) else {
+            return Err(syn::Error::new(
+                decl[0].span(),
+                "expected `property: value;`",
+            ));
+        };
+        let (name, value) = (&decl[..colon], &decl[colon + 1..]);
+
+        let mut property = String::new();
+        for (i, tt) in name.iter().enumerate() {
+            let ok = match tt {
+                TokenTree::Ident(id) if i % 2 == 0 => {
+                    property.push_str(&id.to_string());
+                    true
+                }
+                TokenTree::Punct(p) if i % 2 == 1 && p.as_char() == '-' => {
+                    property.push('-');
+                    true
+                }
+                _ => false,
+            };
+            if !ok {
+                return Err(syn::Error::new(tt.span(), "expected a property name"));
+            }
+        }
+        if property.is_empty() || property.ends_with('-') {
+            let span = name.last().map_or(decl[colon].span(), TokenTree::span);
+            return Err(syn::Error::new(span, "expected a property name"));
+        }
+
+        let mut text = SourceText::default();
+        text.extend(value);
+        out.push(Declaration {
+            property,
+            property_span: name[0].span(),
+            value: text,
+            colon_span: decl[colon].span(),
+        });
+    }
+    Ok(out)
+}
+
+/// Parse and lower an `scss!` invocation. Errors come back as `compile_error!`.
+pub fn expand(input: TokenStream) -> TokenStream {
+    match build(input) {
+        Ok(style) => style_tokens(&style),
+        Err(err) => {
+            // Wrapped in a block: a combined error is several
+            // `compile_error!` invocations, which is not an expression.
+            let err = err.to_compile_error();
+            quote!({ #err })
+        }
+    }
+}
+
+fn build(input: TokenStream) -> syn::Result<Style> {
+    let mut style = Style::new();
+    for decl in declarations(input)? {
+        if let Err(err) = declaration::apply(&mut style, &decl.property, &decl.value.text) {
+            let span = match err.kind {
+                DeclarationErrorKind::UnknownProperty(_) => decl.property_span,
+                DeclarationErrorKind::MissingValue => decl.colon_span,
+                _ => decl.value.span_of(&err.range, decl.colon_span),
+            };
+            return Err(syn::Error::new(span, format!("`{}`: {err}", decl.property)));
+        }
+    }
+    Ok(style)
+}
+
+// ---------------------------------------------------------------------------
+// Emission
+// ---------------------------------------------------------------------------
+
+/// Emit a value as a `const`-evaluable expression.
+trait Emit {
+    fn emit(&self) -> TokenStream;
+}
+
+/// Emit a fieldless enum variant as `path::Variant`.
+fn variant<T: Debug>(path: TokenStream, v: &T) -> TokenStream {
+    let name = format_ident!("{}", format!("{v:?}"));
+    quote!(#path::#name)
+}
+
+impl Emit for Unit {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::unit::Unit);
+        match *self {
+            Unit::Cells(n) => quote!(#path::Cells(#n)),
+            Unit::Percent(n) => quote!(#path::Percent(#n)),
+            Unit::Fill(w) => quote!(#path::Fill(#w)),
+            Unit::Auto => quote!(#path::Auto),
+            Unit::Unset => quote!(#path::Unset),
+        }
+    }
+}
+
+impl<T: Emit + Copy> Emit for Edges<T> {
+    fn emit(&self) -> TokenStream {
+        let (t, r, b, l) = (
+            self.top.emit(),
+            self.right.emit(),
+            self.bottom.emit(),
+            self.left.emit(),
+        );
+        quote!(::oxidui::style::border::Edges::new(#t, #r, #b, #l))
+    }
+}
+
+impl Emit for Color {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::color::Color);
+        match *self {
+            Color::Named(n) => {
+                let n = n.emit();
+                quote!(#path::Named(#n))
+            }
+            Color::Rgb(r, g, b) => quote!(#path::Rgb(#r, #g, #b)),
+            Color::Indexed(i) => quote!(#path::Indexed(#i)),
+            Color::Inherit => quote!(#path::Inherit),
+            Color::None => quote!(#path::None),
+        }
+    }
+}
+
+impl Emit for NamedColor {
+    fn emit(&self) -> TokenStream {
+        variant(quote!(::oxidui::style::color::NamedColor), self)
+    }
+}
+
+impl Emit for Float {
+    fn emit(&self) -> TokenStream {
+        let v = Literal::f32_suffixed(self.get());
+        quote!(::oxidui::style::number::Float(#v))
+    }
+}
+
+impl Emit for Int {
+    fn emit(&self) -> TokenStream {
+        let v = self.get();
+        quote!(::oxidui::style::number::Int(#v))
+    }
+}
+
+impl Emit for char {
+    fn emit(&self) -> TokenStream {
+        quote!(#self)
+    }
+}
+
+impl Emit for ScrollbarPart {
+    fn emit(&self) -> TokenStream {
+        let glyph = self.glyph.emit();
+        let color = self.color.emit();
+        quote!(::oxidui::style::layout::ScrollbarPart { glyph: #glyph, color: #color })
+    }
+}
+
+impl Emit for Border {
+    fn emit(&self) -> TokenStream {
+        let style = self.style.emit();
+        let color = self.color.emit();
+        quote!(::oxidui::style::border::Border { style: #style, color: #color })
+    }
+}
+
+impl Emit for Str {
+    fn emit(&self) -> TokenStream {
+        let s = self.as_str();
+        quote!(::oxidui::style::str::Str::from_static(#s))
+    }
+}
+
+impl Emit for BorderLabel {
+    fn emit(&self) -> TokenStream {
+        let text = self.text.emit();
+        let align = self.align.emit();
+        let color = self.color.emit();
+        let font_style = self.font_style.emit();
+        quote!(::oxidui::style::border::BorderLabel {
+            text: #text,
+            align: #align,
+            color: #color,
+            font_style: #font_style,
+        })
+    }
+}
+
+impl Emit for TextOverflow {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::layout::TextOverflow);
+        match self {
+            TextOverflow::Marker(marker) => {
+                let marker = marker.emit();
+                quote!(#path::Marker(#marker))
+            }
+            other => variant(path, other),
+        }
+    }
+}
+
+impl Emit for LineClamp {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::layout::LineClamp);
+        match self {
+            LineClamp::Lines(n) => {
+                let n = n.emit();
+                quote!(#path::Lines(#n))
+            }
+            LineClamp::None => quote!(#path::None),
+        }
+    }
+}
+
+impl Emit for ZIndex {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::layout::ZIndex);
+        match self {
+            ZIndex::Index(n) => {
+                let n = n.emit();
+                quote!(#path::Index(#n))
+            }
+            ZIndex::Auto => quote!(#path::Auto),
+        }
+    }
+}
+
+impl Emit for TrackList {
+    fn emit(&self) -> TokenStream {
+        let tracks = self.as_slice().iter().map(Emit::emit);
+        quote!(::oxidui::style::grid::TrackList::EMPTY #(.with(#tracks))*)
+    }
+}
+
+impl Emit for GridPlacement {
+    fn emit(&self) -> TokenStream {
+        let span = self.span;
+        let start = match self.start {
+            Some(start) => quote!(::core::option::Option::Some(#start)),
+            None => quote!(::core::option::Option::None),
+        };
+        quote!(::oxidui::style::grid::GridPlacement { start: #start, span: #span })
+    }
+}
+
+impl Emit for GridAreas {
+    fn emit(&self) -> TokenStream {
+        let template = self.as_str();
+        quote!(::oxidui::style::grid::GridAreas::from_normalized(#template))
+    }
+}
+
+impl Emit for BorderStyle {
+    fn emit(&self) -> TokenStream {
+        let path = quote!(::oxidui::style::border::BorderStyle);
+        match self {
+            // Registered sets only exist at run time, so the grammar can't
+            // produce one here.
+            BorderStyle::Custom(_) => {
+                quote!(::core::compile_error!(
+                    "custom border glyph sets need a runtime stylesheet"
+                ))
+            }
+            other => variant(path, other),
+        }
+    }
+}
+
+impl Emit for FontStyle {
+    fn emit(&self) -> TokenStream {
+        let bits = self.0;
+        quote!(::oxidui::style::font::FontStyle(#bits))
+    }
+}
+
+impl<T: Emit> Emit for Option<T> {
+    fn emit(&self) -> TokenStream {
+        match self {
+            Some(v) => {
+                let v = v.emit();
+                quote!(::core::option::Option::Some(#v))
+            }
+            None => quote!(::core::option::Option::None),
+        }
+    }
+}
+
+macro_rules! emit_keyword {
+    ($($ty:ident => $($path:tt)::+),+ $(,)?) => {
+        $(impl Emit for $ty {
+            fn emit(&self) -> TokenStream {
+                variant(quote!($(::$path)+), self)
+            }
+        })+
+    };
+}
+
+emit_keyword! {
+    Display => oxidui::style::layout::Display,
+    FlexDirection => oxidui::style::layout::FlexDirection,
+    Align => oxidui::style::layout::Align,
+    Justify => oxidui::style::layout::Justify,
+    TextAlign => oxidui::style::layout::TextAlign,
+    WordBreak => oxidui::style::layout::WordBreak,
+    WhiteSpace => oxidui::style::layout::WhiteSpace,
+    Overflow => oxidui::style::layout::Overflow,
+    Position => oxidui::style::layout::Position,
+    BorderCollapse => oxidui::style::border::BorderCollapse,
+}
+
+/// Emit `Style { <every field> }`, `None` where undeclared.
+///
+/// The exhaustive destructuring makes adding a `Style` field without
+/// teaching the macro about it a compile error.
+fn style_tokens(style: &Style) -> TokenStream {
+    let Style {
+        width,
+        height,
+        min_width,
+        min_height,
+        max_width,
+        max_height,
+        padding,
+        margin,
+        display,
+        flex_direction,
+        flex_grow,
+        flex_shrink,
+        align_items,
+        justify_content,
+        gap,
+        grid_template_columns,
+        grid_template_rows,
+        grid_template_areas,
+        grid_column,
+        grid_row,
+        grid_area,
+        position,
+        inset,
+        z_index,
+        color,
+        background,
+        border,
+        border_title,
+        border_footer,
+        border_collapse,
+        opacity,
+        text_align,
+        word_break,
+        white_space,
+        text_overflow,
+        line_clamp,
+        font_style,
+        underline_color,
+        overflow,
+        scrollbar_thumb,
+        scrollbar_track,
+    } = style;
+
+    let mut fields = Vec::new();
+    macro_rules! field {
+        ($($f:ident),+ $(,)?) => {$(
+            let v = $f.emit();
+            fields.push(quote!($f: #v));
+        )+};
+    }
+    field!(
+        width,
+        height,
+        min_width,
+        min_height,
+        max_width,
+        max_height,
+        padding,
+        margin,
+        display,
+        flex_direction,
+        flex_grow,
+        flex_shrink,
+        align_items,
+        justify_content,
+        gap,
+        grid_template_columns,
+        grid_template_rows,
+        grid_template_areas,
+        grid_column,
+        grid_row,
+        grid_area,
+        position,
+        inset,
+        z_index,
+        color,
+        background,
+        border,
+        border_title,
+        border_footer,
+        border_collapse,
+        opacity,
+        text_align,
+        word_break,
+        white_space,
+        text_overflow,
+        line_clamp,
+        font_style,
+        underline_color,
+        overflow,
+        scrollbar_thumb,
+        scrollbar_track,
+    );
+
+    // Every field is spelled out: `..Style::new()` would drop the unused
+    // rest of a temporary, which a `const` can't do once `Style` owns
+    // strings.
+    quote! {
+        ::oxidui::style::Style {
+            #(#fields,)*
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use quote::quote;
+
+    fn expanded(input: proc_macro2::TokenStream) -> String {
+        expand(input).to_string()
+    }
+
+    #[test]
+    fn empty_style() {
+        let out = expanded(quote!());
+        assert!(out.starts_with(&quote!(::oxidui::style::Style).to_string()));
+        assert!(out.contains(&quote!(width: ::core::option::Option::None).to_string()));
+        assert!(!out.contains("Some"));
+        assert!(!out.contains(".."));
+    }
+
+    #[test]
+    fn single_color() {
+        let out = expanded(quote!(color: red;));
+        let color = quote!(
+            color: ::core::option::Option::Some(::oxidui::style::color::Color::Named(
+                ::oxidui::style::color::NamedColor::Red
+            ))
+        );
+        assert!(out.contains(&color.to_string()));
+        assert_eq!(out.matches("Some").count(), 1);
+    }
+
+    #[test]
+    fn trailing_semicolon_is_optional() {
+        assert_eq!(expanded(quote!(width: 10)), expanded(quote!(width: 10;)));
+    }
+
+    #[test]
+    fn reconstructs_css_spacing() {
+        let out = expanded(quote! {
+            flex-direction: row-reverse;
+            width: 50%;
+            margin: auto -1;
+            background: #1e1e2e;
+            border: rounded bright-cyan;
+        });
+        assert!(out.contains("FlexDirection :: RowReverse"));
+        assert!(out.contains("Percent (50u8)"));
+        assert!(out.contains("Unit :: Auto"));
+        assert!(out.contains("Cells (- 1i32)"));
+        assert!(out.contains("Rgb (30u8 , 30u8 , 46u8)"));
+        assert!(out.contains("BorderStyle :: Rounded"));
+        assert!(out.contains("NamedColor :: BrightCyan"));
+    }
+
+    #[test]
+    fn unknown_property() {
+        let out = expanded(quote!(colour: red;));
+        assert!(out.contains("`colour`: unknown property `colour`"));
+    }
+
+    #[test]
+    fn malformed_value() {
+        let out = expanded(quote!(padding: 1 x;));
+        assert!(out.contains("`padding`: invalid value `x`"));
+    }
+
+    #[test]
+    fn missing_colon() {
+        assert!(expanded(quote!(color red;)).contains("expected `property: value;`"));
+    }
+
+    #[test]
+    fn bad_property_name() {
+        assert!(expanded(quote!(flex-: 1;)).contains("expected a property name"));
+        assert!(expanded(quote!(10: 1;)).contains("expected a property name"));
+    }
+}
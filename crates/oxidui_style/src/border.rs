@@ -1,4 +1,14 @@
 use super::color::Color;
+use super::font::FontStyle;
+use super::layout::TextAlign;
+use super::str::Str;
+
+mod glyphs;
+mod junction;
+
+pub use glyphs::{BorderGlyphs, Charset};
+pub use junction::join;
+
 /// Four-sided shorthand for `padding`, `margin`, border widths, etc.
 ///
 /// Mirrors the CSS shorthand model where a single property expands to
@@ -7,7 +17,7 @@ use super::color::Color;
 /// # Type parameter
 ///
 /// `T` is most commonly [`super::unit::Unit`] (padding, margin) but can be any `Copy`
-/// type — e.g. `Color` for per-side border colors.
+/// type — e.g. `Option<Border>` for per-side borders.
 ///
 /// # CSS shorthand mapping
 ///
@@ -20,6 +30,7 @@ use super::color::Color;
 /// # Examples
 ///
 /// ```rust
+/// # use oxidui_style::{border::{Border, Edges}, color::{Color, NamedColor}, unit::Unit};
 /// let p = Edges::all(Unit::cells(1));
 /// let p = Edges::symmetric(Unit::cells(2), Unit::cells(4));
 /// let p = Edges::new(Unit::cells(1), Unit::ZERO, Unit::cells(1), Unit::ZERO);
@@ -37,6 +48,15 @@ pub struct Edges<T: Copy> {
     pub left: T,
 }
 
+/// One side of a box, to pick it out of an [`Edges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
 impl<T: Copy> Edges<T> {
     /// All four sides equal — CSS `padding: 8px`.
     pub const fn all(v: T) -> Self {
@@ -71,6 +91,7 @@ impl<T: Copy> Edges<T> {
     /// Map each side through a function, producing `Edges<U>`.
     ///
     /// ```rust
+    /// # use oxidui_style::{border::{Border, Edges}, color::{Color, NamedColor}, unit::Unit};
     /// let raw: Edges<i32> = Edges::all(4);
     /// let units = raw.map(Unit::cells);
     /// ```
@@ -83,6 +104,49 @@ impl<T: Copy> Edges<T> {
         }
     }
 
+    /// Combine two sets of edges side by side.
+    ///
+    /// ```rust
+    /// # use oxidui_style::border::Edges;
+    /// let sum = Edges::all(1).zip(Edges::new(0, 1, 2, 3), |a, b| a + b);
+    /// assert_eq!(sum, Edges::new(1, 2, 3, 4));
+    /// ```
+    pub fn zip<U: Copy, V: Copy, F: Fn(T, U) -> V>(self, other: Edges<U>, f: F) -> Edges<V> {
+        Edges {
+            top: f(self.top, other.top),
+            right: f(self.right, other.right),
+            bottom: f(self.bottom, other.bottom),
+            left: f(self.left, other.left),
+        }
+    }
+
+    /// The value on one side.
+    ///
+    /// ```rust
+    /// # use oxidui_style::border::{Edges, Side};
+    /// let mut e = Edges::new(1, 2, 3, 4);
+    /// *e.get_mut(Side::Left) += 1;
+    /// assert_eq!(e.get(Side::Left), 5);
+    /// ```
+    pub fn get(self, side: Side) -> T {
+        match side {
+            Side::Top => self.top,
+            Side::Right => self.right,
+            Side::Bottom => self.bottom,
+            Side::Left => self.left,
+        }
+    }
+
+    /// The value on one side, to change it.
+    pub fn get_mut(&mut self, side: Side) -> &mut T {
+        match side {
+            Side::Top => &mut self.top,
+            Side::Right => &mut self.right,
+            Side::Bottom => &mut self.bottom,
+            Side::Left => &mut self.left,
+        }
+    }
+
     /// `true` if all four sides satisfy the predicate.
     pub fn all_satisfy<F: Fn(T) -> bool>(&self, f: F) -> bool {
         f(self.top) && f(self.right) && f(self.bottom) && f(self.left)
@@ -105,28 +169,62 @@ impl<T: Copy> Edges<T> {
     }
 }
 
+impl Edges<super::unit::Unit> {
+    /// Convert to `ratatui::widgets::Padding`.
+    ///
+    /// Only `Cells` sides carry over (clamped to `0..=u16::MAX`); the other
+    /// units need a container size and become `0` — resolve them through
+    /// layout first if they matter.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui_padding(self) -> ratatui::widgets::Padding {
+        let cells = |u: super::unit::Unit| {
+            u.as_cells()
+                .map_or(0, |n| n.clamp(0, i32::from(u16::MAX)) as u16)
+        };
+        ratatui::widgets::Padding::new(
+            cells(self.left),
+            cells(self.right),
+            cells(self.top),
+            cells(self.bottom),
+        )
+    }
+}
+
 impl<T: Copy + Default> Default for Edges<T> {
     fn default() -> Self {
         Self::all(T::default())
     }
 }
 
-/// A complete border declaration — line style and optional color.
+/// A border declaration — line style and color, each optional.
 ///
-/// Combines CSS `border-style` and `border-color`. In a TUI, border
+/// Combines CSS `border-style` and `border-color`. Either can be left
+/// undeclared, as the `border-color` longhand leaves the style, and
+/// merging a side keeps the other's value for it. In a TUI, border
 /// "thickness" is binary (present/absent) — one character cell — so there
-/// is no `border-width` analogue. Per-side control can be achieved with
-/// `Edges<Option<Border>>` if needed.
+/// is no `border-width` analogue. [`Style::border`] holds one per side as
+/// `Edges<Option<Border>>`, so a header can have just a bottom rule and a
+/// sidebar just a left accent bar.
 ///
 /// # Examples
 ///
 /// ```rust
+/// # use oxidui_style::{Style, border::{Border, Edges}, color::{Color, NamedColor}, unit::Unit};
 /// let b = Border::ROUNDED.with_color(Color::Named(NamedColor::Cyan));
+///
+/// // `border-left: thick cyan` alone.
+/// let accent = Style::new().with_border_sides(Edges {
+///     left: Some(Border { style: Some(oxidui_style::border::BorderStyle::Thick), ..b }),
+///     ..Edges::all(None)
+/// });
 /// ```
+///
+/// [`Style::border`]: crate::Style::border
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Border {
-    /// Which Unicode box-drawing character set to use.
-    pub style: BorderStyle,
+    /// Which Unicode box-drawing character set to use. `None` is
+    /// undeclared, and draws no border unless merged with a style.
+    pub style: Option<BorderStyle>,
 
     /// Color override for the border characters.
     ///
@@ -137,17 +235,17 @@ pub struct Border {
 impl Border {
     /// Single thin lines, square corners. No color override.
     pub const SOLID: Self = Self {
-        style: BorderStyle::Solid,
+        style: Some(BorderStyle::Solid),
         color: None,
     };
     /// Thin lines, rounded corners (`╭ ╮ ╰ ╯`). No color override.
     pub const ROUNDED: Self = Self {
-        style: BorderStyle::Rounded,
+        style: Some(BorderStyle::Rounded),
         color: None,
     };
     /// No border.
     pub const NONE: Self = Self {
-        style: BorderStyle::None,
+        style: Some(BorderStyle::None),
         color: None,
     };
 
@@ -157,13 +255,111 @@ impl Border {
         self
     }
 
-    /// `true` if the border style is `None` (invisible).
+    /// `true` if the border style is `None` or undeclared (invisible).
     pub const fn is_none(self) -> bool {
-        matches!(self.style, BorderStyle::None)
+        matches!(self.style, None | Some(BorderStyle::None))
+    }
+
+    /// `over` on top of `self`, field by field: what `over` leaves
+    /// undeclared keeps `self`'s value.
+    pub const fn merge(self, over: Self) -> Self {
+        Self {
+            style: match over.style {
+                Some(s) => Some(s),
+                None => self.style,
+            },
+            color: match over.color {
+                Some(c) => Some(c),
+                None => self.color,
+            },
+        }
+    }
+}
+
+/// Text set into the top or bottom edge of a border — a pane title or a
+/// footer. CSS has no equivalent; stylesheets write it as
+/// `border-title: "Files" center bold cyan`, with the string first and the
+/// rest in any order.
+///
+/// The label is drawn only where its edge has a border, between the
+/// corners. Text that doesn't fit is cut short with an ellipsis.
+///
+/// ```rust
+/// # use oxidui_style::{Style, border::{Border, BorderLabel}, color::{Color, NamedColor}, layout::TextAlign};
+/// let pane = Style::new()
+///     .with_border(Border::ROUNDED)
+///     .with_border_title(BorderLabel::new("Files").with_align(TextAlign::Center))
+///     .with_border_footer(BorderLabel::new("3 items").with_color(Color::Named(NamedColor::BrightBlack)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BorderLabel {
+    /// The text. An empty label draws nothing, which lets a rule remove a
+    /// title set by another.
+    pub text: Str,
+    /// Placement along the edge.
+    pub align: TextAlign,
+    /// `None` = the color of the border edge it sits on.
+    pub color: Option<Color>,
+    /// `None` = the element's own font style.
+    pub font_style: Option<FontStyle>,
+}
+
+impl BorderLabel {
+    /// Left-aligned `text` in the border's color.
+    pub fn new(text: impl Into<Str>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_font_style(mut self, font_style: FontStyle) -> Self {
+        self.font_style = Some(font_style);
+        self
+    }
+
+    /// A ratatui block title, styled on top of `border` (the border's own
+    /// style).
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(
+        &self,
+        border: ratatui::style::Style,
+    ) -> ratatui::widgets::block::Title<'static> {
+        use ratatui::layout::Alignment;
+        use ratatui::text::Line;
+
+        let mut style = border;
+        if let Some(color) = self.color
+            && color != Color::Inherit
+        {
+            style = style.fg(color.to_ratatui());
+        }
+        if let Some(f) = self.font_style {
+            style = style
+                .add_modifier(f.to_ratatui())
+                .remove_modifier(f.to_ratatui_cleared());
+        }
+        let align = match self.align {
+            TextAlign::Left | TextAlign::Justify => Alignment::Left,
+            TextAlign::Center => Alignment::Center,
+            TextAlign::Right => Alignment::Right,
+        };
+        ratatui::widgets::block::Title::from(Line::styled(self.text.to_string(), style))
+            .alignment(align)
     }
 }
 
-/// Which family of Unicode box-drawing characters to use for a border.
+/// Which family of box-drawing characters to use for a border.
 ///
 /// | Variant   | Characters                      |
 /// |-----------|---------------------------------|
@@ -173,8 +369,13 @@ impl Border {
 /// | `Double`  | `═ ║ ╔ ╗ ╚ ╝`                  |
 /// | `Thick`   | `━ ┃ ┏ ┓ ┗ ┛`                  |
 /// | `Dashed`  | `╌ ╎ ┌ ┐ └ ┘`                  |
+/// | `Ascii`   | `- \| + + + +`                  |
+/// | `Custom`  | whatever the set says           |
 ///
-/// Maps to `ratatui::widgets::BorderType` in the renderer.
+/// Stylesheets name them in lowercase; a [registered] custom set goes by
+/// its registered name. See [`BorderGlyphs`] for every glyph of each set.
+///
+/// [registered]: BorderGlyphs::register
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum BorderStyle {
     /// No border (default).
@@ -190,4 +391,153 @@ pub enum BorderStyle {
     Thick,
     /// Dashed lines — may render as dotted depending on the terminal font.
     Dashed,
+    /// `+`, `-` and `|` — for terminals without box-drawing characters.
+    Ascii,
+    /// An app-defined glyph set.
+    Custom(&'static BorderGlyphs),
+}
+
+impl BorderStyle {
+    /// A built-in style by its stylesheet name, or a
+    /// [registered](BorderGlyphs::register) set.
+    ///
+    /// ```rust
+    /// # use oxidui_style::border::BorderStyle;
+    /// assert_eq!(BorderStyle::named("thick"), Some(BorderStyle::Thick));
+    /// assert_eq!(BorderStyle::named("wobbly"), None);
+    /// ```
+    pub fn named(name: &str) -> Option<Self> {
+        Some(match name {
+            "none" => Self::None,
+            "solid" => Self::Solid,
+            "rounded" => Self::Rounded,
+            "double" => Self::Double,
+            "thick" => Self::Thick,
+            "dashed" => Self::Dashed,
+            "ascii" => Self::Ascii,
+            _ => return BorderGlyphs::registered(name).map(Self::Custom),
+        })
+    }
+
+    /// The characters this style is drawn with, or `None` for no border.
+    pub const fn glyphs(self) -> Option<&'static BorderGlyphs> {
+        match self {
+            Self::None => None,
+            Self::Solid => Some(&BorderGlyphs::SOLID),
+            Self::Rounded => Some(&BorderGlyphs::ROUNDED),
+            Self::Double => Some(&BorderGlyphs::DOUBLE),
+            Self::Thick => Some(&BorderGlyphs::THICK),
+            Self::Dashed => Some(&BorderGlyphs::DASHED),
+            Self::Ascii => Some(&BorderGlyphs::ASCII),
+            Self::Custom(glyphs) => Some(glyphs),
+        }
+    }
+
+    /// This style as `charset` can show it: every border is
+    /// [`BorderStyle::Ascii`] on an ASCII-only terminal.
+    pub const fn for_charset(self, charset: Charset) -> Self {
+        match (self, charset) {
+            (Self::None, _) | (_, Charset::Unicode) => self,
+            (_, Charset::Ascii) => Self::Ascii,
+        }
+    }
+
+    /// The matching `ratatui::widgets::BorderType`, or `None` for no border.
+    ///
+    /// Ratatui only has types for the solid, rounded, double and thick
+    /// sets. The others map to `Plain` here and get their glyphs from
+    /// [`BorderStyle::to_ratatui_set`].
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(self) -> Option<ratatui::widgets::BorderType> {
+        use ratatui::widgets::BorderType as B;
+        match self {
+            Self::None => None,
+            Self::Solid | Self::Dashed | Self::Ascii | Self::Custom(_) => Some(B::Plain),
+            Self::Rounded => Some(B::Rounded),
+            Self::Double => Some(B::Double),
+            Self::Thick => Some(B::Thick),
+        }
+    }
+
+    /// The glyph where a horizontal side in this style meets a `vertical`
+    /// one at `corner`, or `None` if either side has no border.
+    ///
+    /// Matching sides get their own corner. Mixed box-drawing sides get the
+    /// glyph joining both line weights (`┍`, `╓`, …); rounded corners need
+    /// both sides rounded. There is no glyph joining thick and double
+    /// lines, nor any for ASCII or custom sets, so there the horizontal
+    /// side's own corner wins.
+    ///
+    /// ```rust
+    /// # use oxidui_style::border::{BorderStyle, Corner};
+    /// assert_eq!(BorderStyle::Rounded.corner(BorderStyle::Rounded, Corner::TopLeft), Some("╭"));
+    /// assert_eq!(BorderStyle::Double.corner(BorderStyle::Solid, Corner::TopLeft), Some("╒"));
+    /// assert_eq!(BorderStyle::Solid.corner(BorderStyle::None, Corner::TopLeft), None);
+    /// ```
+    pub fn corner(self, vertical: BorderStyle, corner: Corner) -> Option<&'static str> {
+        use Weight::{Double, Heavy, Light};
+        let own = self.glyphs()?;
+        vertical.glyphs()?;
+        if self == vertical {
+            return Some(own.corner(corner));
+        }
+        let (Some(h), Some(v)) = (self.weight(), vertical.weight()) else {
+            return Some(own.corner(corner));
+        };
+        // Clockwise from the top left, like `Corner`.
+        let glyphs = match (h, v) {
+            (Light, Light) => ["┌", "┐", "┘", "└"],
+            (Heavy, Heavy | Double) | (Double, Double | Heavy) => return Some(own.corner(corner)),
+            (Heavy, Light) => ["┍", "┑", "┙", "┕"],
+            (Light, Heavy) => ["┎", "┒", "┚", "┖"],
+            (Double, Light) => ["╒", "╕", "╛", "╘"],
+            (Light, Double) => ["╓", "╖", "╜", "╙"],
+        };
+        Some(glyphs[corner as usize])
+    }
+
+    /// Line weight of the box-drawing sets; `None` for the others.
+    const fn weight(self) -> Option<Weight> {
+        match self {
+            Self::Solid | Self::Rounded | Self::Dashed => Some(Weight::Light),
+            Self::Double => Some(Weight::Double),
+            Self::Thick => Some(Weight::Heavy),
+            Self::None | Self::Ascii | Self::Custom(_) => None,
+        }
+    }
+
+    /// The glyph set to hand to `Block::border_set`.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui_set(self) -> Option<ratatui::symbols::border::Set> {
+        self.glyphs().map(BorderGlyphs::to_ratatui)
+    }
+}
+
+/// A corner of a box, clockwise from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+/// Whether neighbouring borders share a line. CSS `border-collapse`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BorderCollapse {
+    /// Every box draws its own border, so neighbours show two lines
+    /// side by side (default).
+    #[default]
+    Separate,
+    /// A child's border lands on the line its container or previous
+    /// sibling already draws there; where lines cross they [`join`].
+    Collapse,
+}
+
+/// How heavy a border line is drawn — what decides the junction glyphs.
+#[derive(Clone, Copy)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
 }
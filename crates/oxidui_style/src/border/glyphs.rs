@@ -0,0 +1,214 @@
+//! The characters borders are drawn with.
+//!
+//! Every [`BorderStyle`] but `None` has a built-in [`BorderGlyphs`] set.
+//! Apps can add their own: declare a `static` set and either use it
+//! directly as [`BorderStyle::Custom`] or [`BorderGlyphs::register`] it
+//! under a name that stylesheets can then use like `rounded`.
+//!
+//! Terminals whose locale can't show box-drawing characters get
+//! [`BorderGlyphs::ASCII`] instead — see [`Charset`].
+
+use std::sync::{PoisonError, RwLock};
+
+use super::Corner;
+
+static REGISTERED: RwLock<Vec<(&str, &BorderGlyphs)>> = RwLock::new(Vec::new());
+
+/// The characters of a border: corners, lines and the T-junctions where a
+/// divider meets the outline.
+///
+/// Each field is one terminal cell wide.
+///
+/// ```rust
+/// # use oxidui_style::border::{BorderGlyphs, BorderStyle};
+/// static STARS: BorderGlyphs = BorderGlyphs {
+///     horizontal: "*",
+///     vertical: "*",
+///     ..BorderGlyphs::ASCII
+/// };
+/// BorderGlyphs::register("stars", &STARS);
+/// assert_eq!(BorderStyle::named("stars"), Some(BorderStyle::Custom(&STARS)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BorderGlyphs {
+    pub top_left: &'static str,
+    pub top_right: &'static str,
+    pub bottom_right: &'static str,
+    pub bottom_left: &'static str,
+    pub horizontal: &'static str,
+    pub vertical: &'static str,
+    /// `┬` — a vertical divider meeting the top edge.
+    pub top_tee: &'static str,
+    /// `┤` — a horizontal divider meeting the right edge.
+    pub right_tee: &'static str,
+    /// `┴` — a vertical divider meeting the bottom edge.
+    pub bottom_tee: &'static str,
+    /// `├` — a horizontal divider meeting the left edge.
+    pub left_tee: &'static str,
+    /// `┼` — two dividers crossing.
+    pub cross: &'static str,
+}
+
+impl BorderGlyphs {
+    pub const SOLID: Self = Self {
+        top_left: "┌",
+        top_right: "┐",
+        bottom_right: "┘",
+        bottom_left: "└",
+        horizontal: "─",
+        vertical: "│",
+        top_tee: "┬",
+        right_tee: "┤",
+        bottom_tee: "┴",
+        left_tee: "├",
+        cross: "┼",
+    };
+    pub const ROUNDED: Self = Self {
+        top_left: "╭",
+        top_right: "╮",
+        bottom_right: "╯",
+        bottom_left: "╰",
+        ..Self::SOLID
+    };
+    pub const DOUBLE: Self = Self {
+        top_left: "╔",
+        top_right: "╗",
+        bottom_right: "╝",
+        bottom_left: "╚",
+        horizontal: "═",
+        vertical: "║",
+        top_tee: "╦",
+        right_tee: "╣",
+        bottom_tee: "╩",
+        left_tee: "╠",
+        cross: "╬",
+    };
+    pub const THICK: Self = Self {
+        top_left: "┏",
+        top_right: "┓",
+        bottom_right: "┛",
+        bottom_left: "┗",
+        horizontal: "━",
+        vertical: "┃",
+        top_tee: "┳",
+        right_tee: "┫",
+        bottom_tee: "┻",
+        left_tee: "┣",
+        cross: "╋",
+    };
+    pub const DASHED: Self = Self {
+        horizontal: "╌",
+        vertical: "╎",
+        ..Self::SOLID
+    };
+    /// Plain ASCII — safe on any terminal.
+    pub const ASCII: Self = Self {
+        top_left: "+",
+        top_right: "+",
+        bottom_right: "+",
+        bottom_left: "+",
+        horizontal: "-",
+        vertical: "|",
+        top_tee: "+",
+        right_tee: "+",
+        bottom_tee: "+",
+        left_tee: "+",
+        cross: "+",
+    };
+
+    /// The glyph for `corner`.
+    pub const fn corner(&self, corner: Corner) -> &'static str {
+        match corner {
+            Corner::TopLeft => self.top_left,
+            Corner::TopRight => self.top_right,
+            Corner::BottomRight => self.bottom_right,
+            Corner::BottomLeft => self.bottom_left,
+        }
+    }
+
+    /// Make `glyphs` available to stylesheets as `name`, replacing any set
+    /// registered under that name before. Built-in style names always
+    /// refer to the built-ins.
+    ///
+    /// Register before parsing the stylesheets that use it. `scss!` runs at
+    /// compile time and only knows the built-ins.
+    pub fn register(name: &'static str, glyphs: &'static BorderGlyphs) {
+        let mut registered = REGISTERED.write().unwrap_or_else(PoisonError::into_inner);
+        match registered.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = glyphs,
+            None => registered.push((name, glyphs)),
+        }
+    }
+
+    /// The set registered as `name`.
+    pub fn registered(name: &str) -> Option<&'static BorderGlyphs> {
+        REGISTERED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, glyphs)| *glyphs)
+    }
+
+    /// As a ratatui glyph set.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(&self) -> ratatui::symbols::border::Set {
+        ratatui::symbols::border::Set {
+            top_left: self.top_left,
+            top_right: self.top_right,
+            bottom_left: self.bottom_left,
+            bottom_right: self.bottom_right,
+            vertical_left: self.vertical,
+            vertical_right: self.vertical,
+            horizontal_top: self.horizontal,
+            horizontal_bottom: self.horizontal,
+        }
+    }
+}
+
+/// Which characters the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Charset {
+    /// Box-drawing characters and the rest of Unicode.
+    #[default]
+    Unicode,
+    /// ASCII only: every border is drawn with [`BorderGlyphs::ASCII`].
+    Ascii,
+}
+
+impl Charset {
+    /// Detect the charset from the locale variables.
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// Detect the charset from environment variables looked up by `var`.
+    ///
+    /// The locale is the first non-empty one of `LC_ALL`, `LC_CTYPE` and
+    /// `LANG`. It is [`Charset::Unicode`] if its encoding is UTF-8, and
+    /// also if no locale is set at all — many terminals that leave it
+    /// unset are UTF-8 anyway. Any other locale (`C`, `POSIX`,
+    /// `en_US.ISO-8859-1`, …) means [`Charset::Ascii`].
+    ///
+    /// ```rust
+    /// # use oxidui_style::border::Charset;
+    /// let env = |name: &str| (name == "LANG").then(|| "C".to_owned());
+    /// assert_eq!(Charset::from_env(env), Charset::Ascii);
+    /// ```
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .find_map(|name| var(name).filter(|v| !v.is_empty()));
+        match locale {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    Self::Unicode
+                } else {
+                    Self::Ascii
+                }
+            }
+            None => Self::Unicode,
+        }
+    }
+}
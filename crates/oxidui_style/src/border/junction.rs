@@ -0,0 +1,208 @@
+//! Where border lines meet.
+//!
+//! A box-drawing glyph is up to four arms — up, right, down and left —
+//! each light, heavy or double. [`join`] lays one glyph over another by
+//! taking the union of their arms and looking up the glyph for the result,
+//! so `─` over `│` is `┼` and `┐` over `═` is `╤`.
+
+/// Arm weights clockwise from the top: `0` none, `1` light, `2` heavy,
+/// `3` double. Heavier is larger, with double the heaviest.
+type Arms = [u8; 4];
+
+const LIGHT: u8 = 1;
+const HEAVY: u8 = 2;
+const DOUBLE: u8 = 3;
+
+/// Every box-drawing line glyph and its arms. Glyphs that share arms with
+/// an earlier one — dashed lines, rounded corners — come last, so a lookup
+/// by arms finds the plain glyph.
+const GLYPHS: &[(&str, Arms)] = &[
+    ("─", [0, 1, 0, 1]),
+    ("━", [0, 2, 0, 2]),
+    ("│", [1, 0, 1, 0]),
+    ("┃", [2, 0, 2, 0]),
+    ("┌", [0, 1, 1, 0]),
+    ("┍", [0, 2, 1, 0]),
+    ("┎", [0, 1, 2, 0]),
+    ("┏", [0, 2, 2, 0]),
+    ("┐", [0, 0, 1, 1]),
+    ("┑", [0, 0, 1, 2]),
+    ("┒", [0, 0, 2, 1]),
+    ("┓", [0, 0, 2, 2]),
+    ("└", [1, 1, 0, 0]),
+    ("┕", [1, 2, 0, 0]),
+    ("┖", [2, 1, 0, 0]),
+    ("┗", [2, 2, 0, 0]),
+    ("┘", [1, 0, 0, 1]),
+    ("┙", [1, 0, 0, 2]),
+    ("┚", [2, 0, 0, 1]),
+    ("┛", [2, 0, 0, 2]),
+    ("├", [1, 1, 1, 0]),
+    ("┝", [1, 2, 1, 0]),
+    ("┞", [2, 1, 1, 0]),
+    ("┟", [1, 1, 2, 0]),
+    ("┠", [2, 1, 2, 0]),
+    ("┡", [2, 2, 1, 0]),
+    ("┢", [1, 2, 2, 0]),
+    ("┣", [2, 2, 2, 0]),
+    ("┤", [1, 0, 1, 1]),
+    ("┥", [1, 0, 1, 2]),
+    ("┦", [2, 0, 1, 1]),
+    ("┧", [1, 0, 2, 1]),
+    ("┨", [2, 0, 2, 1]),
+    ("┩", [2, 0, 1, 2]),
+    ("┪", [1, 0, 2, 2]),
+    ("┫", [2, 0, 2, 2]),
+    ("┬", [0, 1, 1, 1]),
+    ("┭", [0, 1, 1, 2]),
+    ("┮", [0, 2, 1, 1]),
+    ("┯", [0, 2, 1, 2]),
+    ("┰", [0, 1, 2, 1]),
+    ("┱", [0, 1, 2, 2]),
+    ("┲", [0, 2, 2, 1]),
+    ("┳", [0, 2, 2, 2]),
+    ("┴", [1, 1, 0, 1]),
+    ("┵", [1, 1, 0, 2]),
+    ("┶", [1, 2, 0, 1]),
+    ("┷", [1, 2, 0, 2]),
+    ("┸", [2, 1, 0, 1]),
+    ("┹", [2, 1, 0, 2]),
+    ("┺", [2, 2, 0, 1]),
+    ("┻", [2, 2, 0, 2]),
+    ("┼", [1, 1, 1, 1]),
+    ("┽", [1, 1, 1, 2]),
+    ("┾", [1, 2, 1, 1]),
+    ("┿", [1, 2, 1, 2]),
+    ("╀", [2, 1, 1, 1]),
+    ("╁", [1, 1, 2, 1]),
+    ("╂", [2, 1, 2, 1]),
+    ("╃", [2, 1, 1, 2]),
+    ("╄", [2, 2, 1, 1]),
+    ("╅", [1, 1, 2, 2]),
+    ("╆", [1, 2, 2, 1]),
+    ("╇", [2, 2, 1, 2]),
+    ("╈", [1, 2, 2, 2]),
+    ("╉", [2, 1, 2, 2]),
+    ("╊", [2, 2, 2, 1]),
+    ("╋", [2, 2, 2, 2]),
+    ("═", [0, 3, 0, 3]),
+    ("║", [3, 0, 3, 0]),
+    ("╒", [0, 3, 1, 0]),
+    ("╓", [0, 1, 3, 0]),
+    ("╔", [0, 3, 3, 0]),
+    ("╕", [0, 0, 1, 3]),
+    ("╖", [0, 0, 3, 1]),
+    ("╗", [0, 0, 3, 3]),
+    ("╘", [1, 3, 0, 0]),
+    ("╙", [3, 1, 0, 0]),
+    ("╚", [3, 3, 0, 0]),
+    ("╛", [1, 0, 0, 3]),
+    ("╜", [3, 0, 0, 1]),
+    ("╝", [3, 0, 0, 3]),
+    ("╞", [1, 3, 1, 0]),
+    ("╟", [3, 1, 3, 0]),
+    ("╠", [3, 3, 3, 0]),
+    ("╡", [1, 0, 1, 3]),
+    ("╢", [3, 0, 3, 1]),
+    ("╣", [3, 0, 3, 3]),
+    ("╤", [0, 3, 1, 3]),
+    ("╥", [0, 1, 3, 1]),
+    ("╦", [0, 3, 3, 3]),
+    ("╧", [1, 3, 0, 3]),
+    ("╨", [3, 1, 0, 1]),
+    ("╩", [3, 3, 0, 3]),
+    ("╪", [1, 3, 1, 3]),
+    ("╫", [3, 1, 3, 1]),
+    ("╬", [3, 3, 3, 3]),
+    // Half lines.
+    ("╴", [0, 0, 0, 1]),
+    ("╵", [1, 0, 0, 0]),
+    ("╶", [0, 1, 0, 0]),
+    ("╷", [0, 0, 1, 0]),
+    ("╸", [0, 0, 0, 2]),
+    ("╹", [2, 0, 0, 0]),
+    ("╺", [0, 2, 0, 0]),
+    ("╻", [0, 0, 2, 0]),
+    ("╼", [0, 2, 0, 1]),
+    ("╽", [1, 0, 2, 0]),
+    ("╾", [0, 1, 0, 2]),
+    ("╿", [2, 0, 1, 0]),
+    // Same arms as a plain glyph.
+    ("┄", [0, 1, 0, 1]),
+    ("┅", [0, 2, 0, 2]),
+    ("┆", [1, 0, 1, 0]),
+    ("┇", [2, 0, 2, 0]),
+    ("┈", [0, 1, 0, 1]),
+    ("┉", [0, 2, 0, 2]),
+    ("┊", [1, 0, 1, 0]),
+    ("┋", [2, 0, 2, 0]),
+    ("╌", [0, 1, 0, 1]),
+    ("╍", [0, 2, 0, 2]),
+    ("╎", [1, 0, 1, 0]),
+    ("╏", [2, 0, 2, 0]),
+    ("╭", [0, 1, 1, 0]),
+    ("╮", [0, 0, 1, 1]),
+    ("╯", [1, 0, 0, 1]),
+    ("╰", [1, 1, 0, 0]),
+];
+
+/// The ASCII set: `+` stands for every junction.
+const ASCII: &[(&str, Arms)] = &[
+    ("-", [0, 1, 0, 1]),
+    ("|", [1, 0, 1, 0]),
+    ("+", [1, 1, 1, 1]),
+];
+
+/// The glyph for `above` drawn over `below`, or `None` if the two aren't
+/// lines from the same family — box drawing or ASCII.
+///
+/// Where both have an arm, the heavier one is kept. If no glyph has
+/// exactly the joined arms (heavy and double never mix), the heavy and
+/// then the double arms are drawn light until one does. A glyph that
+/// already covers the other is kept as is, dashes and rounded corners
+/// included.
+///
+/// ```rust
+/// # use oxidui_style::border::join;
+/// assert_eq!(join("─", "│"), Some("┼"));
+/// assert_eq!(join("═", "┐"), Some("╤"));
+/// assert_eq!(join("┬", "╭"), Some("┬"));
+/// assert_eq!(join("-", "|"), Some("+"));
+/// assert_eq!(join("x", "│"), None);
+/// ```
+pub fn join(below: &str, above: &str) -> Option<&'static str> {
+    if let (Some(b), Some(a)) = (find(ASCII, below), find(ASCII, above)) {
+        let joined = union(b.1, a.1);
+        return Some(lookup(ASCII, joined).unwrap_or("+"));
+    }
+    let (below, b) = find(GLYPHS, below)?;
+    let (above, a) = find(GLYPHS, above)?;
+    let joined = union(b, a);
+    if joined == a {
+        return Some(above);
+    }
+    if joined == b {
+        return Some(below);
+    }
+    let lighten = |from: u8, arms: Arms| arms.map(|w| if w == from { LIGHT } else { w });
+    [
+        joined,
+        lighten(HEAVY, joined),
+        lighten(DOUBLE, joined),
+        lighten(DOUBLE, lighten(HEAVY, joined)),
+    ]
+    .into_iter()
+    .find_map(|arms| lookup(GLYPHS, arms))
+}
+
+fn union(a: Arms, b: Arms) -> Arms {
+    std::array::from_fn(|i| a[i].max(b[i]))
+}
+
+fn find(table: &'static [(&'static str, Arms)], glyph: &str) -> Option<(&'static str, Arms)> {
+    table.iter().copied().find(|&(g, _)| g == glyph)
+}
+
+fn lookup(table: &'static [(&'static str, Arms)], arms: Arms) -> Option<&'static str> {
+    table.iter().find(|&&(_, a)| a == arms).map(|&(g, _)| g)
+}
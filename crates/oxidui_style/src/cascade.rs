@@ -0,0 +1,234 @@
+//! The cascade — ordering matching rules before [`Style::merge`].
+//!
+//! [`Style::merge`] is "later wins"; the cascade decides what "later"
+//! means. Matching declarations are sorted by:
+//!
+//! 1. [`Origin`] — theme, then component defaults, then inline styles;
+//! 2. [`Specificity`] of the selector within an origin;
+//! 3. source order — stylesheets in the order they were added, rules in
+//!    the order they appear.
+//!
+//! and merged lowest priority first.
+//!
+//! ```rust
+//! # use oxidui_style::{cascade::{Cascade, Origin}, stylesheet::Stylesheet, selector::PathElement, Style};
+//! # use oxidui_style::color::{Color, NamedColor};
+//! let theme = Stylesheet::parse("#title { color: red } Text { color: blue }").unwrap();
+//! let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+//!
+//! let path = [PathElement::new("Text").with_id("title")];
+//! let style = cascade.cascade(&path, &Style::new());
+//! // `#title` is more specific than `Text`, even though it comes first.
+//! assert_eq!(style.color, Some(Color::Named(NamedColor::Red)));
+//! ```
+
+use crate::Style;
+use crate::selector::{PathElement, Specificity};
+use crate::stylesheet::{Rule, Stylesheet};
+
+/// Where a declaration came from. Later variants take priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Origin {
+    /// Application- or user-wide theme stylesheets.
+    Theme,
+    /// Default styles shipped with a component.
+    Component,
+    /// Declarations on the element itself (`style={…}`).
+    Inline,
+}
+
+/// One rule that matched, with the keys it was sorted by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match<'a> {
+    pub origin: Origin,
+    pub specificity: Specificity,
+    pub rule: &'a Rule,
+}
+
+/// An ordered set of stylesheets to match elements against.
+#[derive(Debug, Clone, Default)]
+pub struct Cascade<'a> {
+    sheets: Vec<(Origin, &'a Stylesheet)>,
+}
+
+impl<'a> Cascade<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stylesheet. Among sheets of the same origin, later additions
+    /// win at equal specificity.
+    pub fn push(&mut self, origin: Origin, sheet: &'a Stylesheet) {
+        self.sheets.push((origin, sheet));
+    }
+
+    pub fn with_stylesheet(mut self, origin: Origin, sheet: &'a Stylesheet) -> Self {
+        self.push(origin, sheet);
+        self
+    }
+
+    /// Every rule whose selector matches the last element of `path`,
+    /// lowest priority first — merge them in this order.
+    pub fn matching(&self, path: &[PathElement<'_>]) -> Vec<Match<'a>> {
+        let mut matches: Vec<Match<'a>> = self
+            .sheets
+            .iter()
+            .flat_map(|&(origin, sheet)| {
+                sheet
+                    .rules
+                    .iter()
+                    .filter(|rule| rule.selector.matches(path))
+                    .map(move |rule| Match {
+                        origin,
+                        specificity: rule.selector.specificity(),
+                        rule,
+                    })
+            })
+            .collect();
+        // Stable, so equal keys keep source order.
+        matches.sort_by_key(|m| (m.origin, m.specificity));
+        matches
+    }
+
+    /// The cascaded style for the last element of `path`: every matching
+    /// rule merged in priority order, then `inline` on top.
+    ///
+    /// Only declarations on this element are considered; inheritance from
+    /// ancestors happens later, when the style is computed.
+    pub fn cascade(&self, path: &[PathElement<'_>], inline: &Style) -> Style {
+        let mut style = Style::new();
+        for m in self.matching(path) {
+            style.merge(&m.rule.style);
+        }
+        style.merge(inline);
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::border::{Border, BorderStyle, Edges};
+    use crate::color::{Color, NamedColor};
+    use crate::computed::ComputedStyle;
+    use crate::grid::GridAreas;
+    use crate::layout::{LineClamp, ZIndex};
+    use crate::str::Str;
+    use crate::unit::Unit;
+
+    fn sheet(src: &str) -> Stylesheet {
+        Stylesheet::parse(src).unwrap()
+    }
+
+    const RED: Color = Color::Named(NamedColor::Red);
+    const GREEN: Color = Color::Named(NamedColor::Green);
+    const BLUE: Color = Color::Named(NamedColor::Blue);
+
+    #[test]
+    fn specificity_beats_source_order() {
+        let theme = sheet(".a { color: red } Text { color: blue; width: 3 }");
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let classes = [Str::from_static("a")];
+        let path = [PathElement::new("Text").with_classes(&classes)];
+
+        let order: Vec<_> = cascade
+            .matching(&path)
+            .iter()
+            .map(|m| m.rule.selector.to_string())
+            .collect();
+        assert_eq!(order, ["Text", ".a"]);
+
+        let style = cascade.cascade(&path, &Style::new());
+        assert_eq!(style.color, Some(RED));
+        assert_eq!(style.width, Some(Unit::cells(3)));
+    }
+
+    #[test]
+    fn source_order_breaks_ties() {
+        let first = sheet("Text { color: red } Text { color: green }");
+        let second = sheet("Text { color: blue }");
+        let path = [PathElement::new("Text")];
+
+        let one = Cascade::new().with_stylesheet(Origin::Theme, &first);
+        assert_eq!(one.cascade(&path, &Style::new()).color, Some(GREEN));
+
+        let both = one.with_stylesheet(Origin::Theme, &second);
+        assert_eq!(both.cascade(&path, &Style::new()).color, Some(BLUE));
+    }
+
+    #[test]
+    fn origin_beats_specificity() {
+        let theme = sheet("#id.a.b { color: red }");
+        let component = sheet("Text { color: green }");
+        // Added in reverse to show order of addition doesn't matter across origins.
+        let cascade = Cascade::new()
+            .with_stylesheet(Origin::Component, &component)
+            .with_stylesheet(Origin::Theme, &theme);
+        let classes = [Str::from_static("a"), Str::from_static("b")];
+        let path = [PathElement::new("Text")
+            .with_id("id")
+            .with_classes(&classes)];
+
+        assert_eq!(cascade.cascade(&path, &Style::new()).color, Some(GREEN));
+        let inline = Style::new().with_color(BLUE);
+        assert_eq!(cascade.cascade(&path, &inline).color, Some(BLUE));
+    }
+
+    #[test]
+    fn non_matching_rules_are_skipped() {
+        let theme = sheet("List > Text { color: red } Box Text { color: green }");
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let path = [PathElement::new("Box"), PathElement::new("Text")];
+        let matches = cascade.matching(&path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].rule.style.color, Some(GREEN));
+        assert!(cascade.matching(&[]).is_empty());
+    }
+
+    #[test]
+    fn border_longhands_change_only_their_field() {
+        let theme = sheet("Box { border: rounded } #a { border-color: red }");
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let path = [PathElement::new("Box").with_id("a")];
+        let border = cascade.cascade(&path, &Style::new()).border;
+        assert_eq!(
+            border,
+            Some(Edges::all(Some(Border::ROUNDED.with_color(RED))))
+        );
+
+        let theme = sheet("Box { border: green } #b { border-style: double }");
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let path = [PathElement::new("Box").with_id("b")];
+        let border = cascade.cascade(&path, &Style::new()).border;
+        let double = Border {
+            style: Some(BorderStyle::Double),
+            color: Some(GREEN),
+        };
+        assert_eq!(border, Some(Edges::all(Some(double))));
+    }
+
+    #[test]
+    fn keywords_undo_earlier_values() {
+        let theme =
+            sheet("Text { line-clamp: 3; z-index: 5 } #open { line-clamp: none; z-index: auto }");
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let path = [PathElement::new("Text").with_id("open")];
+        let style = cascade.cascade(&path, &Style::new());
+        assert_eq!(style.line_clamp, Some(LineClamp::None));
+        assert_eq!(style.z_index, Some(ZIndex::Auto));
+
+        let computed = ComputedStyle::resolve(&style, &ComputedStyle::ROOT);
+        assert_eq!(computed.line_clamp, LineClamp::None);
+        assert_eq!(computed.z_index, ZIndex::Auto);
+
+        let theme =
+            sheet(r#"Grid { grid-template-areas: "a b" } #flat { grid-template-areas: none }"#);
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &theme);
+        let path = [PathElement::new("Grid").with_id("flat")];
+        let style = cascade.cascade(&path, &Style::new());
+        assert_eq!(style.grid_template_areas, Some(GridAreas::NONE));
+
+        let computed = ComputedStyle::resolve(&style, &ComputedStyle::ROOT);
+        assert!(computed.grid_template_columns.is_empty());
+    }
+}
@@ -1,4 +1,13 @@
+mod math;
+mod names;
+mod palette;
+mod parse;
+
 use std::hash::Hash;
+use std::str::FromStr;
+
+pub use palette::Palette;
+pub use parse::ColorParseError;
 
 /// A CSS-like color value for terminal output.
 ///
@@ -11,11 +20,13 @@ use std::hash::Hash;
 /// | 24-bit | [`Color::Rgb`]    | Modern terminals (kitty, iTerm2, WinTerm…)   |
 ///
 /// When converting to `ratatui::style::Color`, degrade gracefully:
-/// prefer `Rgb`, fall back to `Indexed`, fall back to `Named`.
+/// prefer `Rgb`, fall back to `Indexed`, fall back to `Named` — see
+/// [`Color::downsample`] and [`ColorDepth`].
 ///
 /// # Examples
 ///
 /// ```rust
+/// # use oxidui_style::color::{Color, NamedColor};
 /// let red   = Color::Named(NamedColor::Red);
 /// let coral = Color::rgb(255, 127, 80);
 /// let grey  = Color::indexed(240);
@@ -60,6 +71,7 @@ impl Color {
     ///
     /// `const` so proc_macro output has zero runtime cost:
     /// ```rust
+    /// # use oxidui_style::color::{Color, NamedColor};
     /// const CORAL: Color = Color::rgb(255, 127, 80);
     /// ```
     pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
@@ -69,42 +81,112 @@ impl Color {
     /// Construct a 256-palette indexed color.
     ///
     /// ```rust
+    /// # use oxidui_style::color::{Color, NamedColor};
     /// const MID_GREY: Color = Color::indexed(244);
     /// ```
     pub const fn indexed(i: u8) -> Self {
         Self::Indexed(i)
     }
 
-    /// Parse a `#RRGGBB` hex color at compile time.
+    /// Parse a hex color at compile time: `#rgb`, `#rgba`, `#rrggbb` or
+    /// `#rrggbbaa`.
     ///
-    /// Accepts exactly 7 ASCII bytes (including the leading `#`).
-    /// Returns `None` on any malformed input — never panics.
+    /// Terminals have no alpha channel: alpha digits are validated and
+    /// dropped. Returns `None` on any malformed input — never panics.
     ///
     /// `const` so the proc_macro can emit:
     /// ```rust
+    /// # use oxidui_style::color::{Color, NamedColor};
     /// const C: Color = Color::from_hex_bytes(b"#ff5f00").unwrap();
+    /// const D: Color = Color::from_hex_bytes(b"#f50").unwrap();
+    /// assert_eq!(D, Color::rgb(0xff, 0x55, 0x00));
     /// ```
     pub const fn from_hex_bytes(bytes: &[u8]) -> Option<Self> {
-        match bytes {
-            [b'#', r1, r2, g1, g2, b1, b2] => {
-                let r = hex_byte(*r1, *r2);
-                let g = hex_byte(*g1, *g2);
-                let b = hex_byte(*b1, *b2);
-                match (r, g, b) {
-                    (Some(r), Some(g), Some(b)) => Some(Self::Rgb(r, g, b)),
-                    _ => None,
-                }
-            }
-            _ => None,
+        match parse::hex(bytes) {
+            Ok(c) => Some(c),
+            Err(_) => None,
         }
     }
 
+    /// Parse any color syntax, at compile time if need be.
+    ///
+    /// | Syntax                              | Result                         |
+    /// |-------------------------------------|--------------------------------|
+    /// | `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` | [`Color::Rgb`] (alpha dropped) |
+    /// | `rgb(255 95 0)`, `rgb(100%, 37%, 0%)`, `rgba(…, 0.5)` | [`Color::Rgb`] |
+    /// | `hsl(20 100% 50%)`, `hsl(20deg, 100%, 50%)`, `hsla(…)` | [`Color::Rgb`] |
+    /// | `ansi(208)`                         | [`Color::Indexed`]             |
+    /// | `red` … `white`, `bright-red` …     | [`Color::Named`]               |
+    /// | other CSS names (`rebeccapurple`)   | [`Color::Rgb`]                 |
+    /// | `inherit`                           | [`Color::Inherit`]             |
+    /// | `none`, `transparent`               | [`Color::None`]                |
+    ///
+    /// Names and function names ignore ASCII case. The eight CSS names
+    /// shared with the ANSI palette stay [`NamedColor`]s so they follow
+    /// the terminal theme.
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::{Color, ColorParseError, NamedColor};
+    /// const ACCENT: Color = match Color::parse("hsl(210 100% 50%)") {
+    ///     Ok(c) => c,
+    ///     Err(_) => panic!("bad color"),
+    /// };
+    /// assert_eq!(ACCENT, Color::rgb(0, 128, 255));
+    /// assert_eq!("ansi(208)".parse(), Ok(Color::Indexed(208)));
+    /// assert_eq!("Red".parse(), Ok(Color::Named(NamedColor::Red)));
+    /// assert_eq!("rgb(1 2)".parse::<Color>(), Err(ColorParseError::Rgb));
+    /// ```
+    pub const fn parse(s: &str) -> Result<Self, ColorParseError> {
+        parse::parse(s.as_bytes())
+    }
+
     /// Returns `true` if this color carries no concrete color information
     /// (`None` or `Inherit`).
     pub const fn is_abstract(self) -> bool {
         matches!(self, Self::None | Self::Inherit)
     }
 
+    /// The RGB this color displays as, if known.
+    ///
+    /// Named colors and palette entries 0–15 are looked up in
+    /// [`Palette::current`].
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Rgb(r, g, b) => Some((r, g, b)),
+            Self::Named(n) => Some(n.rgb()),
+            Self::Indexed(i @ 0..=15) => Some(NamedColor::ALL[i as usize].rgb()),
+            Self::Indexed(i) => Some(indexed_rgb(i)),
+            Self::Inherit | Self::None => None,
+        }
+    }
+
+    /// The nearest color the terminal can show at `depth`.
+    ///
+    /// Colors already within reach are returned unchanged; everything else
+    /// is matched by perceptual distance. At [`ColorDepth::Monochrome`]
+    /// every color becomes [`Color::None`].
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::{Color, ColorDepth, NamedColor};
+    /// let coral = Color::rgb(255, 127, 80);
+    /// assert_eq!(coral.downsample(ColorDepth::TrueColor), coral);
+    /// assert_eq!(coral.downsample(ColorDepth::Ansi256), Color::Indexed(209));
+    /// assert_eq!(coral.downsample(ColorDepth::Ansi16), Color::Named(NamedColor::Red));
+    /// ```
+    pub fn downsample(self, depth: ColorDepth) -> Self {
+        match (depth, self) {
+            (_, Self::Inherit | Self::None) => self,
+            (ColorDepth::Monochrome, _) => Self::None,
+            (ColorDepth::TrueColor, _) | (_, Self::Named(_)) => self,
+            (ColorDepth::Ansi256, Self::Indexed(_)) => self,
+            (ColorDepth::Ansi256, Self::Rgb(r, g, b)) => Self::Indexed(nearest_indexed(r, g, b)),
+            (ColorDepth::Ansi16, _) => match self.to_rgb() {
+                Some((r, g, b)) => Self::Named(nearest_named(r, g, b)),
+                None => self,
+            },
+        }
+    }
+
     /// Convert to `ratatui::style::Color`. Lossy — `Inherit` and `None`
     /// both map to `Reset`.
     #[cfg(feature = "ratatui")]
@@ -119,6 +201,15 @@ impl Color {
     }
 }
 
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Runtime entry point to [`Color::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time hex parsing helpers (private)
 // ---------------------------------------------------------------------------
@@ -142,6 +233,186 @@ const fn hex_byte(hi: u8, lo: u8) -> Option<u8> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Color depth
+// ---------------------------------------------------------------------------
+
+/// How many colors the terminal can show. Ordered from fewest to most.
+///
+/// [`ColorDepth::detect`] reads it from the environment; pass it to
+/// [`Color::downsample`] to fit a color to the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ColorDepth {
+    /// No color at all — `NO_COLOR` is set or `TERM=dumb`.
+    Monochrome,
+    /// The 16 ANSI colors.
+    #[default]
+    Ansi16,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// 24-bit RGB.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detect the depth from `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// Detect the depth from environment variables looked up by `var`.
+    ///
+    /// In order of precedence:
+    ///
+    /// 1. A non-empty `NO_COLOR` ([no-color.org](https://no-color.org)) or
+    ///    `TERM=dumb` means [`ColorDepth::Monochrome`].
+    /// 2. `COLORTERM=truecolor` or `24bit`, or a `TERM` ending in `-direct`
+    ///    or `-truecolor`, means [`ColorDepth::TrueColor`].
+    /// 3. A `TERM` containing `256color` means [`ColorDepth::Ansi256`].
+    /// 4. Anything else gets the safe [`ColorDepth::Ansi16`].
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::ColorDepth;
+    /// let env = |name: &str| (name == "TERM").then(|| "xterm-256color".to_owned());
+    /// assert_eq!(ColorDepth::from_env(env), ColorDepth::Ansi256);
+    /// ```
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let set = |name| var(name).filter(|v| !v.is_empty());
+        let term = set("TERM").unwrap_or_default();
+
+        if set("NO_COLOR").is_some() || term == "dumb" {
+            Self::Monochrome
+        } else if set("COLORTERM").is_some_and(|v| v == "truecolor" || v == "24bit")
+            || term.ends_with("-direct")
+            || term.ends_with("-truecolor")
+        {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Palette matching (private)
+// ---------------------------------------------------------------------------
+
+/// Channel levels of the 6×6×6 cube at palette entries 16–231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The RGB of xterm palette entry `i`, with entries 0–15 at their xterm
+/// defaults.
+const fn indexed_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => Palette::XTERM.0[i as usize],
+        16..=231 => {
+            let i = (i - 16) as usize;
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[i / 6 % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        _ => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+/// The closest palette entry in 16–255. Entries 0–15 are left out: the
+/// theme decides what they look like.
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let target = Lab::from_rgb(r, g, b);
+    let cube = cube_lab();
+    (16..=255)
+        .min_by(|&x: &u8, &y: &u8| {
+            let (dx, dy) = (
+                target.distance(cube[usize::from(x - 16)]),
+                target.distance(cube[usize::from(y - 16)]),
+            );
+            dx.total_cmp(&dy)
+        })
+        .unwrap_or(16)
+}
+
+/// The closest of the 16 ANSI colors as [`Palette::current`] defines them.
+fn nearest_named(r: u8, g: u8, b: u8) -> NamedColor {
+    let target = Lab::from_rgb(r, g, b);
+    let palette = Palette::current();
+    let distance = |c: NamedColor| {
+        let (r, g, b) = palette.get(c);
+        target.distance(Lab::from_rgb(r, g, b))
+    };
+    NamedColor::ALL
+        .into_iter()
+        .min_by(|&x, &y| distance(x).total_cmp(&distance(y)))
+        .unwrap_or(NamedColor::Black)
+}
+
+/// A CIELAB color, where straight-line distance approximates how different
+/// two colors look — unlike RGB, which overweights blue and underweights
+/// green.
+#[derive(Clone, Copy)]
+struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Lab {
+    /// sRGB → linear RGB → XYZ (D65) → L*a*b*.
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        fn linear(c: u8) -> f64 {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        fn f(t: f64) -> f64 {
+            const D: f64 = 6.0 / 29.0;
+            if t > D * D * D {
+                t.cbrt()
+            } else {
+                t / (3.0 * D * D) + 4.0 / 29.0
+            }
+        }
+
+        let (r, g, b) = (linear(r), linear(g), linear(b));
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.950_47;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.088_83;
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Squared distance (CIE76 ΔE²).
+    fn distance(self, o: Self) -> f64 {
+        let (dl, da, db) = (self.l - o.l, self.a - o.a, self.b - o.b);
+        dl * dl + da * da + db * db
+    }
+}
+
+/// Palette entries 16–255 in Lab, computed once. They are fixed, unlike
+/// 0–15.
+fn cube_lab() -> &'static [Lab; 240] {
+    static CUBE: std::sync::OnceLock<[Lab; 240]> = std::sync::OnceLock::new();
+    CUBE.get_or_init(|| {
+        std::array::from_fn(|i| {
+            let (r, g, b) = indexed_rgb(i as u8 + 16);
+            Lab::from_rgb(r, g, b)
+        })
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Named (ANSI) colors
 // ---------------------------------------------------------------------------
@@ -178,11 +449,37 @@ pub enum NamedColor {
 }
 
 impl NamedColor {
+    /// All 16 colors in palette order.
+    pub const ALL: [Self; 16] = [
+        Self::Black,
+        Self::Red,
+        Self::Green,
+        Self::Yellow,
+        Self::Blue,
+        Self::Magenta,
+        Self::Cyan,
+        Self::White,
+        Self::BrightBlack,
+        Self::BrightRed,
+        Self::BrightGreen,
+        Self::BrightYellow,
+        Self::BrightBlue,
+        Self::BrightMagenta,
+        Self::BrightCyan,
+        Self::BrightWhite,
+    ];
+
     /// The ANSI palette index (0–15) for this color.
     pub const fn ansi_index(self) -> u8 {
         self as u8
     }
 
+    /// The RGB this color is assumed to show as: its entry in
+    /// [`Palette::current`].
+    pub fn rgb(self) -> (u8, u8, u8) {
+        Palette::current().get(self)
+    }
+
     #[cfg(feature = "ratatui")]
     pub fn to_ratatui(self) -> ratatui::style::Color {
         use ratatui::style::Color as R;
@@ -0,0 +1,184 @@
+//! Color math — lightness, saturation, mixing and WCAG contrast.
+//!
+//! Every operation works on the RGB a color shows as (see
+//! [`Color::to_rgb`]), so named and indexed colors take part too; the
+//! result is always [`Color::Rgb`]. `Inherit` and `None` have no RGB and
+//! pass through unchanged.
+
+use super::Color;
+
+impl Color {
+    /// Raise HSL lightness by `amount` (0–1), like Sass `lighten`.
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::Color;
+    /// assert_eq!(Color::rgb(128, 0, 0).lighten(0.25), Color::rgb(255, 0, 0));
+    /// ```
+    pub fn lighten(self, amount: f32) -> Self {
+        self.map_hsl(|h, s, l| (h, s, l + f64::from(amount)))
+    }
+
+    /// Lower HSL lightness by `amount` (0–1), like Sass `darken`.
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Raise HSL saturation by `amount` (0–1), like Sass `saturate`.
+    pub fn saturate(self, amount: f32) -> Self {
+        self.map_hsl(|h, s, l| (h, s + f64::from(amount), l))
+    }
+
+    /// Lower HSL saturation by `amount` (0–1). `desaturate(1.0)` is the
+    /// grey of the same lightness.
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Blend towards `other`: `t = 0` is `self`, `t = 1` is `other`.
+    ///
+    /// Channels are interpolated in sRGB, as CSS `color-mix(in srgb, …)`
+    /// and Sass `mix` do. If either side has no RGB the nearer end wins.
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::Color;
+    /// let black = Color::rgb(0, 0, 0);
+    /// let white = Color::rgb(255, 255, 255);
+    /// assert_eq!(black.mix(white, 0.5), Color::rgb(128, 128, 128));
+    /// ```
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let t = f64::from(t.clamp(0.0, 1.0));
+        match (self.to_rgb(), other.to_rgb()) {
+            (Some(a), Some(b)) => {
+                let lerp = |a: u8, b: u8| channel(f64::from(a) + (f64::from(b) - f64::from(a)) * t);
+                Self::Rgb(lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2))
+            }
+            _ if t < 0.5 => self,
+            _ => other,
+        }
+    }
+
+    /// WCAG 2 relative luminance: 0 for black, 1 for white. `None` for
+    /// `Inherit` and `None`.
+    pub fn luminance(self) -> Option<f32> {
+        self.relative_luminance().map(|l| l as f32)
+    }
+
+    /// WCAG 2 contrast ratio, from 1 (identical) to 21 (black on white).
+    /// WCAG AA asks for at least 4.5 for body text.
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::Color;
+    /// let ratio = Color::rgb(0, 0, 0).contrast_ratio(Color::rgb(255, 255, 255));
+    /// assert_eq!(ratio, Some(21.0));
+    /// ```
+    pub fn contrast_ratio(self, other: Self) -> Option<f32> {
+        let (a, b) = (self.relative_luminance()?, other.relative_luminance()?);
+        let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+        Some(((hi + 0.05) / (lo + 0.05)) as f32)
+    }
+
+    /// Of `candidates`, the one with the highest contrast against `self`
+    /// as a background. `None` if `self` or every candidate has no RGB.
+    pub fn most_readable(self, candidates: &[Self]) -> Option<Self> {
+        candidates
+            .iter()
+            .filter_map(|&c| Some((c, self.contrast_ratio(c)?)))
+            .max_by(|(_, x), (_, y)| x.total_cmp(y))
+            .map(|(c, _)| c)
+    }
+
+    /// Black or white, whichever reads better on `self` as a background.
+    /// The terminal default ([`Color::None`]) if `self` has no RGB.
+    ///
+    /// ```rust
+    /// # use oxidui_style::color::{Color, NamedColor};
+    /// assert_eq!(Color::rgb(250, 220, 0).readable_foreground(), Color::rgb(0, 0, 0));
+    /// assert_eq!(Color::Named(NamedColor::Blue).readable_foreground(), Color::rgb(255, 255, 255));
+    /// ```
+    pub fn readable_foreground(self) -> Self {
+        const CANDIDATES: [Color; 2] = [Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)];
+        self.most_readable(&CANDIDATES).unwrap_or(Self::None)
+    }
+
+    fn relative_luminance(self) -> Option<f64> {
+        fn linear(c: u8) -> f64 {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.039_28 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = self.to_rgb()?;
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Convert to HSL, adjust, clamp and convert back.
+    fn map_hsl(self, f: impl FnOnce(f64, f64, f64) -> (f64, f64, f64)) -> Self {
+        let Some((r, g, b)) = self.to_rgb() else {
+            return self;
+        };
+        let (h, s, l) = rgb_to_hsl(r, g, b);
+        let (h, s, l) = f(h, s, l);
+        let (r, g, b) = hsl_to_rgb(h, s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        Self::Rgb(r, g, b)
+    }
+}
+
+/// Hue in degrees `[0, 360)`, saturation and lightness in `[0, 1]`.
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d == 0.0 {
+        return (0.0, 0.0, l);
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / d)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (wrap(h, 360.0), s, l)
+}
+
+/// The inverse of [`rgb_to_hsl`]. Any hue is accepted; saturation and
+/// lightness must be in `[0, 1]`. `const` for the color parser.
+pub(super) const fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let h = wrap(h, 360.0);
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - (wrap(h / 60.0, 2.0) - 1.0).abs());
+    let (r, g, b) = match (h / 60.0) as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    (
+        channel((r + m) * 255.0),
+        channel((g + m) * 255.0),
+        channel((b + m) * 255.0),
+    )
+}
+
+/// `v` modulo `m`, always non-negative.
+pub(super) const fn wrap(v: f64, m: f64) -> f64 {
+    let r = v % m;
+    if r < 0.0 { r + m } else { r }
+}
+
+/// Round a 0–255 channel value to the nearest integer, saturating.
+pub(super) const fn channel(v: f64) -> u8 {
+    (v + 0.5) as u8
+}
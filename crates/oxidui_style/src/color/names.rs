@@ -0,0 +1,180 @@
+//! The CSS named colors.
+//!
+//! The eight names CSS shares with the ANSI palette (`red`, `blue`, …) map
+//! to [`NamedColor`] so they follow the user's terminal theme, as do our
+//! `bright-*` names for the other eight. Everything else is the exact CSS
+//! RGB value.
+
+use super::{Color, NamedColor};
+
+/// Every name [`lookup`] knows, with its color.
+pub(super) const NAMES: &[(&str, Color)] = &[
+    ("aliceblue", Color::Rgb(240, 248, 255)),
+    ("antiquewhite", Color::Rgb(250, 235, 215)),
+    ("aqua", Color::Rgb(0, 255, 255)),
+    ("aquamarine", Color::Rgb(127, 255, 212)),
+    ("azure", Color::Rgb(240, 255, 255)),
+    ("beige", Color::Rgb(245, 245, 220)),
+    ("bisque", Color::Rgb(255, 228, 196)),
+    ("black", Color::Named(NamedColor::Black)),
+    ("blanchedalmond", Color::Rgb(255, 235, 205)),
+    ("blue", Color::Named(NamedColor::Blue)),
+    ("blueviolet", Color::Rgb(138, 43, 226)),
+    ("brown", Color::Rgb(165, 42, 42)),
+    ("burlywood", Color::Rgb(222, 184, 135)),
+    ("cadetblue", Color::Rgb(95, 158, 160)),
+    ("chartreuse", Color::Rgb(127, 255, 0)),
+    ("chocolate", Color::Rgb(210, 105, 30)),
+    ("coral", Color::Rgb(255, 127, 80)),
+    ("cornflowerblue", Color::Rgb(100, 149, 237)),
+    ("cornsilk", Color::Rgb(255, 248, 220)),
+    ("crimson", Color::Rgb(220, 20, 60)),
+    ("cyan", Color::Named(NamedColor::Cyan)),
+    ("darkblue", Color::Rgb(0, 0, 139)),
+    ("darkcyan", Color::Rgb(0, 139, 139)),
+    ("darkgoldenrod", Color::Rgb(184, 134, 11)),
+    ("darkgray", Color::Rgb(169, 169, 169)),
+    ("darkgreen", Color::Rgb(0, 100, 0)),
+    ("darkgrey", Color::Rgb(169, 169, 169)),
+    ("darkkhaki", Color::Rgb(189, 183, 107)),
+    ("darkmagenta", Color::Rgb(139, 0, 139)),
+    ("darkolivegreen", Color::Rgb(85, 107, 47)),
+    ("darkorange", Color::Rgb(255, 140, 0)),
+    ("darkorchid", Color::Rgb(153, 50, 204)),
+    ("darkred", Color::Rgb(139, 0, 0)),
+    ("darksalmon", Color::Rgb(233, 150, 122)),
+    ("darkseagreen", Color::Rgb(143, 188, 143)),
+    ("darkslateblue", Color::Rgb(72, 61, 139)),
+    ("darkslategray", Color::Rgb(47, 79, 79)),
+    ("darkslategrey", Color::Rgb(47, 79, 79)),
+    ("darkturquoise", Color::Rgb(0, 206, 209)),
+    ("darkviolet", Color::Rgb(148, 0, 211)),
+    ("deeppink", Color::Rgb(255, 20, 147)),
+    ("deepskyblue", Color::Rgb(0, 191, 255)),
+    ("dimgray", Color::Rgb(105, 105, 105)),
+    ("dimgrey", Color::Rgb(105, 105, 105)),
+    ("dodgerblue", Color::Rgb(30, 144, 255)),
+    ("firebrick", Color::Rgb(178, 34, 34)),
+    ("floralwhite", Color::Rgb(255, 250, 240)),
+    ("forestgreen", Color::Rgb(34, 139, 34)),
+    ("fuchsia", Color::Rgb(255, 0, 255)),
+    ("gainsboro", Color::Rgb(220, 220, 220)),
+    ("ghostwhite", Color::Rgb(248, 248, 255)),
+    ("gold", Color::Rgb(255, 215, 0)),
+    ("goldenrod", Color::Rgb(218, 165, 32)),
+    ("gray", Color::Rgb(128, 128, 128)),
+    ("green", Color::Named(NamedColor::Green)),
+    ("greenyellow", Color::Rgb(173, 255, 47)),
+    ("grey", Color::Rgb(128, 128, 128)),
+    ("honeydew", Color::Rgb(240, 255, 240)),
+    ("hotpink", Color::Rgb(255, 105, 180)),
+    ("indianred", Color::Rgb(205, 92, 92)),
+    ("indigo", Color::Rgb(75, 0, 130)),
+    ("ivory", Color::Rgb(255, 255, 240)),
+    ("khaki", Color::Rgb(240, 230, 140)),
+    ("lavender", Color::Rgb(230, 230, 250)),
+    ("lavenderblush", Color::Rgb(255, 240, 245)),
+    ("lawngreen", Color::Rgb(124, 252, 0)),
+    ("lemonchiffon", Color::Rgb(255, 250, 205)),
+    ("lightblue", Color::Rgb(173, 216, 230)),
+    ("lightcoral", Color::Rgb(240, 128, 128)),
+    ("lightcyan", Color::Rgb(224, 255, 255)),
+    ("lightgoldenrodyellow", Color::Rgb(250, 250, 210)),
+    ("lightgray", Color::Rgb(211, 211, 211)),
+    ("lightgreen", Color::Rgb(144, 238, 144)),
+    ("lightgrey", Color::Rgb(211, 211, 211)),
+    ("lightpink", Color::Rgb(255, 182, 193)),
+    ("lightsalmon", Color::Rgb(255, 160, 122)),
+    ("lightseagreen", Color::Rgb(32, 178, 170)),
+    ("lightskyblue", Color::Rgb(135, 206, 250)),
+    ("lightslategray", Color::Rgb(119, 136, 153)),
+    ("lightslategrey", Color::Rgb(119, 136, 153)),
+    ("lightsteelblue", Color::Rgb(176, 196, 222)),
+    ("lightyellow", Color::Rgb(255, 255, 224)),
+    ("lime", Color::Rgb(0, 255, 0)),
+    ("limegreen", Color::Rgb(50, 205, 50)),
+    ("linen", Color::Rgb(250, 240, 230)),
+    ("magenta", Color::Named(NamedColor::Magenta)),
+    ("maroon", Color::Rgb(128, 0, 0)),
+    ("mediumaquamarine", Color::Rgb(102, 205, 170)),
+    ("mediumblue", Color::Rgb(0, 0, 205)),
+    ("mediumorchid", Color::Rgb(186, 85, 211)),
+    ("mediumpurple", Color::Rgb(147, 112, 219)),
+    ("mediumseagreen", Color::Rgb(60, 179, 113)),
+    ("mediumslateblue", Color::Rgb(123, 104, 238)),
+    ("mediumspringgreen", Color::Rgb(0, 250, 154)),
+    ("mediumturquoise", Color::Rgb(72, 209, 204)),
+    ("mediumvioletred", Color::Rgb(199, 21, 133)),
+    ("midnightblue", Color::Rgb(25, 25, 112)),
+    ("mintcream", Color::Rgb(245, 255, 250)),
+    ("mistyrose", Color::Rgb(255, 228, 225)),
+    ("moccasin", Color::Rgb(255, 228, 181)),
+    ("navajowhite", Color::Rgb(255, 222, 173)),
+    ("navy", Color::Rgb(0, 0, 128)),
+    ("oldlace", Color::Rgb(253, 245, 230)),
+    ("olive", Color::Rgb(128, 128, 0)),
+    ("olivedrab", Color::Rgb(107, 142, 35)),
+    ("orange", Color::Rgb(255, 165, 0)),
+    ("orangered", Color::Rgb(255, 69, 0)),
+    ("orchid", Color::Rgb(218, 112, 214)),
+    ("palegoldenrod", Color::Rgb(238, 232, 170)),
+    ("palegreen", Color::Rgb(152, 251, 152)),
+    ("paleturquoise", Color::Rgb(175, 238, 238)),
+    ("palevioletred", Color::Rgb(219, 112, 147)),
+    ("papayawhip", Color::Rgb(255, 239, 213)),
+    ("peachpuff", Color::Rgb(255, 218, 185)),
+    ("peru", Color::Rgb(205, 133, 63)),
+    ("pink", Color::Rgb(255, 192, 203)),
+    ("plum", Color::Rgb(221, 160, 221)),
+    ("powderblue", Color::Rgb(176, 224, 230)),
+    ("purple", Color::Rgb(128, 0, 128)),
+    ("rebeccapurple", Color::Rgb(102, 51, 153)),
+    ("red", Color::Named(NamedColor::Red)),
+    ("rosybrown", Color::Rgb(188, 143, 143)),
+    ("royalblue", Color::Rgb(65, 105, 225)),
+    ("saddlebrown", Color::Rgb(139, 69, 19)),
+    ("salmon", Color::Rgb(250, 128, 114)),
+    ("sandybrown", Color::Rgb(244, 164, 96)),
+    ("seagreen", Color::Rgb(46, 139, 87)),
+    ("seashell", Color::Rgb(255, 245, 238)),
+    ("sienna", Color::Rgb(160, 82, 45)),
+    ("silver", Color::Rgb(192, 192, 192)),
+    ("skyblue", Color::Rgb(135, 206, 235)),
+    ("slateblue", Color::Rgb(106, 90, 205)),
+    ("slategray", Color::Rgb(112, 128, 144)),
+    ("slategrey", Color::Rgb(112, 128, 144)),
+    ("snow", Color::Rgb(255, 250, 250)),
+    ("springgreen", Color::Rgb(0, 255, 127)),
+    ("steelblue", Color::Rgb(70, 130, 180)),
+    ("tan", Color::Rgb(210, 180, 140)),
+    ("teal", Color::Rgb(0, 128, 128)),
+    ("thistle", Color::Rgb(216, 191, 216)),
+    ("tomato", Color::Rgb(255, 99, 71)),
+    ("turquoise", Color::Rgb(64, 224, 208)),
+    ("violet", Color::Rgb(238, 130, 238)),
+    ("wheat", Color::Rgb(245, 222, 179)),
+    ("white", Color::Named(NamedColor::White)),
+    ("whitesmoke", Color::Rgb(245, 245, 245)),
+    ("yellow", Color::Named(NamedColor::Yellow)),
+    ("yellowgreen", Color::Rgb(154, 205, 50)),
+    ("bright-black", Color::Named(NamedColor::BrightBlack)),
+    ("bright-red", Color::Named(NamedColor::BrightRed)),
+    ("bright-green", Color::Named(NamedColor::BrightGreen)),
+    ("bright-yellow", Color::Named(NamedColor::BrightYellow)),
+    ("bright-blue", Color::Named(NamedColor::BrightBlue)),
+    ("bright-magenta", Color::Named(NamedColor::BrightMagenta)),
+    ("bright-cyan", Color::Named(NamedColor::BrightCyan)),
+    ("bright-white", Color::Named(NamedColor::BrightWhite)),
+];
+
+/// The color called `name`, ignoring ASCII case.
+pub(super) const fn lookup(name: &[u8]) -> Option<Color> {
+    let mut i = 0;
+    while i < NAMES.len() {
+        if NAMES[i].0.as_bytes().eq_ignore_ascii_case(name) {
+            return Some(NAMES[i].1);
+        }
+        i += 1;
+    }
+    None
+}
@@ -0,0 +1,74 @@
+//! The RGB values behind the 16 [`NamedColor`]s.
+//!
+//! Terminals let users pick their own ANSI colors, so there is no single
+//! right answer. Color math, contrast checks and downsampling all read
+//! [`Palette::current`], which starts as the xterm defaults; an app that
+//! knows the user's theme can install it with [`Palette::set_current`].
+
+use std::sync::{PoisonError, RwLock};
+
+use super::NamedColor;
+
+static CURRENT: RwLock<Palette> = RwLock::new(Palette::XTERM);
+
+/// RGB values for the 16 ANSI colors, indexed by [`NamedColor`].
+///
+/// ```rust
+/// # use oxidui_style::color::{NamedColor, Palette};
+/// let solarized = Palette::XTERM
+///     .with(NamedColor::Black, (0x07, 0x36, 0x42))
+///     .with(NamedColor::Red, (0xdc, 0x32, 0x2f));
+/// assert_eq!(solarized.get(NamedColor::Red), (0xdc, 0x32, 0x2f));
+/// assert_eq!(solarized.get(NamedColor::Blue), Palette::XTERM.get(NamedColor::Blue));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Palette(pub [(u8, u8, u8); 16]);
+
+impl Palette {
+    /// xterm's default colors.
+    pub const XTERM: Self = Self([
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ]);
+
+    /// The RGB for `color`.
+    pub const fn get(&self, color: NamedColor) -> (u8, u8, u8) {
+        self.0[color.ansi_index() as usize]
+    }
+
+    /// Return a copy with `color` set to `rgb`.
+    pub const fn with(mut self, color: NamedColor, rgb: (u8, u8, u8)) -> Self {
+        self.0[color.ansi_index() as usize] = rgb;
+        self
+    }
+
+    /// The process-wide palette. [`Palette::XTERM`] until replaced.
+    pub fn current() -> Self {
+        *CURRENT.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replace the process-wide palette.
+    pub fn set_current(palette: Self) {
+        *CURRENT.write().unwrap_or_else(PoisonError::into_inner) = palette;
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::XTERM
+    }
+}
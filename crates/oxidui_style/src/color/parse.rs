@@ -0,0 +1,364 @@
+//! Color syntax — a `const` parser shared by [`Color::parse`] and
+//! [`FromStr`](std::str::FromStr).
+//!
+//! Everything works on bytes with `while` loops so that the whole parser
+//! can run at compile time.
+
+use std::fmt;
+
+use super::math::{channel, hsl_to_rgb, wrap};
+use super::{Color, hex_byte, hex_nibble, names};
+
+/// Why a string is not a color. Each variant names the form that was
+/// being parsed, so the message can say what was expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorParseError {
+    /// Nothing but whitespace.
+    Empty,
+    /// `#` followed by the wrong number of digits, or a non-hex digit.
+    Hex,
+    /// Not a known color name or keyword.
+    Name,
+    /// `name(…)` where `name` is not a color function.
+    Function,
+    /// A color function without its closing `)`.
+    Unclosed,
+    /// Bad arguments to `rgb()` / `rgba()`.
+    Rgb,
+    /// Bad arguments to `hsl()` / `hsla()`.
+    Hsl,
+    /// Bad argument to `ansi()`.
+    Ansi,
+}
+
+impl ColorParseError {
+    /// What a valid value of the attempted form looks like.
+    pub const fn expected(self) -> &'static str {
+        match self {
+            Self::Empty => "a color",
+            Self::Hex => "3, 4, 6 or 8 hex digits after `#`",
+            Self::Name => {
+                "a color name (`red`, `bright-blue`, `rebeccapurple`, …), `inherit` or `none`"
+            }
+            Self::Function => "`rgb()`, `rgba()`, `hsl()`, `hsla()` or `ansi()`",
+            Self::Unclosed => "a closing `)`",
+            Self::Rgb => "`rgb(r g b [/ alpha])` with channels 0–255 or 0%–100%",
+            Self::Hsl => "`hsl(hue saturation% lightness% [/ alpha])` with the hue in degrees",
+            Self::Ansi => "`ansi(n)` with a palette index 0–255",
+        }
+    }
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}", self.expected())
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Parse any color syntax. See [`Color::parse`].
+pub(super) const fn parse(s: &[u8]) -> Result<Color, ColorParseError> {
+    let s = s.trim_ascii();
+    if s.is_empty() {
+        return Err(ColorParseError::Empty);
+    }
+    if s[0] == b'#' {
+        return hex(s);
+    }
+
+    let mut open = 0;
+    while open < s.len() && s[open] != b'(' {
+        open += 1;
+    }
+    if open == s.len() {
+        return keyword(s);
+    }
+    if s[s.len() - 1] != b')' {
+        return Err(ColorParseError::Unclosed);
+    }
+    let name = slice(s, 0, open).trim_ascii();
+    let args = slice(s, open + 1, s.len() - 1);
+
+    if name.eq_ignore_ascii_case(b"rgb") || name.eq_ignore_ascii_case(b"rgba") {
+        rgb(args)
+    } else if name.eq_ignore_ascii_case(b"hsl") || name.eq_ignore_ascii_case(b"hsla") {
+        hsl(args)
+    } else if name.eq_ignore_ascii_case(b"ansi") {
+        ansi(args)
+    } else {
+        Err(ColorParseError::Function)
+    }
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. Terminals have no alpha
+/// channel, so the alpha digits are checked and dropped — use `opacity`
+/// for translucency.
+pub(super) const fn hex(s: &[u8]) -> Result<Color, ColorParseError> {
+    const ERR: ColorParseError = ColorParseError::Hex;
+    match s {
+        [b'#', r, g, b] | [b'#', r, g, b, _] => {
+            if let [.., a] = s
+                && s.len() == 5
+                && hex_nibble(*a).is_none()
+            {
+                return Err(ERR);
+            }
+            match (hex_byte(*r, *r), hex_byte(*g, *g), hex_byte(*b, *b)) {
+                (Some(r), Some(g), Some(b)) => Ok(Color::Rgb(r, g, b)),
+                _ => Err(ERR),
+            }
+        }
+        [b'#', r1, r2, g1, g2, b1, b2] | [b'#', r1, r2, g1, g2, b1, b2, _, _] => {
+            if let [.., a1, a2] = s
+                && s.len() == 9
+                && hex_byte(*a1, *a2).is_none()
+            {
+                return Err(ERR);
+            }
+            match (hex_byte(*r1, *r2), hex_byte(*g1, *g2), hex_byte(*b1, *b2)) {
+                (Some(r), Some(g), Some(b)) => Ok(Color::Rgb(r, g, b)),
+                _ => Err(ERR),
+            }
+        }
+        _ => Err(ERR),
+    }
+}
+
+/// A color name, `inherit`, `none` or `transparent` (the same as `none`).
+const fn keyword(s: &[u8]) -> Result<Color, ColorParseError> {
+    if s.eq_ignore_ascii_case(b"inherit") {
+        Ok(Color::Inherit)
+    } else if s.eq_ignore_ascii_case(b"none") || s.eq_ignore_ascii_case(b"transparent") {
+        Ok(Color::None)
+    } else {
+        match names::lookup(s) {
+            Some(c) => Ok(c),
+            None => Err(ColorParseError::Name),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Functions
+// ---------------------------------------------------------------------------
+
+/// `r g b`, `r, g, b`, optionally followed by `/ a` or `, a`.
+const fn rgb(args: &[u8]) -> Result<Color, ColorParseError> {
+    const ERR: ColorParseError = ColorParseError::Rgb;
+    let Some(args) = Args::split(args) else {
+        return Err(ERR);
+    };
+    let mut channels = [0u8; 3];
+    let mut i = 0;
+    while i < 3 {
+        channels[i] = match args.number(i) {
+            Some(Number {
+                value,
+                unit: Suffix::None,
+            }) if value >= 0.0 && value <= 255.0 => channel(value),
+            Some(Number {
+                value,
+                unit: Suffix::Percent,
+            }) if value >= 0.0 && value <= 100.0 => channel(value * 255.0 / 100.0),
+            _ => return Err(ERR),
+        };
+        i += 1;
+    }
+    if !args.alpha_ok() {
+        return Err(ERR);
+    }
+    Ok(Color::Rgb(channels[0], channels[1], channels[2]))
+}
+
+/// `h s% l%`, `h, s%, l%`, optionally followed by an alpha. The hue is in
+/// degrees, with or without `deg`, and wraps around.
+const fn hsl(args: &[u8]) -> Result<Color, ColorParseError> {
+    const ERR: ColorParseError = ColorParseError::Hsl;
+    let Some(args) = Args::split(args) else {
+        return Err(ERR);
+    };
+    let hue = match args.number(0) {
+        Some(Number {
+            value,
+            unit: Suffix::None | Suffix::Deg,
+        }) => wrap(value, 360.0),
+        _ => return Err(ERR),
+    };
+    let (sat, light) = match (args.number(1), args.number(2)) {
+        (
+            Some(Number {
+                value: s,
+                unit: Suffix::Percent,
+            }),
+            Some(Number {
+                value: l,
+                unit: Suffix::Percent,
+            }),
+        ) if s >= 0.0 && s <= 100.0 && l >= 0.0 && l <= 100.0 => (s / 100.0, l / 100.0),
+        _ => return Err(ERR),
+    };
+    if !args.alpha_ok() {
+        return Err(ERR);
+    }
+
+    let (r, g, b) = hsl_to_rgb(hue, sat, light);
+    Ok(Color::Rgb(r, g, b))
+}
+
+/// A single palette index.
+const fn ansi(args: &[u8]) -> Result<Color, ColorParseError> {
+    const ERR: ColorParseError = ColorParseError::Ansi;
+    let Some(args) = Args::split(args) else {
+        return Err(ERR);
+    };
+    if args.len != 1 {
+        return Err(ERR);
+    }
+    match args.number(0) {
+        Some(Number {
+            value,
+            unit: Suffix::None,
+        }) if value >= 0.0 && value <= 255.0 && value == (value as u8) as f64 => {
+            Ok(Color::Indexed(value as u8))
+        }
+        _ => Err(ERR),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Arguments
+// ---------------------------------------------------------------------------
+
+/// Up to four function arguments, as byte ranges into `src`.
+struct Args<'a> {
+    src: &'a [u8],
+    ranges: [(usize, usize); 4],
+    len: usize,
+}
+
+impl<'a> Args<'a> {
+    /// Split on whitespace, commas and `/`. `None` if there are more than
+    /// four arguments.
+    const fn split(src: &'a [u8]) -> Option<Self> {
+        let mut args = Self {
+            src,
+            ranges: [(0, 0); 4],
+            len: 0,
+        };
+        let mut i = 0;
+        while i < src.len() {
+            if is_separator(src[i]) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < src.len() && !is_separator(src[i]) {
+                i += 1;
+            }
+            if args.len == args.ranges.len() {
+                return None;
+            }
+            args.ranges[args.len] = (start, i);
+            args.len += 1;
+        }
+        Some(args)
+    }
+
+    const fn number(&self, i: usize) -> Option<Number> {
+        if i >= self.len {
+            return None;
+        }
+        let (start, end) = self.ranges[i];
+        Number::parse(slice(self.src, start, end))
+    }
+
+    /// Exactly three channels, plus an optional alpha: a number 0–1 or a
+    /// percentage.
+    const fn alpha_ok(&self) -> bool {
+        match self.len {
+            3 => true,
+            4 => {
+                matches!(
+                    self.number(3),
+                    Some(Number { value, unit: Suffix::None }) if value >= 0.0 && value <= 1.0
+                ) || matches!(
+                    self.number(3),
+                    Some(Number { value, unit: Suffix::Percent }) if value >= 0.0 && value <= 100.0
+                )
+            }
+            _ => false,
+        }
+    }
+}
+
+const fn is_separator(b: u8) -> bool {
+    b.is_ascii_whitespace() || b == b',' || b == b'/'
+}
+
+#[derive(Clone, Copy)]
+enum Suffix {
+    None,
+    Percent,
+    Deg,
+}
+
+#[derive(Clone, Copy)]
+struct Number {
+    value: f64,
+    unit: Suffix,
+}
+
+impl Number {
+    /// A decimal number (`12`, `-3.5`, `.5`) with an optional `%` or
+    /// `deg` suffix.
+    const fn parse(s: &[u8]) -> Option<Self> {
+        let (s, unit) = if let [rest @ .., b'%'] = s {
+            (rest, Suffix::Percent)
+        } else if let [rest @ .., b'd', b'e', b'g'] = s {
+            (rest, Suffix::Deg)
+        } else {
+            (s, Suffix::None)
+        };
+
+        let (negative, s) = match s {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            _ => (false, s),
+        };
+        let mut value = 0.0;
+        let mut scale = 1.0;
+        let mut digits = 0;
+        let mut seen_point = false;
+        let mut i = 0;
+        while i < s.len() {
+            match s[i] {
+                d @ b'0'..=b'9' => {
+                    let d = (d - b'0') as f64;
+                    if seen_point {
+                        scale /= 10.0;
+                        value += d * scale;
+                    } else {
+                        value = value * 10.0 + d;
+                    }
+                    digits += 1;
+                }
+                b'.' if !seen_point => seen_point = true,
+                _ => return None,
+            }
+            i += 1;
+        }
+        if digits == 0 {
+            return None;
+        }
+        Some(Self {
+            value: if negative { -value } else { value },
+            unit,
+        })
+    }
+}
+
+/// `&s[start..end]`, usable in `const fn`.
+const fn slice(s: &[u8], start: usize, end: usize) -> &[u8] {
+    s.split_at(end).0.split_at(start).1
+}
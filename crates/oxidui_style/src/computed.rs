@@ -0,0 +1,623 @@
+//! Computed styles — the step between cascade and rendering.
+//!
+//! A cascaded [`Style`] still has holes (`None` fields) and abstract values
+//! (`Color::Inherit`, `Unit::Unset`). [`ComputedStyle::resolve`] fills every
+//! hole against the parent's computed style, so layout and paint never have
+//! to ask "what if this isn't set?".
+//!
+//! # Inheritance
+//!
+//! | Property                | Inherited | Initial value                  |
+//! |-------------------------|-----------|--------------------------------|
+//! | `color`                 | yes       | `Color::None` (terminal fg)    |
+//! | `font-style`            | yes       | `FontStyle::NORMAL`            |
+//! | `text-align`            | yes       | `TextAlign::Left`              |
+//! | `word-break`            | yes       | `WordBreak::Normal`            |
+//! | `white-space`           | yes       | `WhiteSpace::Normal`           |
+//! | `underline-color`       | yes       | `Color::None` (text color)     |
+//! | `border-collapse`       | yes       | `BorderCollapse::Separate`     |
+//! | `scrollbar-thumb/track` | yes²      | charset's glyph, text color    |
+//! | `background`            | no¹       | parent's background            |
+//! | `border`, `border-<side>`| no       | `BorderStyle::None`, `color`   |
+//! | `opacity`               | no        | `1.0`                          |
+//! | `line-clamp`            | no        | `LineClamp::None` (every line) |
+//! | `width`, `height`       | no        | `Unit::Auto`                   |
+//! | `min-width/height`      | no        | `Unit::ZERO`                   |
+//! | `max-width/height`      | no        | `Unit::Auto` (unbounded)       |
+//! | `padding`, `margin`     | no        | `0` on every side              |
+//! | `gap`                   | no        | `Unit::ZERO`                   |
+//! | `flex-grow` / `-shrink` | no        | `0` / `1`                      |
+//! | `grid-template-*`       | no        | none; `auto` per column/row of areas |
+//! | `grid-column`, `grid-row`| no       | `GridPlacement::AUTO`          |
+//! | `position`              | no        | `Position::Static`             |
+//! | `top`, `right`, … `inset`| no       | `Unit::Auto`                   |
+//! | `z-index`               | no        | `ZIndex::Auto`                 |
+//! | other layout enums      | no        | the enum's `Default`           |
+//!
+//! ¹ Backgrounds are transparent by default, so an element without one
+//! shows its parent's. The computed `background` is therefore the color
+//! actually behind the element's content, which is what text painting and
+//! blending need.
+//!
+//! ² A scrollbar part's glyph is inherited. Its color is inherited only
+//! once declared: until then it is each element's own text color.
+//!
+//! `Color::Inherit` takes the parent's computed value for any color
+//! property. `Color::None` means the terminal's own default: it is kept as
+//! is for `color` (it is a real, renderable value — ratatui's `Reset`) and
+//! for `background` it is transparent like an absent declaration.
+//!
+//! ```rust
+//! # use oxidui_style::{computed::ComputedStyle, Style, color::{Color, NamedColor}, layout::TextAlign};
+//! let root = ComputedStyle::resolve(
+//!     &Style::new()
+//!         .with_color(Color::Named(NamedColor::Cyan))
+//!         .with_width(oxidui_style::unit::Unit::cells(40)),
+//!     &ComputedStyle::ROOT,
+//! );
+//! let child = ComputedStyle::resolve(&Style::new().with_text_align(TextAlign::Center), &root);
+//!
+//! assert_eq!(child.color, Color::Named(NamedColor::Cyan)); // inherited
+//! assert_eq!(child.width, oxidui_style::unit::Unit::Auto); // not inherited
+//! ```
+
+use crate::Style;
+use crate::border::{BorderCollapse, BorderStyle, Charset, Edges};
+use crate::color::{Color, ColorDepth};
+use crate::font::FontStyle;
+use crate::grid::{GridPlacement, TrackList};
+use crate::layout::{
+    Align, Display, FlexDirection, Justify, LineClamp, Overflow, Position, ScrollbarPart,
+    TextAlign, WhiteSpace, WordBreak, ZIndex,
+};
+use crate::number::Float;
+use crate::unit::Unit;
+
+/// Properties whose value passes from parent to child when undeclared.
+/// Names as in stylesheets; everything else starts from its initial value.
+pub const INHERITED: &[&str] = &[
+    "color",
+    "font-style",
+    "text-align",
+    "word-break",
+    "white-space",
+    "underline-color",
+    "border-collapse",
+    "scrollbar-thumb",
+    "scrollbar-track",
+];
+
+/// A fully resolved style — every property has a concrete value.
+///
+/// Lengths may still be relative (`Percent`, `Fill`, `Auto`); those need
+/// the container size and are resolved by layout, not here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputedStyle {
+    // Box model
+    pub width: Unit,
+    pub height: Unit,
+    pub min_width: Unit,
+    pub min_height: Unit,
+    /// `Unit::Auto` = no maximum.
+    pub max_width: Unit,
+    /// `Unit::Auto` = no maximum.
+    pub max_height: Unit,
+    /// Never contains `Unit::Unset`.
+    pub padding: Edges<Unit>,
+    /// Never contains `Unit::Unset`.
+    pub margin: Edges<Unit>,
+
+    // Layout
+    pub display: Display,
+    pub flex_direction: FlexDirection,
+    pub flex_grow: Float,
+    pub flex_shrink: Float,
+    pub align_items: Align,
+    pub justify_content: Justify,
+    pub gap: Unit,
+    pub grid_template_columns: TrackList,
+    pub grid_template_rows: TrackList,
+    /// Named areas are resolved by the renderer, which knows the parent's
+    /// `grid-template-areas`: an item's `grid-area` becomes its
+    /// `grid_column` and `grid_row`.
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
+    // Positioning
+    pub position: Position,
+    /// Never contains `Unit::Unset`; undeclared sides are `Auto`.
+    pub inset: Edges<Unit>,
+    pub z_index: ZIndex,
+
+    // Visuals
+    /// Foreground. Never `Inherit`; `None` is the terminal default.
+    pub color: Color,
+    /// The color behind this element's content. Never `Inherit`; `None`
+    /// only when nothing up the tree paints a background.
+    pub background: Color,
+    pub border_style: Edges<BorderStyle>,
+    /// Never `Inherit`. Undeclared, `Inherit` and `None` fall back to `color`.
+    pub border_color: Edges<Color>,
+    pub border_collapse: BorderCollapse,
+    /// Clamped to `0.0..=1.0`.
+    pub opacity: Float,
+
+    // Typography
+    pub text_align: TextAlign,
+    pub word_break: WordBreak,
+    pub white_space: WhiteSpace,
+    /// `None`, or at least one line.
+    pub line_clamp: LineClamp,
+    pub font_style: FontStyle,
+    /// Never `Inherit`. `None` underlines in the text color.
+    pub underline_color: Color,
+
+    // Overflow
+    pub overflow: Overflow,
+    pub scrollbar_thumb: ComputedScrollbarPart,
+    pub scrollbar_track: ComputedScrollbarPart,
+}
+
+/// A [`ScrollbarPart`] as painted: glyph and color both resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComputedScrollbarPart {
+    pub glyph: char,
+    /// Never `Inherit`.
+    pub color: Color,
+    /// `true` if `color` was declared, here or on an ancestor, and so
+    /// passes to the children. Otherwise it is the text color.
+    pub declared: bool,
+}
+
+impl ComputedScrollbarPart {
+    const fn initial(glyph: char) -> Self {
+        Self {
+            glyph,
+            color: Color::None,
+            declared: false,
+        }
+    }
+
+    /// Resolve the `declared` part of an element whose text is `color`,
+    /// against its parent's part.
+    fn resolve(declared: Option<ScrollbarPart>, parent: Self, color: Color) -> Self {
+        let part = declared.unwrap_or_default();
+        let (color, declared) = match part.color {
+            None | Some(Color::Inherit) if parent.declared => (parent.color, true),
+            None | Some(Color::Inherit) => (color, false),
+            Some(c) => (c, true),
+        };
+        Self {
+            glyph: part.glyph.unwrap_or(parent.glyph),
+            color,
+            declared,
+        }
+    }
+}
+
+impl ComputedStyle {
+    /// Initial values — the computed style of the root's parent.
+    pub const ROOT: Self = Self {
+        width: Unit::Auto,
+        height: Unit::Auto,
+        min_width: Unit::ZERO,
+        min_height: Unit::ZERO,
+        max_width: Unit::Auto,
+        max_height: Unit::Auto,
+        padding: Edges::all(Unit::ZERO),
+        margin: Edges::all(Unit::ZERO),
+        display: Display::Block,
+        flex_direction: FlexDirection::Row,
+        flex_grow: Float::ZERO,
+        flex_shrink: Float::ONE,
+        align_items: Align::Stretch,
+        justify_content: Justify::Start,
+        gap: Unit::ZERO,
+        grid_template_columns: TrackList::EMPTY,
+        grid_template_rows: TrackList::EMPTY,
+        grid_column: GridPlacement::AUTO,
+        grid_row: GridPlacement::AUTO,
+        position: Position::Static,
+        inset: Edges::all(Unit::Auto),
+        z_index: ZIndex::Auto,
+        color: Color::None,
+        background: Color::None,
+        border_style: Edges::all(BorderStyle::None),
+        border_color: Edges::all(Color::None),
+        border_collapse: BorderCollapse::Separate,
+        opacity: Float::ONE,
+        text_align: TextAlign::Left,
+        word_break: WordBreak::Normal,
+        white_space: WhiteSpace::Normal,
+        line_clamp: LineClamp::None,
+        font_style: FontStyle::NORMAL,
+        underline_color: Color::None,
+        overflow: Overflow::Visible,
+        scrollbar_thumb: ComputedScrollbarPart::initial('█'),
+        scrollbar_track: ComputedScrollbarPart::initial('│'),
+    };
+
+    /// [`Self::ROOT`] with the default scrollbar glyphs for `charset`.
+    pub const fn root(charset: Charset) -> Self {
+        match charset {
+            Charset::Unicode => Self::ROOT,
+            Charset::Ascii => Self {
+                scrollbar_thumb: ComputedScrollbarPart::initial('#'),
+                scrollbar_track: ComputedScrollbarPart::initial('|'),
+                ..Self::ROOT
+            },
+        }
+    }
+
+    /// Resolve a cascaded `style` against its parent's computed style.
+    ///
+    /// Pass [`ComputedStyle::ROOT`] as `parent` for the root element, or
+    /// [`ComputedStyle::root`] to draw scrollbars in another charset.
+    pub fn resolve(style: &Style, parent: &ComputedStyle) -> Self {
+        let initial = &Self::ROOT;
+
+        let color = match style.color {
+            None | Some(Color::Inherit) => parent.color,
+            Some(c) => c,
+        };
+        let background = match style.background {
+            None | Some(Color::Inherit | Color::None) => parent.background,
+            Some(c) => c,
+        };
+        let border = style.border.unwrap_or(Edges::all(None));
+        let border_style = border.map(|b| b.and_then(|b| b.style).unwrap_or(BorderStyle::None));
+        let border_color = border.map(|b| match b.and_then(|b| b.color) {
+            None | Some(Color::Inherit | Color::None) => color,
+            Some(c) => c,
+        });
+
+        Self {
+            width: definite(style.width, initial.width),
+            height: definite(style.height, initial.height),
+            min_width: definite(style.min_width, initial.min_width),
+            min_height: definite(style.min_height, initial.min_height),
+            max_width: definite(style.max_width, initial.max_width),
+            max_height: definite(style.max_height, initial.max_height),
+            padding: edges(style.padding),
+            margin: edges(style.margin),
+
+            display: style.display.unwrap_or(initial.display),
+            flex_direction: style.flex_direction.unwrap_or(initial.flex_direction),
+            flex_grow: style.flex_grow.unwrap_or(initial.flex_grow),
+            flex_shrink: style.flex_shrink.unwrap_or(initial.flex_shrink),
+            align_items: style.align_items.unwrap_or(initial.align_items),
+            justify_content: style.justify_content.unwrap_or(initial.justify_content),
+            gap: definite(style.gap, initial.gap),
+            grid_template_columns: style.grid_template_columns.unwrap_or_else(|| {
+                let columns = style
+                    .grid_template_areas
+                    .as_ref()
+                    .map_or(0, |a| a.columns());
+                TrackList::repeat(columns, Unit::Auto)
+            }),
+            grid_template_rows: style.grid_template_rows.unwrap_or_else(|| {
+                let rows = style.grid_template_areas.as_ref().map_or(0, |a| a.rows());
+                TrackList::repeat(rows, Unit::Auto)
+            }),
+            grid_column: style.grid_column.unwrap_or(initial.grid_column),
+            grid_row: style.grid_row.unwrap_or(initial.grid_row),
+
+            position: style.position.unwrap_or(initial.position),
+            inset: style
+                .inset
+                .map_or(initial.inset, |e| e.map(|u| definite(Some(u), Unit::Auto))),
+            z_index: style.z_index.unwrap_or(initial.z_index),
+
+            color,
+            background,
+            border_style,
+            border_color,
+            border_collapse: style.border_collapse.unwrap_or(parent.border_collapse),
+            opacity: style.opacity.unwrap_or(initial.opacity).clamp_unit(),
+
+            text_align: style.text_align.unwrap_or(parent.text_align),
+            word_break: style.word_break.unwrap_or(parent.word_break),
+            white_space: style.white_space.unwrap_or(parent.white_space),
+            line_clamp: match style.line_clamp {
+                Some(LineClamp::Lines(n)) if n.get() > 0 => LineClamp::Lines(n),
+                _ => LineClamp::None,
+            },
+            font_style: style.font_style.unwrap_or(parent.font_style),
+            underline_color: match style.underline_color {
+                None | Some(Color::Inherit) => parent.underline_color,
+                Some(c) => c,
+            },
+
+            overflow: style.overflow.unwrap_or(initial.overflow),
+            scrollbar_thumb: ComputedScrollbarPart::resolve(
+                style.scrollbar_thumb,
+                parent.scrollbar_thumb,
+                color,
+            ),
+            scrollbar_track: ComputedScrollbarPart::resolve(
+                style.scrollbar_track,
+                parent.scrollbar_track,
+                color,
+            ),
+        }
+    }
+
+    /// `true` if the element takes part in layout and paint.
+    pub const fn is_displayed(&self) -> bool {
+        !matches!(self.display, Display::None)
+    }
+
+    /// `true` if the element takes room in its parent's layout: it is
+    /// displayed and not taken out of the flow by `position`.
+    pub const fn is_in_flow(&self) -> bool {
+        self.is_displayed() && !self.position.is_out_of_flow()
+    }
+
+    /// `true` if a border is drawn on any side.
+    pub fn has_border(&self) -> bool {
+        !self.border_style.all_satisfy(|s| s == BorderStyle::None)
+    }
+
+    /// Cells taken by the border on each side: `1` where one is drawn.
+    pub fn border_widths(&self) -> Edges<i32> {
+        self.border_style.map(|s| i32::from(s != BorderStyle::None))
+    }
+
+    /// This style with every color fitted to `depth` — see
+    /// [`Color::downsample`]. Applied last, just before paint.
+    pub fn downsample(mut self, depth: ColorDepth) -> Self {
+        self.color = self.color.downsample(depth);
+        self.background = self.background.downsample(depth);
+        self.border_color = self.border_color.map(|c| c.downsample(depth));
+        self.underline_color = self.underline_color.downsample(depth);
+        self
+    }
+
+    /// The complete ratatui style for this element's text: every field is
+    /// set, so nothing leaks through from underneath.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(&self) -> ratatui::style::Style {
+        ratatui::style::Style::new()
+            .fg(self.color.to_ratatui())
+            .bg(self.background.to_ratatui())
+            .underline_color(self.underline_color.to_ratatui())
+            .add_modifier(self.font_style.to_ratatui())
+            .remove_modifier(self.font_style.to_ratatui_cleared())
+    }
+}
+
+impl Default for ComputedStyle {
+    fn default() -> Self {
+        Self::ROOT
+    }
+}
+
+/// A declared unit, or `initial` if absent or `Unset`.
+fn definite(v: Option<Unit>, initial: Unit) -> Unit {
+    match v {
+        None | Some(Unit::Unset) => initial,
+        Some(u) => u,
+    }
+}
+
+/// Declared edges with `Unset` sides zeroed.
+fn edges(v: Option<Edges<Unit>>) -> Edges<Unit> {
+    v.map_or(Edges::all(Unit::ZERO), |e| {
+        e.map(|u| definite(Some(u), Unit::ZERO))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::border::Border;
+    use crate::color::NamedColor;
+    use crate::declaration::PROPERTIES;
+    use crate::number::Int;
+
+    const RED: Color = Color::Named(NamedColor::Red);
+    const BLUE: Color = Color::Named(NamedColor::Blue);
+
+    fn parent() -> ComputedStyle {
+        ComputedStyle::resolve(
+            &Style::new()
+                .with_color(RED)
+                .with_background(BLUE)
+                .with_font_style(FontStyle::BOLD)
+                .with_underline_color(BLUE)
+                .with_text_align(TextAlign::Right)
+                .with_white_space(WhiteSpace::Pre)
+                .with_scrollbar_thumb(ScrollbarPart::glyph('#'))
+                .with_line_clamp(LineClamp::Lines(Int::new(2)))
+                .with_border_collapse(BorderCollapse::Collapse)
+                .with_width(Unit::cells(10))
+                .with_padding_all(Unit::cells(1))
+                .with_opacity(Float::HALF)
+                .with_display(Display::Flex),
+            &ComputedStyle::ROOT,
+        )
+    }
+
+    #[test]
+    fn inherited_table_names_real_properties() {
+        for p in INHERITED {
+            assert!(PROPERTIES.contains(p), "{p}");
+        }
+    }
+
+    #[test]
+    fn empty_style_is_initial_values() {
+        assert_eq!(
+            ComputedStyle::resolve(&Style::new(), &ComputedStyle::ROOT),
+            ComputedStyle::ROOT
+        );
+    }
+
+    #[test]
+    fn inherited_properties_follow_parent() {
+        let c = ComputedStyle::resolve(&Style::new(), &parent());
+        assert_eq!(c.color, RED);
+        assert_eq!(c.font_style, FontStyle::BOLD);
+        assert_eq!(c.text_align, TextAlign::Right);
+        assert_eq!(c.white_space, WhiteSpace::Pre);
+        assert_eq!(c.scrollbar_thumb.glyph, '#');
+        assert_eq!(c.underline_color, BLUE);
+        assert_eq!(c.border_collapse, BorderCollapse::Collapse);
+    }
+
+    #[test]
+    fn box_model_is_not_inherited() {
+        let c = ComputedStyle::resolve(&Style::new(), &parent());
+        assert_eq!(c.width, Unit::Auto);
+        assert_eq!(c.padding, Edges::all(Unit::ZERO));
+        assert_eq!(c.display, Display::Block);
+        assert_eq!(c.opacity, Float::ONE);
+        assert_eq!(c.line_clamp, LineClamp::None);
+    }
+
+    #[test]
+    fn inherit_and_none_colors() {
+        let p = parent();
+        let c = ComputedStyle::resolve(
+            &Style::new()
+                .with_color(Color::Inherit)
+                .with_background(Color::None),
+            &p,
+        );
+        assert_eq!(c.color, RED);
+        assert_eq!(c.background, BLUE);
+
+        // `None` foreground is the terminal default, not the parent's.
+        let c = ComputedStyle::resolve(&Style::new().with_color(Color::None), &p);
+        assert_eq!(c.color, Color::None);
+        // Absent background shows the parent's.
+        assert_eq!(c.background, BLUE);
+    }
+
+    #[test]
+    fn scrollbar_colors_follow_the_text_until_declared() {
+        // Glyph declared on the parent, color not: the text color here.
+        let c = ComputedStyle::resolve(&Style::new().with_color(BLUE), &parent());
+        assert_eq!(c.scrollbar_thumb.glyph, '#');
+        assert_eq!(c.scrollbar_thumb.color, BLUE);
+        assert_eq!(c.scrollbar_track.glyph, '│');
+
+        // Declared, the color passes down, whatever the children's text.
+        let declared = Style::new().with_scrollbar_track(ScrollbarPart::default().with_color(RED));
+        let p = ComputedStyle::resolve(&declared, &ComputedStyle::ROOT);
+        let c = ComputedStyle::resolve(&Style::new().with_color(BLUE), &p);
+        assert_eq!(c.scrollbar_track.color, RED);
+
+        let ascii = ComputedStyle::root(Charset::Ascii);
+        let c = ComputedStyle::resolve(&Style::new(), &ascii);
+        assert_eq!(
+            (c.scrollbar_thumb.glyph, c.scrollbar_track.glyph),
+            ('#', '|')
+        );
+    }
+
+    #[test]
+    fn border_color_falls_back_to_foreground() {
+        let c = ComputedStyle::resolve(&Style::new().with_border(Border::ROUNDED), &parent());
+        assert_eq!(c.border_style, Edges::all(BorderStyle::Rounded));
+        assert_eq!(c.border_color, Edges::all(RED));
+        assert!(c.has_border());
+
+        let c = ComputedStyle::resolve(
+            &Style::new().with_border(Border::SOLID.with_color(BLUE)),
+            &parent(),
+        );
+        assert_eq!(c.border_color, Edges::all(BLUE));
+    }
+
+    #[test]
+    fn borders_resolve_per_side() {
+        let c = ComputedStyle::resolve(
+            &Style::new().with_border_sides(Edges {
+                bottom: Some(Border::SOLID.with_color(BLUE)),
+                ..Edges::all(None)
+            }),
+            &parent(),
+        );
+        assert_eq!(
+            c.border_style,
+            Edges::new(
+                BorderStyle::None,
+                BorderStyle::None,
+                BorderStyle::Solid,
+                BorderStyle::None
+            )
+        );
+        assert_eq!(c.border_color, Edges::new(RED, RED, BLUE, RED));
+        assert_eq!(c.border_widths(), Edges::new(0, 0, 1, 0));
+        assert!(c.has_border());
+        assert!(!ComputedStyle::ROOT.has_border());
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn to_ratatui_sets_every_field() {
+        use ratatui::style::{Color as R, Modifier as M};
+        let s = parent().to_ratatui();
+        assert_eq!((s.fg, s.bg), (Some(R::Red), Some(R::Blue)));
+        assert_eq!(s.underline_color, Some(R::Blue));
+        assert_eq!(s.add_modifier, M::BOLD);
+        assert!(s.sub_modifier.contains(M::ITALIC));
+
+        let root = ComputedStyle::ROOT.to_ratatui();
+        assert_eq!((root.fg, root.bg), (Some(R::Reset), Some(R::Reset)));
+    }
+
+    #[test]
+    fn unset_units_get_initial_values() {
+        let mut side = Edges::all(Unit::UNSET);
+        side.left = Unit::cells(3);
+        let c = ComputedStyle::resolve(
+            &Style::new()
+                .with_margin(side)
+                .with_width(Unit::UNSET)
+                .with_opacity(Float::new(4.0)),
+            &ComputedStyle::ROOT,
+        );
+        assert_eq!(
+            c.margin,
+            Edges::new(Unit::ZERO, Unit::ZERO, Unit::ZERO, Unit::cells(3))
+        );
+        assert_eq!(c.width, Unit::Auto);
+        assert_eq!(c.opacity, Float::ONE);
+    }
+
+    #[test]
+    fn grid_areas_imply_auto_tracks() {
+        use crate::grid::GridAreas;
+        let areas = GridAreas::parse(["head head", "side main", "foot foot"]);
+        let style = Style::new().with_grid_template_areas(areas.unwrap());
+        let c = ComputedStyle::resolve(&style, &ComputedStyle::ROOT);
+        assert_eq!(c.grid_template_columns, TrackList::repeat(2, Unit::Auto));
+        assert_eq!(c.grid_template_rows, TrackList::repeat(3, Unit::Auto));
+
+        let columns = TrackList::EMPTY.with(Unit::cells(20)).with(Unit::fill(1));
+        let c = ComputedStyle::resolve(
+            &style.with_grid_template_columns(columns),
+            &ComputedStyle::ROOT,
+        );
+        assert_eq!(c.grid_template_columns, columns);
+        assert_eq!(c.grid_column, GridPlacement::AUTO);
+    }
+
+    #[test]
+    fn undeclared_insets_are_auto() {
+        let mut inset = Edges::all(Unit::UNSET);
+        inset.top = Unit::cells(2);
+        let style = Style::new()
+            .with_position(Position::Absolute)
+            .with_inset(inset);
+        let c = ComputedStyle::resolve(&style, &ComputedStyle::ROOT);
+        assert_eq!(
+            c.inset,
+            Edges::new(Unit::cells(2), Unit::Auto, Unit::Auto, Unit::Auto)
+        );
+        assert!(c.is_displayed() && !c.is_in_flow());
+        assert_eq!(c.z_index, ZIndex::Auto);
+    }
+}
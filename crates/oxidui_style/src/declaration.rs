@@ -0,0 +1,1358 @@
+//! The property/value grammar shared by `scss!` and runtime stylesheets.
+//!
+//! Both front-ends reduce their input to `property: value` pairs and hand
+//! them to [`apply`]. Keeping the grammar in one place means the
+//! compile-time macro and the runtime parser can never disagree about what
+//! `padding: 1 2` or `border: rounded cyan` means.
+//!
+//! # Values
+//!
+//! | Kind      | Syntax                                               |
+//! |-----------|------------------------------------------------------|
+//! | size      | `10` (cells), `50%`, `1fr`, `auto`                   |
+//! | edges     | 1–4 sizes, CSS shorthand order (top right bottom left)|
+//! | color     | `#rgb`, `#rrggbb`, `rgb()`, `hsl()`, `ansi(n)`, CSS and ANSI names, `inherit`, `none` |
+//! | number    | `0.5`, `1`                                           |
+//! | integer   | `3`, for `line-clamp` (or `none`) and `z-index` (or `auto`) |
+//! | border    | `<style> [<color>]` in any order, for `border` and `border-<side>` |
+//! | font-style| space-separated flags: `bold italic underline` …     |
+//! | label     | `"<text>"`, then alignment, font flags and a color in any order |
+//! | string    | `"<text>"`, `\` escaping the next character          |
+//! | scrollbar | `"<glyph>" [<color>]` in any order, either optional   |
+//! | tracks    | sizes, `repeat(<count>, <sizes>)`, or `none`          |
+//! | areas     | one quoted string of names per row, `.` for no name, or `none` |
+//! | grid line | `auto`, `<n>` or `span <n>`, optionally `/ <end>`     |
+//!
+//! # Example
+//!
+//! ```rust
+//! # use oxidui_style::{Style, declaration, unit::Unit, border::Edges};
+//! let mut s = Style::new();
+//! declaration::apply(&mut s, "padding", "1 2").unwrap();
+//! assert_eq!(s.padding, Some(Edges::symmetric(Unit::cells(1), Unit::cells(2))));
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::Style;
+use crate::border::{Border, BorderCollapse, BorderLabel, BorderStyle, Edges};
+use crate::color::Color;
+use crate::font::FontStyle;
+use crate::grid::{GridAreas, GridAreasError, GridPlacement, TrackList};
+use crate::layout::{
+    Align, Display, FlexDirection, Justify, LineClamp, Overflow, Position, ScrollbarPart,
+    TextAlign, TextOverflow, WhiteSpace, WordBreak, ZIndex,
+};
+use crate::number::{Float, Int};
+use crate::str::Str;
+use crate::unit::Unit;
+
+/// Every property name [`apply`] understands, in `Style` field order.
+pub const PROPERTIES: &[&str] = &[
+    "width",
+    "height",
+    "min-width",
+    "min-height",
+    "max-width",
+    "max-height",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "display",
+    "flex-direction",
+    "flex-grow",
+    "flex-shrink",
+    "align-items",
+    "justify-content",
+    "gap",
+    "grid-template-columns",
+    "grid-template-rows",
+    "grid-template-areas",
+    "grid-column",
+    "grid-row",
+    "grid-area",
+    "position",
+    "inset",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "z-index",
+    "color",
+    "background",
+    "background-color",
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+    "border-style",
+    "border-color",
+    "border-title",
+    "border-footer",
+    "border-collapse",
+    "opacity",
+    "text-align",
+    "word-break",
+    "white-space",
+    "text-overflow",
+    "line-clamp",
+    "font-style",
+    "underline-color",
+    "overflow",
+    "scrollbar-thumb",
+    "scrollbar-track",
+];
+
+/// Why a declaration was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeclarationErrorKind {
+    /// The property name is not in [`PROPERTIES`].
+    UnknownProperty(String),
+    /// The value is empty.
+    MissingValue,
+    /// A value component could not be parsed.
+    InvalidValue {
+        /// The offending component.
+        found: String,
+        /// Human-readable description of what was expected.
+        expected: &'static str,
+    },
+    /// More components than the property accepts.
+    UnexpectedValue(String),
+}
+
+/// A rejected declaration.
+///
+/// `range` is the byte range of the offending component **within the value
+/// string**, so callers can point at the exact token. It is empty (`0..0`)
+/// for [`DeclarationErrorKind::UnknownProperty`] and
+/// [`DeclarationErrorKind::MissingValue`], which concern the property itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeclarationError {
+    pub kind: DeclarationErrorKind,
+    pub range: Range<usize>,
+}
+
+impl DeclarationError {
+    fn new(kind: DeclarationErrorKind, range: Range<usize>) -> Self {
+        Self { kind, range }
+    }
+
+    fn invalid(c: Component, expected: &'static str) -> Self {
+        Self::new(
+            DeclarationErrorKind::InvalidValue {
+                found: c.text.to_owned(),
+                expected,
+            },
+            c.range(),
+        )
+    }
+
+    /// `true` if the error is about the property name rather than its value.
+    pub fn is_property_error(&self) -> bool {
+        matches!(self.kind, DeclarationErrorKind::UnknownProperty(_))
+    }
+}
+
+impl fmt::Display for DeclarationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DeclarationErrorKind::UnknownProperty(p) => write!(f, "unknown property `{p}`"),
+            DeclarationErrorKind::MissingValue => f.write_str("missing value"),
+            DeclarationErrorKind::InvalidValue { found, expected } => {
+                write!(f, "invalid value `{found}`: expected {expected}")
+            }
+            DeclarationErrorKind::UnexpectedValue(v) => write!(f, "unexpected value `{v}`"),
+        }
+    }
+}
+
+impl std::error::Error for DeclarationError {}
+
+/// Parse `value` for `property` and store it in `style`.
+///
+/// Longhands such as `padding-top` update one side of the corresponding
+/// [`Edges`], leaving the other sides as they were (or [`Unit::Unset`] if
+/// the shorthand was never declared).
+pub fn apply(style: &mut Style, property: &str, value: &str) -> Result<(), DeclarationError> {
+    if !PROPERTIES.contains(&property) {
+        return Err(unknown_property(property));
+    }
+    let parts = components(value);
+    if parts.is_empty() {
+        return Err(DeclarationError::new(
+            DeclarationErrorKind::MissingValue,
+            0..0,
+        ));
+    }
+
+    match property {
+        "width" => style.width = Some(single(&parts, size)?),
+        "height" => style.height = Some(single(&parts, size)?),
+        "min-width" => style.min_width = Some(single(&parts, size)?),
+        "min-height" => style.min_height = Some(single(&parts, size)?),
+        "max-width" => style.max_width = Some(single(&parts, size)?),
+        "max-height" => style.max_height = Some(single(&parts, size)?),
+
+        "padding" => style.padding = Some(edges(&parts, size)?),
+        "padding-top" => side(&mut style.padding, &parts, size, |e| &mut e.top)?,
+        "padding-right" => side(&mut style.padding, &parts, size, |e| &mut e.right)?,
+        "padding-bottom" => side(&mut style.padding, &parts, size, |e| &mut e.bottom)?,
+        "padding-left" => side(&mut style.padding, &parts, size, |e| &mut e.left)?,
+        "margin" => style.margin = Some(edges(&parts, offset)?),
+        "margin-top" => side(&mut style.margin, &parts, offset, |e| &mut e.top)?,
+        "margin-right" => side(&mut style.margin, &parts, offset, |e| &mut e.right)?,
+        "margin-bottom" => side(&mut style.margin, &parts, offset, |e| &mut e.bottom)?,
+        "margin-left" => side(&mut style.margin, &parts, offset, |e| &mut e.left)?,
+
+        "display" => style.display = Some(single(&parts, display)?),
+        "flex-direction" => style.flex_direction = Some(single(&parts, flex_direction)?),
+        "flex-grow" => style.flex_grow = Some(single(&parts, factor)?),
+        "flex-shrink" => style.flex_shrink = Some(single(&parts, factor)?),
+        "align-items" => style.align_items = Some(single(&parts, align)?),
+        "justify-content" => style.justify_content = Some(single(&parts, justify)?),
+        "gap" => style.gap = Some(single(&parts, size)?),
+        "grid-template-columns" => style.grid_template_columns = Some(track_list(&parts)?),
+        "grid-template-rows" => style.grid_template_rows = Some(track_list(&parts)?),
+        "grid-template-areas" => style.grid_template_areas = Some(grid_areas(&parts)?),
+        "grid-column" => style.grid_column = Some(grid_placement(&parts)?),
+        "grid-row" => style.grid_row = Some(grid_placement(&parts)?),
+        "grid-area" => style.grid_area = Some(single(&parts, area_name)?),
+
+        "position" => style.position = Some(single(&parts, position)?),
+        "inset" => style.inset = Some(edges(&parts, offset)?),
+        "top" => side(&mut style.inset, &parts, offset, |e| &mut e.top)?,
+        "right" => side(&mut style.inset, &parts, offset, |e| &mut e.right)?,
+        "bottom" => side(&mut style.inset, &parts, offset, |e| &mut e.bottom)?,
+        "left" => side(&mut style.inset, &parts, offset, |e| &mut e.left)?,
+        "z-index" => style.z_index = Some(single(&parts, z_index)?),
+
+        "color" => style.color = Some(single(&parts, color)?),
+        "background" | "background-color" => style.background = Some(single(&parts, color)?),
+        "border" => style.border = Some(Edges::all(Some(border(&parts)?))),
+        "border-top" => border_side(style, &parts, |e| &mut e.top)?,
+        "border-right" => border_side(style, &parts, |e| &mut e.right)?,
+        "border-bottom" => border_side(style, &parts, |e| &mut e.bottom)?,
+        "border-left" => border_side(style, &parts, |e| &mut e.left)?,
+        "border-style" => {
+            let v = single(&parts, border_style)?;
+            every_border_side(
+                style,
+                Border {
+                    style: Some(v),
+                    color: None,
+                },
+            );
+        }
+        "border-color" => {
+            let v = single(&parts, color)?;
+            every_border_side(
+                style,
+                Border {
+                    style: None,
+                    color: Some(v),
+                },
+            );
+        }
+        "border-title" => style.border_title = Some(border_label(&parts)?),
+        "border-footer" => style.border_footer = Some(border_label(&parts)?),
+        "border-collapse" => style.border_collapse = Some(single(&parts, border_collapse)?),
+        "opacity" => style.opacity = Some(single(&parts, opacity)?),
+
+        "text-align" => style.text_align = Some(single(&parts, text_align)?),
+        "word-break" => style.word_break = Some(single(&parts, word_break)?),
+        "white-space" => style.white_space = Some(single(&parts, white_space)?),
+        "text-overflow" => style.text_overflow = Some(single(&parts, text_overflow)?),
+        "line-clamp" => style.line_clamp = Some(single(&parts, line_clamp)?),
+        "font-style" => style.font_style = Some(font_style(&parts)?),
+        "underline-color" => style.underline_color = Some(single(&parts, color)?),
+
+        "overflow" => style.overflow = Some(single(&parts, overflow)?),
+        "scrollbar-thumb" => style.scrollbar_thumb = Some(scrollbar_part(&parts)?),
+        "scrollbar-track" => style.scrollbar_track = Some(scrollbar_part(&parts)?),
+
+        _ => return Err(unknown_property(property)),
+    }
+    Ok(())
+}
+
+fn unknown_property(property: &str) -> DeclarationError {
+    DeclarationError::new(
+        DeclarationErrorKind::UnknownProperty(property.to_owned()),
+        0..0,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// One whitespace-separated word of a value, with its byte offset.
+///
+/// Parenthesised groups (`rgb(1, 2, 3)`) and quoted strings (`"two
+/// words"`) stay in a single component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Component<'a> {
+    pub text: &'a str,
+    pub offset: usize,
+}
+
+impl Component<'_> {
+    fn range(self) -> Range<usize> {
+        self.offset..self.offset + self.text.len()
+    }
+}
+
+/// Split a value into whitespace-separated components.
+pub fn components(value: &str) -> Vec<Component<'_>> {
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+    let (mut quoted, mut escaped) = (false, false);
+    for (i, ch) in value.char_indices() {
+        if quoted {
+            match ch {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => quoted = false,
+                _ => {}
+            }
+            continue;
+        }
+        match ch {
+            '"' => quoted = true,
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    out.push(Component {
+                        text: &value[s..i],
+                        offset: s,
+                    });
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        out.push(Component {
+            text: &value[s..],
+            offset: s,
+        });
+    }
+    out
+}
+
+type ParseFn<T> = fn(Component) -> Result<T, DeclarationError>;
+
+/// Exactly one component.
+fn single<T>(parts: &[Component], f: ParseFn<T>) -> Result<T, DeclarationError> {
+    let v = f(parts[0])?;
+    reject_extra(parts, 1)?;
+    Ok(v)
+}
+
+fn reject_extra(parts: &[Component], max: usize) -> Result<(), DeclarationError> {
+    match parts.get(max) {
+        Some(extra) => Err(DeclarationError::new(
+            DeclarationErrorKind::UnexpectedValue(extra.text.to_owned()),
+            extra.range(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// 1–4 values in CSS shorthand order.
+fn edges(parts: &[Component], f: ParseFn<Unit>) -> Result<Edges<Unit>, DeclarationError> {
+    reject_extra(parts, 4)?;
+    let v = parts.iter().map(|c| f(*c)).collect::<Result<Vec<_>, _>>()?;
+    Ok(match v[..] {
+        [all] => Edges::all(all),
+        [vertical, horizontal] => Edges::symmetric(vertical, horizontal),
+        [top, horizontal, bottom] => Edges::new(top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => Edges::new(top, right, bottom, left),
+        _ => {
+            return Err(DeclarationError::new(
+                DeclarationErrorKind::MissingValue,
+                0..0,
+            ));
+        }
+    })
+}
+
+/// A single side longhand (`padding-top`, …).
+fn side(
+    target: &mut Option<Edges<Unit>>,
+    parts: &[Component],
+    f: ParseFn<Unit>,
+    pick: fn(&mut Edges<Unit>) -> &mut Unit,
+) -> Result<(), DeclarationError> {
+    let v = single(parts, f)?;
+    *pick(target.get_or_insert(Edges::all(Unit::UNSET))) = v;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Scalar values
+// ---------------------------------------------------------------------------
+
+const SIZE: &str = "a size (`10`, `50%`, `1fr` or `auto`)";
+const OFFSET: &str = "a size (`10`, `-1`, `50%`, `1fr` or `auto`)";
+
+/// Parse a [`Unit`]. Negative cell counts are accepted; callers decide
+/// whether that is meaningful.
+pub fn parse_unit(s: &str) -> Option<Unit> {
+    if s == "auto" {
+        return Some(Unit::AUTO);
+    }
+    if let Some(n) = s.strip_suffix('%') {
+        return n.parse().ok().map(Unit::Percent);
+    }
+    if let Some(n) = s.strip_suffix("fr") {
+        return n.parse().ok().map(Unit::Fill);
+    }
+    s.parse().ok().map(Unit::Cells)
+}
+
+/// A non-negative size.
+fn size(c: Component) -> Result<Unit, DeclarationError> {
+    match parse_unit(c.text) {
+        Some(Unit::Cells(n)) if n < 0 => Err(DeclarationError::invalid(c, SIZE)),
+        Some(u) => Ok(u),
+        None => Err(DeclarationError::invalid(c, SIZE)),
+    }
+}
+
+/// A size that may be negative (margins).
+fn offset(c: Component) -> Result<Unit, DeclarationError> {
+    parse_unit(c.text).ok_or_else(|| DeclarationError::invalid(c, OFFSET))
+}
+
+/// Parse a finite floating-point number.
+pub fn parse_float(s: &str) -> Option<Float> {
+    // `f32::from_str` also accepts `inf` and `NaN`, neither of which is a
+    // valid style value.
+    let first = s.bytes().next()?;
+    if !(first.is_ascii_digit() || matches!(first, b'.' | b'-' | b'+')) {
+        return None;
+    }
+    s.parse::<f32>().ok().filter(|v| v.is_finite()).map(Float)
+}
+
+/// Parse an integer.
+pub fn parse_int(s: &str) -> Option<Int> {
+    s.parse::<i32>().ok().map(Int)
+}
+
+/// `none`, or how many lines to keep (`line-clamp`).
+fn line_clamp(c: Component) -> Result<LineClamp, DeclarationError> {
+    const EXPECTED: &str = "`none` or a positive integer";
+    if c.text == "none" {
+        return Ok(LineClamp::None);
+    }
+    match parse_int(c.text) {
+        Some(n) if n.get() > 0 => Ok(LineClamp::Lines(n)),
+        _ => Err(DeclarationError::invalid(c, EXPECTED)),
+    }
+}
+
+/// `auto`, or a paint order (`z-index`).
+fn z_index(c: Component) -> Result<ZIndex, DeclarationError> {
+    if c.text == "auto" {
+        return Ok(ZIndex::Auto);
+    }
+    parse_int(c.text)
+        .map(ZIndex::Index)
+        .ok_or_else(|| DeclarationError::invalid(c, "`auto` or an integer"))
+}
+
+/// A non-negative factor (`flex-grow`, `flex-shrink`).
+fn factor(c: Component) -> Result<Float, DeclarationError> {
+    const EXPECTED: &str = "a non-negative number";
+    match parse_float(c.text) {
+        Some(v) if v.get() >= 0.0 => Ok(v),
+        _ => Err(DeclarationError::invalid(c, EXPECTED)),
+    }
+}
+
+fn opacity(c: Component) -> Result<Float, DeclarationError> {
+    const EXPECTED: &str = "a number between 0 and 1";
+    match parse_float(c.text) {
+        Some(v) if (0.0..=1.0).contains(&v.get()) => Ok(v),
+        _ => Err(DeclarationError::invalid(c, EXPECTED)),
+    }
+}
+
+/// Parse a [`Color`] in any syntax [`Color::parse`] accepts.
+pub fn parse_color(s: &str) -> Option<Color> {
+    Color::parse(s).ok()
+}
+
+fn color(c: Component) -> Result<Color, DeclarationError> {
+    Color::parse(c.text).map_err(|err| DeclarationError::invalid(c, err.expected()))
+}
+
+// ---------------------------------------------------------------------------
+// Keywords
+// ---------------------------------------------------------------------------
+
+/// Declare a keyword parser: `keyword!(name -> Type, "expected", { "kw" => value, … })`.
+macro_rules! keyword {
+    ($name:ident -> $ty:ty, $expected:literal, { $($kw:literal => $v:expr),+ $(,)? }) => {
+        fn $name(c: Component) -> Result<$ty, DeclarationError> {
+            match c.text {
+                $($kw => Ok($v),)+
+                _ => Err(DeclarationError::invalid(c, $expected)),
+            }
+        }
+    };
+}
+
+keyword!(display -> Display, "`block`, `flex`, `grid` or `none`", {
+    "block" => Display::Block,
+    "flex" => Display::Flex,
+    "grid" => Display::Grid,
+    "none" => Display::None,
+});
+
+keyword!(flex_direction -> FlexDirection,
+    "`row`, `column`, `row-reverse` or `column-reverse`", {
+    "row" => FlexDirection::Row,
+    "column" => FlexDirection::Column,
+    "row-reverse" => FlexDirection::RowReverse,
+    "column-reverse" => FlexDirection::ColumnReverse,
+});
+
+keyword!(align -> Align, "`start`, `stretch`, `center`, `end` or `baseline`", {
+    "start" => Align::Start,
+    "flex-start" => Align::Start,
+    "stretch" => Align::Stretch,
+    "center" => Align::Center,
+    "end" => Align::End,
+    "flex-end" => Align::End,
+    "baseline" => Align::Baseline,
+});
+
+keyword!(justify -> Justify,
+    "`start`, `center`, `end`, `space-between`, `space-around` or `space-evenly`", {
+    "start" => Justify::Start,
+    "flex-start" => Justify::Start,
+    "center" => Justify::Center,
+    "end" => Justify::End,
+    "flex-end" => Justify::End,
+    "space-between" => Justify::SpaceBetween,
+    "space-around" => Justify::SpaceAround,
+    "space-evenly" => Justify::SpaceEvenly,
+});
+
+keyword!(text_align -> TextAlign, "`left`, `center`, `right` or `justify`", {
+    "left" => TextAlign::Left,
+    "center" => TextAlign::Center,
+    "right" => TextAlign::Right,
+    "justify" => TextAlign::Justify,
+});
+
+keyword!(word_break -> WordBreak, "`normal` or `break-all`", {
+    "normal" => WordBreak::Normal,
+    "break-all" => WordBreak::BreakAll,
+});
+
+keyword!(white_space -> WhiteSpace, "`normal`, `nowrap`, `pre` or `pre-wrap`", {
+    "normal" => WhiteSpace::Normal,
+    "nowrap" => WhiteSpace::NoWrap,
+    "pre" => WhiteSpace::Pre,
+    "pre-wrap" => WhiteSpace::PreWrap,
+});
+
+keyword!(position -> Position, "`static`, `relative`, `absolute` or `fixed`", {
+    "static" => Position::Static,
+    "relative" => Position::Relative,
+    "absolute" => Position::Absolute,
+    "fixed" => Position::Fixed,
+});
+
+keyword!(overflow -> Overflow, "`visible`, `hidden` or `scroll`", {
+    "visible" => Overflow::Visible,
+    "hidden" => Overflow::Hidden,
+    "scroll" => Overflow::Scroll,
+});
+
+keyword!(border_collapse -> BorderCollapse, "`separate` or `collapse`", {
+    "separate" => BorderCollapse::Separate,
+    "collapse" => BorderCollapse::Collapse,
+});
+
+/// A built-in border style or a registered glyph set.
+fn border_style(c: Component) -> Result<BorderStyle, DeclarationError> {
+    BorderStyle::named(c.text).ok_or_else(|| {
+        DeclarationError::invalid(
+            c,
+            "a border style (`none`, `solid`, `rounded`, `double`, `thick`, `dashed`, `ascii` \
+             or a registered glyph set)",
+        )
+    })
+}
+
+keyword!(font_flag -> FontStyle,
+    "a font style (`normal`, `bold`, `italic`, `underline`, `double-underline`, `curly-underline`, \
+     `blink`, `rapid-blink`, `strikethrough`, `dim`, `reverse`, `hidden` or `overline`)", {
+    "normal" => FontStyle::NORMAL,
+    "bold" => FontStyle::BOLD,
+    "italic" => FontStyle::ITALIC,
+    "underline" => FontStyle::UNDERLINE,
+    "blink" => FontStyle::BLINK,
+    "strikethrough" => FontStyle::STRIKETHROUGH,
+    "dim" => FontStyle::DIM,
+    "reverse" => FontStyle::REVERSE,
+    "hidden" => FontStyle::HIDDEN,
+    "rapid-blink" => FontStyle::RAPID_BLINK,
+    "overline" => FontStyle::OVERLINE,
+    "double-underline" => FontStyle::DOUBLE_UNDERLINE,
+    "curly-underline" => FontStyle::CURLY_UNDERLINE,
+});
+
+/// `<style> [<color>]`, in either order.
+fn border(parts: &[Component]) -> Result<Border, DeclarationError> {
+    reject_extra(parts, 2)?;
+    let mut out = Border::default();
+    let (mut seen_style, mut seen_color) = (false, false);
+    for &c in parts {
+        if !seen_style && let Ok(s) = border_style(c) {
+            out.style = Some(s);
+            seen_style = true;
+        } else if !seen_color && let Some(col) = parse_color(c.text) {
+            out.color = Some(col);
+            seen_color = true;
+        } else {
+            return Err(DeclarationError::invalid(
+                c,
+                "a border style and/or a color (`rounded cyan`)",
+            ));
+        }
+    }
+    // `border: cyan` alone means a solid border in that color.
+    out.style.get_or_insert(BorderStyle::Solid);
+    Ok(out)
+}
+
+/// `"<glyph>" [<color>]` in any order, either one optional
+/// (`scrollbar-thumb`, `scrollbar-track`).
+fn scrollbar_part(parts: &[Component]) -> Result<ScrollbarPart, DeclarationError> {
+    reject_extra(parts, 2)?;
+    let mut out = ScrollbarPart::default();
+    for &c in parts {
+        if out.glyph.is_none() && c.text.starts_with('"') {
+            let glyph = string(c)?;
+            let mut chars = glyph.as_str().chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => out.glyph = Some(ch),
+                _ => return Err(DeclarationError::invalid(c, "a single character (`\"█\"`)")),
+            }
+        } else if out.color.is_none()
+            && let Some(col) = parse_color(c.text)
+        {
+            out.color = Some(col);
+        } else {
+            return Err(DeclarationError::invalid(
+                c,
+                "a quoted glyph and/or a color (`\"█\" cyan`)",
+            ));
+        }
+    }
+    Ok(out)
+}
+
+/// A single border side (`border-top`, …). The other sides stay
+/// undeclared.
+fn border_side(
+    style: &mut Style,
+    parts: &[Component],
+    pick: fn(&mut Edges<Option<Border>>) -> &mut Option<Border>,
+) -> Result<(), DeclarationError> {
+    let v = border(parts)?;
+    *pick(style.border.get_or_insert(Edges::all(None))) = Some(v);
+    Ok(())
+}
+
+/// Declare one field of every side for `border-style` and `border-color`,
+/// leaving the other as it was.
+fn every_border_side(style: &mut Style, part: Border) {
+    let e = style.border.get_or_insert(Edges::all(None));
+    for side in [&mut e.top, &mut e.right, &mut e.bottom, &mut e.left] {
+        *side = Some(side.map_or(part, |b| b.merge(part)));
+    }
+}
+
+/// A double-quoted string. `\` escapes the next character.
+fn string(c: Component) -> Result<Str, DeclarationError> {
+    let err = || DeclarationError::invalid(c, "a quoted string (`\"Files\"`)");
+    let inner = c
+        .text
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .ok_or_else(err)?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => out.push(chars.next().ok_or_else(err)?),
+            '"' => return Err(err()),
+            ch => out.push(ch),
+        }
+    }
+    Ok(Str::from_string(out))
+}
+
+/// `clip`, `ellipsis`, `middle-ellipsis` or a quoted marker.
+fn text_overflow(c: Component) -> Result<TextOverflow, DeclarationError> {
+    match c.text {
+        "clip" => Ok(TextOverflow::Clip),
+        "ellipsis" => Ok(TextOverflow::Ellipsis),
+        "middle-ellipsis" => Ok(TextOverflow::MiddleEllipsis),
+        _ if c.text.starts_with('"') => string(c).map(TextOverflow::Marker),
+        _ => Err(DeclarationError::invalid(
+            c,
+            "`clip`, `ellipsis`, `middle-ellipsis` or a quoted marker",
+        )),
+    }
+}
+
+/// `"<text>"` followed by an alignment, font flags and a color in any
+/// order.
+fn border_label(parts: &[Component]) -> Result<BorderLabel, DeclarationError> {
+    let mut label = BorderLabel::new(string(parts[0])?);
+    let (mut seen_align, mut seen_color) = (false, false);
+    for &c in &parts[1..] {
+        if !seen_align && let Ok(a) = text_align(c) {
+            label.align = a;
+            seen_align = true;
+        } else if let Ok(f) = font_flag(c) {
+            label.font_style = Some(label.font_style.unwrap_or_default() | f);
+        } else if !seen_color && let Some(col) = parse_color(c.text) {
+            label.color = Some(col);
+            seen_color = true;
+        } else {
+            return Err(DeclarationError::invalid(
+                c,
+                "an alignment, font style or color after the label (`\"Files\" center bold cyan`)",
+            ));
+        }
+    }
+    Ok(label)
+}
+
+/// Space-separated flags, OR-ed together.
+fn font_style(parts: &[Component]) -> Result<FontStyle, DeclarationError> {
+    parts
+        .iter()
+        .try_fold(FontStyle::NORMAL, |acc, &c| Ok(acc | font_flag(c)?))
+}
+
+// ---------------------------------------------------------------------------
+// Grid
+// ---------------------------------------------------------------------------
+
+/// Track sizes, with `repeat(<count>, <sizes>)` expanded in place, or
+/// `none` (`grid-template-columns`, `grid-template-rows`).
+fn track_list(parts: &[Component]) -> Result<TrackList, DeclarationError> {
+    const REPEAT: &str = "`repeat(<count>, <sizes>)`";
+    const TOO_MANY: &str = "at most 16 tracks";
+    if parts[0].text == "none" {
+        reject_extra(parts, 1)?;
+        return Ok(TrackList::EMPTY);
+    }
+    let mut tracks = TrackList::EMPTY;
+    for &c in parts {
+        let sizes = match c.text.strip_prefix("repeat(") {
+            Some(inner) => {
+                let inner = inner
+                    .strip_suffix(')')
+                    .ok_or_else(|| DeclarationError::invalid(c, REPEAT))?;
+                let (count, sizes) = inner
+                    .split_once(',')
+                    .ok_or_else(|| DeclarationError::invalid(c, REPEAT))?;
+                let count: usize = count
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| DeclarationError::invalid(c, REPEAT))?;
+                // Offsets within the value, for errors in the sizes.
+                let base = c.offset + "repeat(".len() + inner.len() - sizes.len();
+                let sizes = components(sizes)
+                    .into_iter()
+                    .map(|s| {
+                        size(Component {
+                            text: s.text,
+                            offset: base + s.offset,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if sizes.is_empty() {
+                    return Err(DeclarationError::invalid(c, REPEAT));
+                }
+                sizes.repeat(count.min(TrackList::MAX + 1))
+            }
+            None => vec![size(c)?],
+        };
+        for track in sizes {
+            if tracks.len() == TrackList::MAX {
+                return Err(DeclarationError::invalid(c, TOO_MANY));
+            }
+            tracks = tracks.with(track);
+        }
+    }
+    Ok(tracks)
+}
+
+/// One quoted string of area names per row, or `none`
+/// (`grid-template-areas`).
+fn grid_areas(parts: &[Component]) -> Result<GridAreas, DeclarationError> {
+    if parts[0].text == "none" {
+        reject_extra(parts, 1)?;
+        return Ok(GridAreas::NONE);
+    }
+    let rows = parts
+        .iter()
+        .map(|&c| string(c))
+        .collect::<Result<Vec<_>, _>>()?;
+    GridAreas::parse(rows.iter().map(Str::as_str)).map_err(|err| {
+        let cells = |i: usize| rows[i].as_str().split_whitespace();
+        let (row, expected) = match &err {
+            GridAreasError::Empty => (
+                (0..rows.len()).find(|&i| cells(i).next().is_none()),
+                "at least one name per row",
+            ),
+            GridAreasError::Ragged => (
+                (0..rows.len()).find(|&i| cells(i).count() != cells(0).count()),
+                "the same number of names in every row",
+            ),
+            GridAreasError::InvalidName(name) => (
+                (0..rows.len()).find(|&i| cells(i).any(|n| n == name)),
+                "names of letters, digits, `-` and `_`, or `.`",
+            ),
+            GridAreasError::NotRectangular(name) => (
+                (0..rows.len()).find(|&i| cells(i).any(|n| n == name)),
+                "areas that form rectangles",
+            ),
+        };
+        DeclarationError::invalid(parts[row.unwrap_or(0)], expected)
+    })
+}
+
+/// One end of a grid item's placement.
+#[derive(Clone, Copy)]
+enum Line {
+    Auto,
+    At(u16),
+    Span(u16),
+}
+
+/// `<line> [/ <line>]`, each `auto`, a track number from 1 or
+/// `span <count>` (`grid-column`, `grid-row`).
+fn grid_placement(parts: &[Component]) -> Result<GridPlacement, DeclarationError> {
+    const LINE: &str = "`auto`, a track number from 1 or `span <count>`";
+    // `1/3` is one component; split it into `1`, `/`, `3`.
+    let mut words = Vec::new();
+    for c in parts {
+        let mut offset = c.offset;
+        for (i, piece) in c.text.split('/').enumerate() {
+            if i > 0 {
+                words.push(Component {
+                    text: "/",
+                    offset: offset - 1,
+                });
+            }
+            if !piece.is_empty() {
+                words.push(Component {
+                    text: piece,
+                    offset,
+                });
+            }
+            offset += piece.len() + 1;
+        }
+    }
+    let count = |c: Component| {
+        c.text
+            .parse::<u16>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| DeclarationError::invalid(c, LINE))
+    };
+    let mut rest = &words[..];
+    let line = |rest: &mut &[Component<'_>], after: Component| {
+        let (&first, tail) = rest
+            .split_first()
+            .ok_or_else(|| DeclarationError::invalid(after, LINE))?;
+        *rest = tail;
+        Ok(match first.text {
+            "auto" => Line::Auto,
+            "span" => {
+                let (&n, tail) = rest
+                    .split_first()
+                    .ok_or_else(|| DeclarationError::invalid(first, LINE))?;
+                *rest = tail;
+                Line::Span(count(n)?)
+            }
+            _ => Line::At(count(first)?),
+        })
+    };
+    let start = line(&mut rest, parts[0])?;
+    let end = match rest.split_first() {
+        Some((&slash, tail)) if slash.text == "/" => {
+            rest = tail;
+            Some(line(&mut rest, slash)?)
+        }
+        _ => None,
+    };
+    reject_extra(rest, 0)?;
+
+    Ok(match (start, end.unwrap_or(Line::Auto)) {
+        (Line::Auto, Line::Auto) => GridPlacement::AUTO,
+        (Line::At(a), Line::Auto) => GridPlacement::at(a),
+        (Line::Span(n), Line::Auto | Line::Span(_)) | (Line::Auto, Line::Span(n)) => {
+            GridPlacement::span(n)
+        }
+        // Lines given the wrong way round are swapped, as in CSS.
+        (Line::At(a), Line::At(b)) => GridPlacement::at(a.min(b)).with_span(a.abs_diff(b)),
+        (Line::At(a), Line::Span(n)) => GridPlacement::at(a).with_span(n),
+        (Line::Span(n), Line::At(b)) => {
+            let a = b.saturating_sub(n).max(1);
+            GridPlacement::at(a).with_span(b - a)
+        }
+        (Line::Auto, Line::At(b)) => GridPlacement::at(b.saturating_sub(1).max(1)),
+    })
+}
+
+/// An area name (`grid-area`).
+fn area_name(c: Component) -> Result<Str, DeclarationError> {
+    let valid = !c.text.is_empty()
+        && c.text
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || ch == '-' || ch == '_');
+    if !valid {
+        return Err(DeclarationError::invalid(
+            c,
+            "an area name of letters, digits, `-` and `_`",
+        ));
+    }
+    Ok(Str::from_string(c.text.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::NamedColor;
+
+    fn parse(property: &str, value: &str) -> Result<Style, DeclarationError> {
+        let mut s = Style::new();
+        apply(&mut s, property, value).map(|()| s)
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(parse_unit("10"), Some(Unit::cells(10)));
+        assert_eq!(parse_unit("-1"), Some(Unit::cells(-1)));
+        assert_eq!(parse_unit("50%"), Some(Unit::percent(50)));
+        assert_eq!(parse_unit("2fr"), Some(Unit::fill(2)));
+        assert_eq!(parse_unit("auto"), Some(Unit::AUTO));
+        assert_eq!(parse_unit("1.5"), None);
+        assert_eq!(parse_unit("300%"), None);
+    }
+
+    #[test]
+    fn edges_shorthand() {
+        let c = Unit::cells;
+        assert_eq!(
+            parse("padding", "1").map(|s| s.padding),
+            Ok(Some(Edges::all(c(1))))
+        );
+        assert_eq!(
+            parse("padding", "1 2").map(|s| s.padding),
+            Ok(Some(Edges::symmetric(c(1), c(2))))
+        );
+        assert_eq!(
+            parse("padding", "1 2 3").map(|s| s.padding),
+            Ok(Some(Edges::new(c(1), c(2), c(3), c(2))))
+        );
+        assert_eq!(
+            parse("margin", "1 2 3 -4").map(|s| s.margin),
+            Ok(Some(Edges::new(c(1), c(2), c(3), c(-4))))
+        );
+    }
+
+    #[test]
+    fn edges_longhand() {
+        let mut s = Style::new();
+        assert_eq!(apply(&mut s, "padding", "1"), Ok(()));
+        assert_eq!(apply(&mut s, "padding-left", "3"), Ok(()));
+        assert_eq!(
+            s.padding,
+            Some(Edges::new(
+                Unit::cells(1),
+                Unit::cells(1),
+                Unit::cells(1),
+                Unit::cells(3)
+            ))
+        );
+
+        let s = parse("margin-top", "2").map(|s| s.margin);
+        assert_eq!(
+            s,
+            Ok(Some(Edges::new(
+                Unit::cells(2),
+                Unit::UNSET,
+                Unit::UNSET,
+                Unit::UNSET
+            )))
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(parse_color("#ff5f00"), Some(Color::rgb(255, 95, 0)));
+        assert_eq!(
+            parse_color("bright-blue"),
+            Some(Color::Named(NamedColor::BrightBlue))
+        );
+        assert_eq!(parse_color("inherit"), Some(Color::Inherit));
+        assert_eq!(parse_color("none"), Some(Color::None));
+        assert_eq!(parse_color("#fffff"), None);
+        assert_eq!(parse_color("purple"), Some(Color::rgb(128, 0, 128)));
+        assert_eq!(
+            parse("background", "rgb(0, 128, 255)").map(|s| s.background),
+            Ok(Some(Color::rgb(0, 128, 255)))
+        );
+    }
+
+    #[test]
+    fn color_errors_say_what_was_expected() {
+        let err = parse("color", "rgb(1 2)").unwrap_err();
+        assert_eq!(err.range, 0..8);
+        assert_eq!(
+            err.to_string(),
+            "invalid value `rgb(1 2)`: expected `rgb(r g b [/ alpha])` with channels 0–255 or 0%–100%"
+        );
+        let err = parse("border", "rounded #ff").unwrap_err();
+        assert_eq!(err.range, 8..11);
+    }
+
+    #[test]
+    fn border_in_any_order() {
+        let expected = Some(Edges::all(Some(
+            Border::ROUNDED.with_color(Color::Named(NamedColor::Cyan)),
+        )));
+        assert_eq!(
+            parse("border", "rounded cyan").map(|s| s.border),
+            Ok(expected)
+        );
+        assert_eq!(
+            parse("border", "cyan rounded").map(|s| s.border),
+            Ok(expected)
+        );
+        assert_eq!(
+            parse("border", "red").map(|s| s.border),
+            Ok(Some(Edges::all(Some(
+                Border::SOLID.with_color(Color::Named(NamedColor::Red))
+            ))))
+        );
+    }
+
+    #[test]
+    fn border_sides() {
+        let s = parse("border-left", "thick cyan").map(|s| s.border);
+        assert_eq!(
+            s,
+            Ok(Some(Edges {
+                left: Some(Border {
+                    style: Some(BorderStyle::Thick),
+                    color: Some(Color::Named(NamedColor::Cyan)),
+                }),
+                ..Edges::all(None)
+            }))
+        );
+
+        // `border-style` and `border-color` reach every side, and leave
+        // the field they don't name undeclared.
+        let s = parse("border-color", "red").map(|s| s.border);
+        let red = Border {
+            style: None,
+            color: Some(Color::Named(NamedColor::Red)),
+        };
+        assert_eq!(s, Ok(Some(Edges::all(Some(red)))));
+        let mut s = Style::new();
+        assert_eq!(apply(&mut s, "border-bottom", "solid"), Ok(()));
+        assert_eq!(apply(&mut s, "border-color", "red"), Ok(()));
+        let bottom = s.border.and_then(|e| e.bottom);
+        assert_eq!(bottom, Some(Border::SOLID.merge(red)));
+    }
+
+    #[test]
+    fn font_style_flags() {
+        assert_eq!(
+            parse("font-style", "bold underline").map(|s| s.font_style),
+            Ok(Some(FontStyle::BOLD | FontStyle::UNDERLINE))
+        );
+        assert_eq!(
+            parse("font-style", "curly-underline reverse overline").map(|s| s.font_style),
+            Ok(Some(
+                FontStyle::CURLY_UNDERLINE | FontStyle::REVERSE | FontStyle::OVERLINE
+            ))
+        );
+    }
+
+    #[test]
+    fn border_labels() {
+        let s =
+            parse("border-title", r#""Open \"files\"" center bold cyan"#).map(|s| s.border_title);
+        assert_eq!(
+            s,
+            Ok(Some(
+                BorderLabel::new("Open \"files\"")
+                    .with_align(TextAlign::Center)
+                    .with_font_style(FontStyle::BOLD)
+                    .with_color(Color::Named(NamedColor::Cyan))
+            ))
+        );
+        let s = parse("border-footer", r#""3 items" right"#).map(|s| s.border_footer);
+        assert_eq!(
+            s,
+            Ok(Some(
+                BorderLabel::new("3 items").with_align(TextAlign::Right)
+            ))
+        );
+
+        assert!(parse("border-title", "Files").is_err());
+        assert!(parse("border-title", r#""Files" left right"#).is_err());
+        let err = parse("border-title", r#""Files" wobbly"#).err();
+        assert_eq!(err.map(|e| e.range), Some(8..14));
+    }
+
+    #[test]
+    fn text_overflow_and_line_clamp() {
+        let s = parse("text-overflow", "middle-ellipsis").map(|s| s.text_overflow);
+        assert_eq!(s, Ok(Some(TextOverflow::MiddleEllipsis)));
+        let s = parse("text-overflow", r#"" [more]""#).map(|s| s.text_overflow);
+        assert_eq!(s, Ok(Some(TextOverflow::Marker(" [more]".into()))));
+        assert!(parse("text-overflow", "fade").is_err());
+
+        let s = parse("line-clamp", "3").map(|s| s.line_clamp);
+        assert_eq!(s, Ok(Some(LineClamp::Lines(Int::new(3)))));
+        let s = parse("line-clamp", "none").map(|s| s.line_clamp);
+        assert_eq!(s, Ok(Some(LineClamp::None)));
+        assert!(parse("line-clamp", "0").is_err());
+        assert!(parse("line-clamp", "1.5").is_err());
+
+        let s = parse("white-space", "pre-wrap").map(|s| s.white_space);
+        assert_eq!(s, Ok(Some(WhiteSpace::PreWrap)));
+    }
+
+    #[test]
+    fn scrollbar_parts() {
+        let s = parse("scrollbar-thumb", r#"cyan "┃""#).map(|s| s.scrollbar_thumb);
+        assert_eq!(
+            s,
+            Ok(Some(
+                ScrollbarPart::glyph('┃').with_color(Color::Named(NamedColor::Cyan))
+            ))
+        );
+        let s = parse("scrollbar-track", "bright-black").map(|s| s.scrollbar_track);
+        assert_eq!(
+            s,
+            Ok(Some(ScrollbarPart {
+                glyph: None,
+                color: Some(Color::Named(NamedColor::BrightBlack)),
+            }))
+        );
+        assert!(parse("scrollbar-thumb", r#""ab""#).is_err());
+        assert!(parse("scrollbar-thumb", "red blue").is_err());
+    }
+
+    #[test]
+    fn grid_templates() {
+        let c = Unit::cells;
+        let s = parse("grid-template-columns", "20 1fr repeat(2, 10% auto)");
+        let expected = TrackList::EMPTY
+            .with(c(20))
+            .with(Unit::fill(1))
+            .with(Unit::percent(10))
+            .with(Unit::AUTO)
+            .with(Unit::percent(10))
+            .with(Unit::AUTO);
+        assert_eq!(s.map(|s| s.grid_template_columns), Ok(Some(expected)));
+        let s = parse("grid-template-rows", "none").map(|s| s.grid_template_rows);
+        assert_eq!(s, Ok(Some(TrackList::EMPTY)));
+
+        let err = parse("grid-template-rows", "1 repeat(2, 3 -1)").unwrap_err();
+        assert_eq!(err.range, 14..16);
+        assert!(parse("grid-template-rows", "repeat(0, 1)").is_err());
+        assert!(parse("grid-template-rows", "repeat(17, 1fr)").is_err());
+
+        let s = parse("grid-template-areas", r#""head head" "side main""#);
+        let areas = s.map(|s| s.grid_template_areas.map(|a| a.as_str().to_owned()));
+        assert_eq!(areas, Ok(Some("head head\nside main".to_owned())));
+        let s = parse("grid-template-areas", "none").map(|s| s.grid_template_areas);
+        assert_eq!(s, Ok(Some(GridAreas::NONE)));
+        let err = parse("grid-template-areas", r#""a b" "c" "d e""#).unwrap_err();
+        assert_eq!(err.range, 6..9);
+        let err = parse("grid-template-areas", r#""a b" "b b""#).unwrap_err();
+        assert_eq!(err.range, 0..5);
+    }
+
+    #[test]
+    fn positions_and_offsets() {
+        let s = parse("position", "absolute").map(|s| s.position);
+        assert_eq!(s, Ok(Some(Position::Absolute)));
+        let mut s = Style::new();
+        assert_eq!(apply(&mut s, "top", "100%"), Ok(()));
+        assert_eq!(apply(&mut s, "left", "-2"), Ok(()));
+        let mut inset = Edges::all(Unit::UNSET);
+        (inset.top, inset.left) = (Unit::percent(100), Unit::cells(-2));
+        assert_eq!(s.inset, Some(inset));
+        let s = parse("inset", "0 auto").map(|s| s.inset);
+        assert_eq!(s, Ok(Some(Edges::symmetric(Unit::ZERO, Unit::AUTO))));
+
+        assert_eq!(
+            parse("z-index", "-3").map(|s| s.z_index),
+            Ok(Some(ZIndex::Index(Int::new(-3))))
+        );
+        let s = parse("z-index", "auto").map(|s| s.z_index);
+        assert_eq!(s, Ok(Some(ZIndex::Auto)));
+        assert!(parse("z-index", "1.5").is_err());
+    }
+
+    #[test]
+    fn grid_placements() {
+        let placement = |v| parse("grid-column", v).map(|s| s.grid_column);
+        let at = GridPlacement::at;
+        assert_eq!(placement("auto"), Ok(Some(GridPlacement::AUTO)));
+        assert_eq!(placement("2"), Ok(Some(at(2))));
+        assert_eq!(placement("span 3"), Ok(Some(GridPlacement::span(3))));
+        assert_eq!(placement("1 / 3"), Ok(Some(at(1).with_span(2))));
+        assert_eq!(placement("3/1"), Ok(Some(at(1).with_span(2))));
+        assert_eq!(placement("2 /span 2"), Ok(Some(at(2).with_span(2))));
+        assert_eq!(placement("span 2 / 4"), Ok(Some(at(2).with_span(2))));
+        assert_eq!(placement("auto / 3"), Ok(Some(at(2))));
+
+        let err = placement("1 / 0").unwrap_err();
+        assert_eq!(err.range, 4..5);
+        assert!(placement("span").is_err());
+        assert!(placement("1 /").is_err());
+        assert!(placement("1 2").is_err());
+
+        let s = parse("grid-area", "main").map(|s| s.grid_area);
+        assert_eq!(s, Ok(Some(Str::from_static("main"))));
+        assert!(parse("grid-area", "a.b").is_err());
+        assert_eq!(
+            parse("display", "grid").map(|s| s.display),
+            Ok(Some(Display::Grid))
+        );
+    }
+
+    #[test]
+    fn underline_color() {
+        assert_eq!(
+            parse("underline-color", "#f00").map(|s| s.underline_color),
+            Ok(Some(Color::Rgb(255, 0, 0)))
+        );
+        assert!(parse("underline-color", "wavy").is_err());
+    }
+
+    #[test]
+    fn keywords() {
+        let s = parse("justify-content", "space-evenly").map(|s| s.justify_content);
+        assert_eq!(s, Ok(Some(Justify::SpaceEvenly)));
+        let s = parse("flex-direction", "row-reverse").map(|s| s.flex_direction);
+        assert_eq!(s, Ok(Some(FlexDirection::RowReverse)));
+        let s = parse("text-align", "justify").map(|s| s.text_align);
+        assert_eq!(s, Ok(Some(TextAlign::Justify)));
+        let s = parse("word-break", "break-all").map(|s| s.word_break);
+        assert_eq!(s, Ok(Some(WordBreak::BreakAll)));
+        let s = parse("border-collapse", "collapse").map(|s| s.border_collapse);
+        assert_eq!(s, Ok(Some(BorderCollapse::Collapse)));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            parse("opacity", "0.5").map(|s| s.opacity),
+            Ok(Some(Float::HALF))
+        );
+        assert!(parse("opacity", "1.5").is_err());
+        assert!(parse("flex-grow", "-1").is_err());
+        assert!(parse("flex-grow", "inf").is_err());
+        assert!(parse("flex-grow", "NaN").is_err());
+    }
+
+    #[test]
+    fn errors_point_at_component() {
+        let err = parse("padding", "1 x 3");
+        assert_eq!(
+            err,
+            Err(DeclarationError {
+                kind: DeclarationErrorKind::InvalidValue {
+                    found: "x".into(),
+                    expected: SIZE
+                },
+                range: 2..3,
+            })
+        );
+
+        let err = parse("width", "10 20").map(|_| ());
+        assert_eq!(
+            err,
+            Err(DeclarationError {
+                kind: DeclarationErrorKind::UnexpectedValue("20".into()),
+                range: 3..5,
+            })
+        );
+        assert!(parse("width", "-3").is_err());
+    }
+
+    #[test]
+    fn unknown_property() {
+        let err = parse("colour", "red");
+        assert!(err.as_ref().is_err_and(DeclarationError::is_property_error));
+        assert_eq!(
+            err.map(|_| ()).map_err(|e| e.to_string()),
+            Err("unknown property `colour`".to_owned())
+        );
+    }
+
+    #[test]
+    fn every_property_is_known() {
+        for p in PROPERTIES {
+            let err = parse(p, "");
+            assert_eq!(
+                err.map(|_| ()).map_err(|e| e.kind),
+                Err(DeclarationErrorKind::MissingValue)
+            );
+        }
+    }
+
+    #[test]
+    fn components_keep_groups() {
+        let parts: Vec<_> = components(" rgb(1, 2, 3)  red")
+            .iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(parts, ["rgb(1, 2, 3)", "red"]);
+
+        let parts: Vec<_> = components(r#""a \" b" red"#)
+            .iter()
+            .map(|c| c.text)
+            .collect();
+        assert_eq!(parts, [r#""a \" b""#, "red"]);
+    }
+}
@@ -5,17 +5,27 @@
 ///
 /// # Storage
 ///
-/// A single `u8` — 6 bits used, 2 reserved for future extensions.
+/// A single `u16` — 12 bits used, 4 reserved for future extensions.
 /// `Copy` + `const`-constructible + `Hash`-able, zero overhead.
 ///
 /// # CSS equivalents
 ///
 /// Combines `font-weight`, `font-style`, `text-decoration`, and several
-/// terminal-specific properties (`blink`, `dim`) with no CSS analogue.
+/// terminal-specific properties (`blink`, `dim`, `reverse`) with no CSS
+/// analogue.
+///
+/// # Terminal support
+///
+/// ratatui has no modifier for [`FontStyle::OVERLINE`] or the underline
+/// variants. [`FontStyle::to_ratatui`] keeps them in modifier bits ratatui
+/// leaves unused, so a backend can read them back with
+/// [`FontStyle::from_ratatui`] and write [`FontStyle::sgr`]; ratatui's own
+/// backends show a plain underline and no overline.
 ///
 /// # Examples
 ///
 /// ```rust
+/// # use oxidui_style::{Style, font::FontStyle};
 /// let heading = FontStyle::BOLD | FontStyle::UNDERLINE;
 /// assert!(heading.has(FontStyle::BOLD));
 /// assert!(!heading.has(FontStyle::ITALIC));
@@ -23,7 +33,7 @@
 /// let style = Style { font_style: Some(FontStyle::BOLD | FontStyle::ITALIC), ..Style::new() };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct FontStyle(pub u8);
+pub struct FontStyle(pub u16);
 
 impl FontStyle {
     /// No modifiers — plain text.
@@ -56,6 +66,37 @@ impl FontStyle {
     /// Exact rendering is terminal-dependent.
     pub const DIM: Self = Self(0b0010_0000);
 
+    /// Reverse video — swap foreground and background. Terminal: `\x1b[7m`.
+    pub const REVERSE: Self = Self(0b0100_0000);
+
+    /// Concealed text — drawn in the background color. Terminal: `\x1b[8m`.
+    ///
+    /// The text is still there: it can be selected and copied.
+    pub const HIDDEN: Self = Self(0b1000_0000);
+
+    /// Fast blinking text. Terminal: `\x1b[6m`. Rarely supported; most
+    /// terminals treat it as [`FontStyle::BLINK`] or ignore it.
+    pub const RAPID_BLINK: Self = Self(0b0001_0000_0000);
+
+    /// A line above the text. Terminal: `\x1b[53m`.
+    pub const OVERLINE: Self = Self(0b0010_0000_0000);
+
+    /// Double underline. Terminal: `\x1b[4:2m`. Drawn as a single
+    /// underline where unsupported.
+    pub const DOUBLE_UNDERLINE: Self = Self(0b0100_0000_0000);
+
+    /// Curly ("squiggly") underline, as used for spell-check marks.
+    /// Terminal: `\x1b[4:3m`. Drawn as a single underline where
+    /// unsupported. Pair with `underline-color`.
+    pub const CURLY_UNDERLINE: Self = Self(0b1000_0000_0000);
+
+    /// Every defined flag.
+    pub const ALL: Self = Self(0b1111_1111_1111);
+
+    /// Every underline variant.
+    pub const ANY_UNDERLINE: Self =
+        Self(Self::UNDERLINE.0 | Self::DOUBLE_UNDERLINE.0 | Self::CURLY_UNDERLINE.0);
+
     /// Return a new `FontStyle` with the flags from `other` added.
     pub const fn with(self, other: Self) -> Self {
         Self(self.0 | other.0)
@@ -80,6 +121,127 @@ impl FontStyle {
     pub const fn is_normal(self) -> bool {
         self.0 == 0
     }
+
+    /// SGR parameters that switch these flags on, in SGR order.
+    ///
+    /// Only one underline is emitted: curly over double over single.
+    ///
+    /// ```rust
+    /// # use oxidui_style::font::FontStyle;
+    /// let squiggle = FontStyle::BOLD | FontStyle::UNDERLINE | FontStyle::CURLY_UNDERLINE;
+    /// assert_eq!(squiggle.sgr(), ["1", "4:3"]);
+    /// ```
+    pub fn sgr(self) -> Vec<&'static str> {
+        let underline = if self.has(Self::CURLY_UNDERLINE) {
+            "4:3"
+        } else if self.has(Self::DOUBLE_UNDERLINE) {
+            "4:2"
+        } else {
+            "4"
+        };
+        [
+            (Self::BOLD, "1"),
+            (Self::DIM, "2"),
+            (Self::ITALIC, "3"),
+            (Self::ANY_UNDERLINE, underline),
+            (Self::BLINK, "5"),
+            (Self::RAPID_BLINK, "6"),
+            (Self::REVERSE, "7"),
+            (Self::HIDDEN, "8"),
+            (Self::STRIKETHROUGH, "9"),
+            (Self::OVERLINE, "53"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.has_any(*flag))
+        .map(|(_, code)| code)
+        .collect()
+    }
+
+    /// Convert to `ratatui::style::Modifier`. `BLINK` is the slow blink.
+    ///
+    /// Double and curly underlines also set `UNDERLINED`. They and
+    /// `OVERLINE` take a bit of [`Self::RATATUI_SPARE`] each, which
+    /// ratatui's backends ignore.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(self) -> ratatui::style::Modifier {
+        use ratatui::style::Modifier as M;
+        let spare = Self::RATATUI_SPARE
+            .into_iter()
+            .filter(|(flag, _)| self.has(*flag))
+            .fold(M::empty(), |acc, (_, m)| acc | m);
+        [
+            (Self::BOLD, M::BOLD),
+            (Self::ITALIC, M::ITALIC),
+            (Self::UNDERLINE, M::UNDERLINED),
+            (Self::DOUBLE_UNDERLINE, M::UNDERLINED),
+            (Self::CURLY_UNDERLINE, M::UNDERLINED),
+            (Self::BLINK, M::SLOW_BLINK),
+            (Self::STRIKETHROUGH, M::CROSSED_OUT),
+            (Self::DIM, M::DIM),
+            (Self::REVERSE, M::REVERSED),
+            (Self::HIDDEN, M::HIDDEN),
+            (Self::RAPID_BLINK, M::RAPID_BLINK),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.has(*flag))
+        .fold(spare, |acc, (_, m)| acc | m)
+    }
+
+    /// The flags ratatui has no modifier for, and the unused
+    /// `ratatui::style::Modifier` bit [`Self::to_ratatui`] keeps each in.
+    #[cfg(feature = "ratatui")]
+    pub const RATATUI_SPARE: [(Self, ratatui::style::Modifier); 3] = {
+        use ratatui::style::Modifier as M;
+        [
+            (Self::DOUBLE_UNDERLINE, M::from_bits_retain(1 << 13)),
+            (Self::CURLY_UNDERLINE, M::from_bits_retain(1 << 14)),
+            (Self::OVERLINE, M::from_bits_retain(1 << 15)),
+        ]
+    };
+
+    /// Convert back from `ratatui::style::Modifier`, including the flags
+    /// [`Self::to_ratatui`] keeps in spare bits.
+    ///
+    /// ```rust
+    /// # use oxidui_style::font::FontStyle;
+    /// let squiggle = FontStyle::ITALIC | FontStyle::CURLY_UNDERLINE;
+    /// assert_eq!(FontStyle::from_ratatui(squiggle.to_ratatui()), squiggle);
+    /// ```
+    #[cfg(feature = "ratatui")]
+    pub fn from_ratatui(modifier: ratatui::style::Modifier) -> Self {
+        let spare = Self::RATATUI_SPARE
+            .into_iter()
+            .filter(|(_, m)| modifier.contains(*m))
+            .fold(Self::NORMAL, |acc, (flag, _)| acc | flag);
+        let plain = [
+            Self::BOLD,
+            Self::ITALIC,
+            Self::UNDERLINE,
+            Self::BLINK,
+            Self::STRIKETHROUGH,
+            Self::DIM,
+            Self::REVERSE,
+            Self::HIDDEN,
+            Self::RAPID_BLINK,
+        ]
+        .into_iter()
+        .filter(|flag| modifier.contains(flag.to_ratatui()))
+        .fold(Self::NORMAL, |acc, flag| acc | flag);
+        // The underline variants set `UNDERLINED` too; it isn't a flag of
+        // its own then.
+        if spare.has_any(Self::ANY_UNDERLINE) {
+            plain.without(Self::UNDERLINE) | spare
+        } else {
+            plain | spare
+        }
+    }
+
+    /// The ratatui modifiers this style switches off when it replaces
+    /// another: every one [`FontStyle::to_ratatui`] doesn't set.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui_cleared(self) -> ratatui::style::Modifier {
+        Self::ALL.to_ratatui().difference(self.to_ratatui())
+    }
 }
 
 impl std::ops::BitOr for FontStyle {
@@ -0,0 +1,337 @@
+//! Grid templates and item placement.
+//!
+//! A `display: grid` container declares its columns and rows as
+//! [`TrackList`]s, and may name rectangular areas of them with
+//! [`GridAreas`]. Each child is placed by a [`GridPlacement`] per axis, or
+//! by the name of an area.
+//!
+//! ```rust
+//! # use oxidui_style::grid::{GridAreas, GridPlacement};
+//! let areas = GridAreas::parse(["head head", "side main"]).unwrap();
+//! assert_eq!(areas.area("head"), Some((GridPlacement::at(1).with_span(2), GridPlacement::at(1))));
+//! assert_eq!(areas.area("main"), Some((GridPlacement::at(2), GridPlacement::at(2))));
+//! ```
+
+use std::fmt;
+
+use crate::str::Str;
+use crate::unit::Unit;
+
+/// The sizes of a grid's columns or rows. CSS `grid-template-columns` /
+/// `grid-template-rows`.
+///
+/// Each track is a [`Unit`]: cells and percentages are fixed, `Auto` fits
+/// the items in the track, and `Fill(w)` is CSS `wfr` — a `w`-weighted
+/// share of the room the other tracks leave. Up to [`TrackList::MAX`]
+/// tracks are stored inline, which keeps the list `Copy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrackList {
+    len: u8,
+    tracks: [Unit; TrackList::MAX],
+}
+
+impl TrackList {
+    /// The most tracks a list holds.
+    pub const MAX: usize = 16;
+
+    /// No tracks: the grid has a single `auto` column, and rows are added
+    /// as items need them.
+    pub const EMPTY: Self = Self {
+        len: 0,
+        tracks: [Unit::Unset; Self::MAX],
+    };
+
+    /// `count` tracks of `track` — CSS `repeat(count, track)`.
+    pub const fn repeat(count: usize, track: Unit) -> Self {
+        let mut list = Self::EMPTY;
+        while (list.len as usize) < count && (list.len as usize) < Self::MAX {
+            list = list.with(track);
+        }
+        list
+    }
+
+    /// Append a track. Tracks past [`TrackList::MAX`] are dropped.
+    pub const fn with(mut self, track: Unit) -> Self {
+        if (self.len as usize) < Self::MAX {
+            self.tracks[self.len as usize] = track;
+            self.len += 1;
+        }
+        self
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size of track `i`, counting from 0.
+    pub fn get(&self, i: usize) -> Option<Unit> {
+        self.as_slice().get(i).copied()
+    }
+
+    pub fn as_slice(&self) -> &[Unit] {
+        &self.tracks[..self.len()]
+    }
+}
+
+impl Default for TrackList {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Where an item sits along one axis of its grid. CSS `grid-column` /
+/// `grid-row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridPlacement {
+    /// The first track the item covers, counting from 1 as CSS grid lines
+    /// do. `None` leaves it to auto-placement.
+    pub start: Option<u16>,
+    /// How many tracks the item covers. At least 1.
+    pub span: u16,
+}
+
+impl GridPlacement {
+    /// One track, wherever auto-placement puts it.
+    pub const AUTO: Self = Self {
+        start: None,
+        span: 1,
+    };
+
+    /// One track, starting at `start` (from 1).
+    pub const fn at(start: u16) -> Self {
+        Self {
+            start: Some(start),
+            span: 1,
+        }
+    }
+
+    /// `span` tracks, wherever auto-placement puts them.
+    pub const fn span(span: u16) -> Self {
+        Self::AUTO.with_span(span)
+    }
+
+    /// Cover `span` tracks instead. Zero counts as 1.
+    pub const fn with_span(mut self, span: u16) -> Self {
+        self.span = if span == 0 { 1 } else { span };
+        self
+    }
+
+    /// The tracks covered, as a half-open range of 0-based indices, if the
+    /// start is fixed.
+    pub const fn tracks(self) -> Option<(usize, usize)> {
+        match self.start {
+            Some(start) => {
+                let start = start.saturating_sub(1) as usize;
+                Some((start, start + self.span as usize))
+            }
+            None => None,
+        }
+    }
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self::AUTO
+    }
+}
+
+/// Named areas of a grid. CSS `grid-template-areas`.
+///
+/// One string per row, one name per column; `.` leaves a cell unnamed.
+/// Each name must cover a rectangle of cells. Children are placed in an
+/// area with `grid-area: <name>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridAreas(Str);
+
+impl GridAreas {
+    /// No named areas — CSS `none`. Overrides an earlier template.
+    pub const NONE: Self = Self(Str::from_static(""));
+
+    /// Check and normalize a template, one string per row.
+    pub fn parse<'a>(rows: impl IntoIterator<Item = &'a str>) -> Result<Self, GridAreasError> {
+        let rows: Vec<Vec<&str>> = rows
+            .into_iter()
+            .map(|row| row.split_whitespace().collect())
+            .collect();
+        let columns = rows.first().map_or(0, Vec::len);
+        if columns == 0 {
+            return Err(GridAreasError::Empty);
+        }
+        if rows.iter().any(|row| row.len() != columns) {
+            return Err(GridAreasError::Ragged);
+        }
+        for &name in rows.iter().flatten() {
+            if is_unnamed(name) {
+                continue;
+            }
+            let valid = name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                return Err(GridAreasError::InvalidName(name.to_owned()));
+            }
+        }
+        let template = rows
+            .iter()
+            .map(|row| {
+                let cells: Vec<&str> = row
+                    .iter()
+                    .map(|&name| if is_unnamed(name) { "." } else { name })
+                    .collect();
+                cells.join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let areas = Self(Str::from_string(template));
+        for name in areas.cells().flatten().flatten() {
+            if !areas.is_rectangle(name) {
+                return Err(GridAreasError::NotRectangular(name.to_owned()));
+            }
+        }
+        Ok(areas)
+    }
+
+    /// A template already normalized by [`GridAreas::parse`]: rows joined
+    /// with `\n`, names with single spaces. For generated code.
+    #[doc(hidden)]
+    pub const fn from_normalized(template: &'static str) -> Self {
+        Self(Str::from_static(template))
+    }
+
+    /// The normalized template: rows joined with `\n`, names with single
+    /// spaces, unnamed cells as `.`.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells().next().map_or(0, Iterator::count)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.as_str().lines().count()
+    }
+
+    /// The column and row placements covering the area called `name`.
+    pub fn area(&self, name: &str) -> Option<(GridPlacement, GridPlacement)> {
+        let (mut columns, mut rows) = ((usize::MAX, 0), (usize::MAX, 0));
+        for (y, row) in self.cells().enumerate() {
+            for (x, cell) in row.enumerate() {
+                if cell == Some(name) {
+                    columns = (columns.0.min(x), columns.1.max(x + 1));
+                    rows = (rows.0.min(y), rows.1.max(y + 1));
+                }
+            }
+        }
+        let placement = |(start, end): (usize, usize)| {
+            let span = u16::try_from(end - start).ok()?;
+            Some(GridPlacement::at(u16::try_from(start + 1).ok()?).with_span(span))
+        };
+        if columns.1 == 0 {
+            return None;
+        }
+        Some((placement(columns)?, placement(rows)?))
+    }
+
+    /// Each row's cells: a name, or `None` where unnamed.
+    fn cells(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&str>>> {
+        self.as_str()
+            .lines()
+            .map(|row| row.split(' ').map(|name| (name != ".").then_some(name)))
+    }
+
+    /// `true` if the cells called `name` fill their bounding box.
+    fn is_rectangle(&self, name: &str) -> bool {
+        let Some((columns, rows)) = self.area(name) else {
+            return false;
+        };
+        let count = self.cells().flatten().filter(|&c| c == Some(name)).count();
+        count == usize::from(columns.span) * usize::from(rows.span)
+    }
+}
+
+/// A run of dots is an unnamed cell, as in CSS.
+fn is_unnamed(name: &str) -> bool {
+    name.bytes().all(|b| b == b'.')
+}
+
+/// Why a `grid-template-areas` template was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridAreasError {
+    /// No rows, or a row without cells.
+    Empty,
+    /// Rows with different numbers of cells.
+    Ragged,
+    /// A name with characters other than letters, digits, `-` and `_`.
+    InvalidName(String),
+    /// An area whose cells don't form a rectangle.
+    NotRectangular(String),
+}
+
+impl fmt::Display for GridAreasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no cells"),
+            Self::Ragged => f.write_str("rows have different numbers of cells"),
+            Self::InvalidName(name) => write!(f, "invalid area name `{name}`"),
+            Self::NotRectangular(name) => write!(f, "area `{name}` is not a rectangle"),
+        }
+    }
+}
+
+impl std::error::Error for GridAreasError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_lists_hold_up_to_max() {
+        let list = TrackList::EMPTY.with(Unit::cells(10)).with(Unit::fill(1));
+        assert_eq!(list.as_slice(), [Unit::cells(10), Unit::fill(1)]);
+        assert_eq!(list.get(2), None);
+        assert_eq!(TrackList::repeat(40, Unit::AUTO).len(), TrackList::MAX);
+        assert_eq!(
+            TrackList::repeat(2, Unit::AUTO),
+            TrackList::EMPTY.with(Unit::AUTO).with(Unit::AUTO)
+        );
+    }
+
+    #[test]
+    fn placements_cover_tracks() {
+        assert_eq!(GridPlacement::at(2).with_span(3).tracks(), Some((1, 4)));
+        assert_eq!(GridPlacement::span(2).tracks(), None);
+        assert_eq!(GridPlacement::span(0).span, 1);
+    }
+
+    #[test]
+    fn areas_are_normalized_and_checked() {
+        let areas = GridAreas::parse(["a   a ..", "b b c"]).unwrap();
+        assert_eq!(areas.as_str(), "a a .\nb b c");
+        assert_eq!((areas.columns(), areas.rows()), (3, 2));
+        assert_eq!(
+            areas.area("c"),
+            Some((GridPlacement::at(3), GridPlacement::at(2)))
+        );
+        assert_eq!(areas.area("x"), None);
+
+        assert_eq!(GridAreas::parse(["a b", "c"]), Err(GridAreasError::Ragged));
+        assert_eq!(GridAreas::parse([" "]), Err(GridAreasError::Empty));
+        assert_eq!(
+            GridAreas::parse(["a b", "b b"]),
+            Err(GridAreasError::NotRectangular("b".to_owned()))
+        );
+        assert_eq!(
+            GridAreas::parse(["a a", "b a"]),
+            Err(GridAreasError::NotRectangular("a".to_owned()))
+        );
+        assert_eq!(
+            GridAreas::parse(["a$"]),
+            Err(GridAreasError::InvalidName("a$".to_owned()))
+        );
+    }
+}
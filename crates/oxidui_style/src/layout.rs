@@ -1,12 +1,18 @@
+use crate::color::Color;
+use crate::number::Int;
+use crate::str::Str;
+
 /// How an element lays out its children. CSS `display`.
 ///
-/// Restricted to values relevant in a TUI context — no `inline`, `table`,
-/// or `grid` for now.
+/// Restricted to values relevant in a TUI context — no `inline` or
+/// `table` for now.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Display {
     /// Stack children vertically, each on its own line (default).
     ///
-    /// Children without an explicit height size to their content.
+    /// Children take the container's width less their margins, and
+    /// without an explicit height size to their content. `auto` side
+    /// margins center a child with a declared width.
     #[default]
     Block,
 
@@ -14,8 +20,12 @@ pub enum Display {
     /// alignment by [`Align`] and [`Justify`].
     Flex,
 
-    /// Remove from layout entirely — no space taken, not rendered.
-    /// Equivalent to CSS `display: none`.
+    /// Grid layout: children placed in the cells of columns and rows
+    /// sized by `grid_template_columns` / `grid_template_rows`.
+    Grid,
+
+    /// Remove from layout entirely — no space taken, not rendered, and
+    /// nothing in it can scroll. Equivalent to CSS `display: none`.
     None,
 }
 
@@ -101,6 +111,157 @@ pub enum TextAlign {
     Center,
     /// Align to the right edge.
     Right,
+    /// Stretch wrapped lines to both edges by widening the spaces between
+    /// words. The last line of a paragraph stays left-aligned.
+    Justify,
+}
+
+/// Where wrapped lines may break. CSS `word-break`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WordBreak {
+    /// Break between words; a word wider than the line overflows it
+    /// (default).
+    #[default]
+    Normal,
+    /// Break between any two characters, filling every line.
+    BreakAll,
+}
+
+/// How whitespace in text is handled, and whether lines wrap. CSS
+/// `white-space`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WhiteSpace {
+    /// Collapse runs of whitespace, newlines included, to one space and
+    /// wrap to the box (default).
+    #[default]
+    Normal,
+    /// Collapse whitespace like `Normal`, but never wrap.
+    NoWrap,
+    /// Keep spaces and newlines as written and never wrap.
+    Pre,
+    /// Keep spaces and newlines as written, and wrap to the box.
+    PreWrap,
+}
+
+impl WhiteSpace {
+    /// `true` if runs of whitespace collapse to a single space.
+    pub const fn collapses(self) -> bool {
+        matches!(self, Self::Normal | Self::NoWrap)
+    }
+
+    /// `true` if lines wrap to the width of their box.
+    pub const fn wraps(self) -> bool {
+        matches!(self, Self::Normal | Self::PreWrap)
+    }
+}
+
+/// How a line of text too long for its box is cut short. CSS
+/// `text-overflow`.
+///
+/// Applies to lines that can't wrap (`white-space: nowrap` or `pre`, or a
+/// word wider than the box) and to the last line kept by `line-clamp`.
+///
+/// ```rust
+/// # use oxidui_style::layout::TextOverflow;
+/// let more = TextOverflow::Marker(" [more]".into());
+/// assert_ne!(more, TextOverflow::Ellipsis);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum TextOverflow {
+    /// Leave the line whole; only the box's `overflow` cuts it (default).
+    #[default]
+    Clip,
+    /// End the line with `…` where it is cut.
+    Ellipsis,
+    /// Keep the start and end of the line and put `…` in between —
+    /// `/usr/…/bin/cargo`. Suits file paths, where both ends matter.
+    MiddleEllipsis,
+    /// End the line with a marker of your own.
+    Marker(Str),
+}
+
+/// How many lines of text a box shows. CSS `line-clamp`.
+///
+/// An enum rather than a bare count so `none` can be declared, and undo a
+/// clamp from an earlier rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineClamp {
+    /// Every line (default).
+    #[default]
+    None,
+    /// At most this many lines, the last one marked with the
+    /// `text-overflow` marker if any were dropped.
+    Lines(Int),
+}
+
+/// How an element is placed. CSS `position`.
+///
+/// Anything but `Static` makes the element *positioned*: it is painted
+/// after the normal flow around it, in `z_index` order, and is the
+/// containing block its `Absolute` descendants are placed against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Position {
+    /// In the normal flow; the offsets are ignored (default).
+    #[default]
+    Static,
+    /// In the normal flow, then shifted by its offsets. Its siblings stay
+    /// where they were.
+    Relative,
+    /// Out of the flow, placed by its offsets inside the padding box of
+    /// the nearest positioned ancestor, or of the whole screen if none.
+    Absolute,
+    /// Out of the flow, placed by its offsets on the screen, regardless
+    /// of its ancestors' clipping or scrolling.
+    Fixed,
+}
+
+impl Position {
+    /// `true` for anything but `Static`.
+    pub const fn is_positioned(self) -> bool {
+        !matches!(self, Self::Static)
+    }
+
+    /// `true` if the element takes no room in its parent's layout.
+    pub const fn is_out_of_flow(self) -> bool {
+        matches!(self, Self::Absolute | Self::Fixed)
+    }
+}
+
+/// Where a positioned element paints among its neighbours. CSS
+/// `z-index`.
+///
+/// An enum rather than a bare number so `auto` can be declared, and undo
+/// an order from an earlier rule.
+///
+/// ```rust
+/// # use oxidui_style::{layout::ZIndex, number::Int};
+/// assert_eq!(ZIndex::Auto.level(), 0);
+/// assert!(ZIndex::Index(Int::new(0)).is_context());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ZIndex {
+    /// Level 0, and no stacking context: positioned descendants are
+    /// ordered among the element's neighbours (default).
+    #[default]
+    Auto,
+    /// This level, higher on top, and a stacking context.
+    Index(Int),
+}
+
+impl ZIndex {
+    /// The level the element paints at; `Auto` is 0.
+    pub const fn level(self) -> i32 {
+        match self {
+            Self::Auto => 0,
+            Self::Index(n) => n.get(),
+        }
+    }
+
+    /// `true` for `Index`: the element orders its descendants among
+    /// themselves and paints them with it.
+    pub const fn is_context(self) -> bool {
+        matches!(self, Self::Index(_))
+    }
 }
 
 /// What to do when content overflows the element's bounds. CSS `overflow`.
@@ -113,8 +274,41 @@ pub enum Overflow {
     Visible,
     /// Clip content at the element's boundary — nothing renders outside.
     Hidden,
-    /// Clip content and show a scrollbar.
-    ///
-    /// Your framework must manage scroll state separately.
+    /// Clip content and show a scrollbar. The content scrolls vertically;
+    /// the scrollbar takes one column between the border and the padding.
     Scroll,
 }
+
+/// The thumb or the track of a scrollbar: the glyph it is drawn with and
+/// its color.
+///
+/// ```rust
+/// # use oxidui_style::{Style, color::{Color, NamedColor}, layout::ScrollbarPart};
+/// let style = Style::new()
+///     .with_scrollbar_thumb(ScrollbarPart::glyph('┃').with_color(Color::Named(NamedColor::Cyan)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ScrollbarPart {
+    /// One terminal cell wide. `None` keeps the inherited glyph, at first
+    /// the charset's default.
+    pub glyph: Option<char>,
+    /// `None` keeps a color declared on an ancestor, or else draws it in
+    /// the element's text color.
+    pub color: Option<Color>,
+}
+
+impl ScrollbarPart {
+    /// Drawn with `glyph`, in the text color.
+    pub const fn glyph(glyph: char) -> Self {
+        Self {
+            glyph: Some(glyph),
+            color: None,
+        }
+    }
+
+    /// Apply a color override.
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
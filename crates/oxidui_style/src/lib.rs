@@ -25,28 +25,47 @@
 //!
 //! ```text
 //! styles.rs
-//! ├── Color / NamedColor                              — foreground & background colors
+//! ├── Color / NamedColor / ColorDepth / Palette       — colors, their syntax and math, fitted to the terminal
 //! ├── Number                                          — integer scalar values (z-index, tab-index…) and floating-point scalars (opacity, flex-grow…)
 //! ├── Str                                             — CSS string values (font-family, content…)
 //! ├── Unit                                            — dimensional values (width, height, gap…)
 //! ├── Border / BorderStyle  / Edges<T>                — four-sided shorthand (padding, margin…) and border appearance
+//! ├── BorderGlyphs / Charset                          — border characters, custom sets and the ASCII fallback
+//! ├── join / BorderCollapse                           — junctions where collapsed border lines meet
+//! ├── TrackList / GridPlacement / GridAreas           — grid templates and item placement
 //! ├── FontStyle                                       — text modifier bitset (bold | italic | …)
 //! ├── Layout                                          — layout mode enums / flex alignment enums / text and overflow enums
-//! └── Style                    — the aggregate style declaration struct
+//! ├── Style                    — the aggregate style declaration struct
+//! ├── declaration                                     — `property: value` grammar shared by `scss!` and stylesheets
+//! ├── Stylesheet / Rule                               — runtime `.oxss` parser (selector + `Style` rules)
+//! ├── Selector / Specificity                          — type / `.class` / `#id` selectors with `A B` and `A > B` combinators
+//! ├── Cascade / Origin                                — orders matching rules (origin, specificity, source) before merging
+//! └── ComputedStyle                                   — cascaded `Style` resolved against the parent: no `Option`s, no `Inherit`
 //! ```
 pub mod border;
+pub mod cascade;
 pub mod color;
+pub mod computed;
+pub mod declaration;
 pub mod font;
+pub mod grid;
 pub mod layout;
 pub mod number;
+pub mod selector;
 pub mod str;
+pub mod stylesheet;
 pub mod unit;
 
-use border::{Border, Edges};
+use border::{Border, BorderCollapse, BorderLabel, Edges};
 use color::Color;
 use font::FontStyle;
-use layout::{Align, Display, FlexDirection, Justify, Overflow, TextAlign};
+use grid::{GridAreas, GridPlacement, TrackList};
+use layout::{
+    Align, Display, FlexDirection, Justify, LineClamp, Overflow, Position, ScrollbarPart,
+    TextAlign, TextOverflow, WhiteSpace, WordBreak, ZIndex,
+};
 use number::Float;
+use str::Str;
 use unit::Unit;
 
 /// A complete set of style declarations for one UI element.
@@ -68,6 +87,7 @@ use unit::Unit;
 /// # Creating styles
 ///
 /// ```rust
+/// # use oxidui_style::{Style, color::{Color, NamedColor}, font::FontStyle, unit::Unit};
 /// // Direct struct construction (idiomatic proc_macro output)
 /// let s = Style {
 ///     width:      Some(Unit::percent(100)),
@@ -86,6 +106,7 @@ use unit::Unit;
 /// # Merging
 ///
 /// ```rust
+/// # use oxidui_style::{Style, color::{Color, NamedColor}};
 /// let mut base = Style { color: Some(Color::Named(NamedColor::White)), ..Style::new() };
 /// let over     = Style { color: Some(Color::Named(NamedColor::Red)),   ..Style::new() };
 /// base.merge(&over);
@@ -138,6 +159,45 @@ pub struct Style {
     pub justify_content: Option<Justify>,
     /// Space between children (not at edges). CSS `gap`.
     pub gap: Option<Unit>,
+    /// Column sizes of a grid container. CSS `grid-template-columns`.
+    pub grid_template_columns: Option<TrackList>,
+    /// Row sizes of a grid container. CSS `grid-template-rows`. Rows
+    /// past the template are `auto`.
+    pub grid_template_rows: Option<TrackList>,
+    /// Named areas of a grid container. CSS `grid-template-areas`.
+    ///
+    /// Without a template of its own, the grid gets an `auto` column and
+    /// row for each column and row of areas.
+    pub grid_template_areas: Option<GridAreas>,
+    /// The columns a grid item covers. CSS `grid-column`.
+    pub grid_column: Option<GridPlacement>,
+    /// The rows a grid item covers. CSS `grid-row`.
+    pub grid_row: Option<GridPlacement>,
+    /// The area of the parent grid's `grid_template_areas` the item
+    /// fills, in place of `grid_column` and `grid_row`. CSS `grid-area`.
+    pub grid_area: Option<Str>,
+
+    // -----------------------------------------------------------------------
+    // Positioning
+    // -----------------------------------------------------------------------
+    /// How the element is placed. CSS `position`.
+    pub position: Option<Position>,
+    /// Offsets from the containing block's edges, per side. CSS `top`,
+    /// `right`, `bottom`, `left` and the `inset` shorthand.
+    ///
+    /// Ignored unless `position` is set. Percentages refer to the
+    /// containing block's width on the left and right, its height on the
+    /// top and bottom. `Auto` sides are free: with both sides of an axis
+    /// `Auto` the element stays where the flow would put it.
+    pub inset: Option<Edges<Unit>>,
+    /// Paint order among positioned elements. CSS `z-index`.
+    ///
+    /// Higher paints on top; ties go in document order, and negative
+    /// values paint beneath the normal flow. A positioned element with a
+    /// `z_index` is a stacking context: its descendants are ordered among
+    /// themselves and painted with it, never between it and its
+    /// neighbours.
+    pub z_index: Option<ZIndex>,
 
     // -----------------------------------------------------------------------
     // Visuals
@@ -152,15 +212,34 @@ pub struct Style {
     /// Fills the element's box including padding (border-box semantics).
     pub background: Option<Color>,
 
-    /// Border appearance. CSS `border`.
+    /// Border appearance, per side. CSS `border`, `border-top`, ….
     ///
-    /// Drawn as Unicode box-drawing characters, always 1 cell thick.
-    pub border: Option<Border>,
+    /// Drawn as Unicode box-drawing characters, always 1 cell thick. A
+    /// `None` side is undeclared, like a `Unit::Unset` padding side: it
+    /// keeps what lower-priority rules set, and is not drawn if none did.
+    pub border: Option<Edges<Option<Border>>>,
+
+    /// Label in the top border edge. Not inherited.
+    pub border_title: Option<BorderLabel>,
+
+    /// Label in the bottom border edge. Not inherited.
+    pub border_footer: Option<BorderLabel>,
+
+    /// Whether adjacent children share border lines. CSS
+    /// `border-collapse`. Inherited.
+    ///
+    /// With `Collapse`, a child's border is drawn on the line its
+    /// container or previous sibling already draws there, and crossing
+    /// lines join into `┬ ┴ ├ ┤ ┼` junctions. Only direct neighbours
+    /// collapse: a borderless wrapper in between keeps its children off
+    /// the outer line.
+    pub border_collapse: Option<BorderCollapse>,
 
     /// Element opacity 0.0–1.0. CSS `opacity`.
     ///
-    /// In TUI, implemented as dimming (`FontStyle::DIM`) rather than
-    /// alpha-blending. Values are typically quantized to visible/dim/hidden.
+    /// Fades the element and its subtree; nested opacities multiply. On
+    /// truecolor terminals colors are blended with the background beneath,
+    /// elsewhere faded elements fall back to `FontStyle::DIM`.
     pub opacity: Option<Float>,
 
     // -----------------------------------------------------------------------
@@ -169,16 +248,74 @@ pub struct Style {
     /// Horizontal text alignment. CSS `text-align`.
     pub text_align: Option<TextAlign>,
 
+    /// Where wrapped text may break: between words or anywhere. CSS
+    /// `word-break`. Inherited.
+    pub word_break: Option<WordBreak>,
+
+    /// Whether whitespace collapses and lines wrap. CSS `white-space`.
+    /// Inherited.
+    pub white_space: Option<WhiteSpace>,
+
+    /// How a line too long for the box is cut short. CSS `text-overflow`.
+    pub text_overflow: Option<TextOverflow>,
+
+    /// Show at most this many lines of text, marking the last one with
+    /// the `text_overflow` marker if any were dropped. CSS
+    /// `line-clamp`.
+    pub line_clamp: Option<LineClamp>,
+
     /// Text modifiers — bold, italic, underline, etc.
     ///
     /// Combine with `|`: `FontStyle::BOLD | FontStyle::ITALIC`.
     pub font_style: Option<FontStyle>,
 
+    /// Color of underlines, independent of the text color. CSS
+    /// `text-decoration-color`; written `underline-color`.
+    ///
+    /// Falls back to the text color when unset. Terminals without colored
+    /// underline support (SGR 58) ignore it.
+    pub underline_color: Option<Color>,
+
     // -----------------------------------------------------------------------
     // Overflow
     // -----------------------------------------------------------------------
     /// Content overflow behaviour. CSS `overflow`.
     pub overflow: Option<Overflow>,
+
+    /// Glyph and color of a scroll container's scrollbar thumb. Inherited.
+    pub scrollbar_thumb: Option<ScrollbarPart>,
+
+    /// Glyph and color of the scrollbar track the thumb moves along.
+    /// Inherited.
+    pub scrollbar_track: Option<ScrollbarPart>,
+}
+
+/// A per-side value that a longhand can leave undeclared.
+trait SideValue: Copy {
+    /// `over` on top of `base`.
+    fn merge(base: Self, over: Self) -> Self;
+}
+
+impl SideValue for Unit {
+    fn merge(base: Self, over: Self) -> Self {
+        if over.is_unset() { base } else { over }
+    }
+}
+
+impl SideValue for Option<Border> {
+    /// A side declared on both merges field by field, so `border-color`
+    /// keeps the style from an earlier `border`.
+    fn merge(base: Self, over: Self) -> Self {
+        match (base, over) {
+            (Some(base), Some(over)) => Some(base.merge(over)),
+            (base, over) => over.or(base),
+        }
+    }
+}
+
+/// Per-side merge for [`Edges`]: `over`'s unset sides keep `base`.
+fn merge_edges<T: SideValue>(base: Edges<T>, over: Edges<T>) -> Edges<T> {
+    base.zip(over, T::merge)
 }
 
 impl Style {
@@ -186,6 +323,7 @@ impl Style {
     ///
     /// `const` so it can be used in static contexts:
     /// ```rust
+    /// # use oxidui_style::Style;
     /// const EMPTY: Style = Style::new();
     /// ```
     pub const fn new() -> Self {
@@ -205,13 +343,32 @@ impl Style {
             align_items: None,
             justify_content: None,
             gap: None,
+            grid_template_columns: None,
+            grid_template_rows: None,
+            grid_template_areas: None,
+            grid_column: None,
+            grid_row: None,
+            grid_area: None,
+            position: None,
+            inset: None,
+            z_index: None,
             color: None,
             background: None,
             border: None,
+            border_title: None,
+            border_footer: None,
+            border_collapse: None,
             opacity: None,
             text_align: None,
+            word_break: None,
+            white_space: None,
+            text_overflow: None,
+            line_clamp: None,
             font_style: None,
+            underline_color: None,
             overflow: None,
+            scrollbar_thumb: None,
+            scrollbar_track: None,
         }
     }
 
@@ -228,12 +385,14 @@ impl Style {
     /// # Example
     ///
     /// ```rust
+    /// # use oxidui_style::{Style, color::{Color, NamedColor}};
     /// let mut s = Style { color: Some(Color::Named(NamedColor::White)), ..Style::new() };
     /// s.merge(&Style { color: Some(Color::Named(NamedColor::Red)), ..Style::new() });
     /// // s.color == Some(Red)
     /// ```
     pub fn merge(&mut self, other: &Style) {
-        // All fields are Copy, so we avoid `.clone()` entirely.
+        // Copy fields go through `m!`; the owned ones (labels, grid areas,
+        // overflow markers) are cloned in place.
         macro_rules! m {
             ($f:ident) => {
                 if let Some(v) = other.$f {
@@ -247,8 +406,21 @@ impl Style {
         m!(min_height);
         m!(max_width);
         m!(max_height);
-        m!(padding);
-        m!(margin);
+        // Edges merge side by side: a longhand like `padding-top` leaves the
+        // other three sides `Unset`, and those must not clobber the base.
+        macro_rules! e {
+            ($f:ident) => {
+                if let Some(v) = other.$f {
+                    self.$f = Some(match self.$f {
+                        Some(base) => merge_edges(base, v),
+                        None => v,
+                    });
+                }
+            };
+        }
+        e!(padding);
+        e!(margin);
+        e!(border);
         m!(display);
         m!(flex_direction);
         m!(flex_grow);
@@ -256,13 +428,42 @@ impl Style {
         m!(align_items);
         m!(justify_content);
         m!(gap);
+        m!(grid_template_columns);
+        m!(grid_template_rows);
+        if let Some(v) = &other.grid_template_areas {
+            self.grid_template_areas = Some(v.clone());
+        }
+        m!(grid_column);
+        m!(grid_row);
+        if let Some(v) = &other.grid_area {
+            self.grid_area = Some(v.clone());
+        }
+        m!(position);
+        e!(inset);
+        m!(z_index);
         m!(color);
         m!(background);
-        m!(border);
+        // The labels own their text, so they are cloned.
+        if let Some(v) = &other.border_title {
+            self.border_title = Some(v.clone());
+        }
+        if let Some(v) = &other.border_footer {
+            self.border_footer = Some(v.clone());
+        }
+        m!(border_collapse);
         m!(opacity);
         m!(text_align);
+        m!(word_break);
+        m!(white_space);
+        if let Some(v) = &other.text_overflow {
+            self.text_overflow = Some(v.clone());
+        }
+        m!(line_clamp);
         m!(font_style);
+        m!(underline_color);
         m!(overflow);
+        m!(scrollbar_thumb);
+        m!(scrollbar_track);
     }
 
     /// Non-mutating merge — returns a new `Style` without touching `self`.
@@ -350,6 +551,42 @@ impl Style {
         self.gap = Some(v);
         self
     }
+    pub fn with_grid_template_columns(mut self, v: TrackList) -> Self {
+        self.grid_template_columns = Some(v);
+        self
+    }
+    pub fn with_grid_template_rows(mut self, v: TrackList) -> Self {
+        self.grid_template_rows = Some(v);
+        self
+    }
+    pub fn with_grid_template_areas(mut self, v: GridAreas) -> Self {
+        self.grid_template_areas = Some(v);
+        self
+    }
+    pub fn with_grid_column(mut self, v: GridPlacement) -> Self {
+        self.grid_column = Some(v);
+        self
+    }
+    pub fn with_grid_row(mut self, v: GridPlacement) -> Self {
+        self.grid_row = Some(v);
+        self
+    }
+    pub fn with_grid_area(mut self, v: Str) -> Self {
+        self.grid_area = Some(v);
+        self
+    }
+    pub fn with_position(mut self, v: Position) -> Self {
+        self.position = Some(v);
+        self
+    }
+    pub fn with_inset(mut self, v: Edges<Unit>) -> Self {
+        self.inset = Some(v);
+        self
+    }
+    pub fn with_z_index(mut self, v: ZIndex) -> Self {
+        self.z_index = Some(v);
+        self
+    }
     pub fn with_color(mut self, v: Color) -> Self {
         self.color = Some(v);
         self
@@ -358,10 +595,26 @@ impl Style {
         self.background = Some(v);
         self
     }
-    pub fn with_border(mut self, v: Border) -> Self {
+    /// The same border on all four sides.
+    pub fn with_border(self, v: Border) -> Self {
+        self.with_border_sides(Edges::all(Some(v)))
+    }
+    pub fn with_border_sides(mut self, v: Edges<Option<Border>>) -> Self {
         self.border = Some(v);
         self
     }
+    pub fn with_border_title(mut self, v: BorderLabel) -> Self {
+        self.border_title = Some(v);
+        self
+    }
+    pub fn with_border_footer(mut self, v: BorderLabel) -> Self {
+        self.border_footer = Some(v);
+        self
+    }
+    pub fn with_border_collapse(mut self, v: BorderCollapse) -> Self {
+        self.border_collapse = Some(v);
+        self
+    }
     pub fn with_opacity(mut self, v: Float) -> Self {
         self.opacity = Some(v);
         self
@@ -370,14 +623,42 @@ impl Style {
         self.text_align = Some(v);
         self
     }
+    pub fn with_word_break(mut self, v: WordBreak) -> Self {
+        self.word_break = Some(v);
+        self
+    }
+    pub fn with_white_space(mut self, v: WhiteSpace) -> Self {
+        self.white_space = Some(v);
+        self
+    }
+    pub fn with_text_overflow(mut self, v: TextOverflow) -> Self {
+        self.text_overflow = Some(v);
+        self
+    }
+    pub fn with_line_clamp(mut self, v: LineClamp) -> Self {
+        self.line_clamp = Some(v);
+        self
+    }
     pub fn with_font_style(mut self, v: FontStyle) -> Self {
         self.font_style = Some(v);
         self
     }
+    pub fn with_underline_color(mut self, v: Color) -> Self {
+        self.underline_color = Some(v);
+        self
+    }
     pub fn with_overflow(mut self, v: Overflow) -> Self {
         self.overflow = Some(v);
         self
     }
+    pub fn with_scrollbar_thumb(mut self, v: ScrollbarPart) -> Self {
+        self.scrollbar_thumb = Some(v);
+        self
+    }
+    pub fn with_scrollbar_track(mut self, v: ScrollbarPart) -> Self {
+        self.scrollbar_track = Some(v);
+        self
+    }
 
     // -----------------------------------------------------------------------
     // Introspection
@@ -406,9 +687,110 @@ impl Style {
         self.color.is_some()
             || self.background.is_some()
             || self.border.is_some()
+            || self.border_title.is_some()
+            || self.border_footer.is_some()
             || self.opacity.is_some()
             || self.text_align.is_some()
             || self.font_style.is_some()
+            || self.underline_color.is_some()
+            || self.border_collapse.is_some()
+            || self.word_break.is_some()
+            || self.white_space.is_some()
+            || self.text_overflow.is_some()
+            || self.line_clamp.is_some()
+            || self.scrollbar_thumb.is_some()
+            || self.scrollbar_track.is_some()
+    }
+
+    // -----------------------------------------------------------------------
+    // Ratatui conversion
+    // -----------------------------------------------------------------------
+
+    /// Convert the visual fields to a `ratatui::style::Style`.
+    ///
+    /// Both are "patch" types, so undeclared fields stay unset and the
+    /// ratatui style inherits them from whatever is underneath:
+    ///
+    /// - `color` / `background` → `fg` / `bg`. `Color::Inherit` stays
+    ///   unset; `Color::None` becomes `Reset`. `underline_color` likewise.
+    /// - `font_style` → added modifiers. A declared `FontStyle` replaces
+    ///   the inherited one, so every flag it doesn't set is removed.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui(&self) -> ratatui::style::Style {
+        let mut out = ratatui::style::Style::new();
+        out.fg = self.color.and_then(color_patch);
+        out.bg = self.background.and_then(color_patch);
+        out.underline_color = self.underline_color.and_then(color_patch);
+        if let Some(f) = self.font_style {
+            out = out
+                .add_modifier(f.to_ratatui())
+                .remove_modifier(f.to_ratatui_cleared());
+        }
+        out
+    }
+
+    /// A `ratatui::widgets::Block` drawing this style's border and padding,
+    /// or `None` if neither is declared.
+    ///
+    /// Only sides with a border are drawn. A `Block` has one glyph set and
+    /// one border color, so they come from the first drawn side, clockwise
+    /// from the top; the color falls back to `color`. Border labels become
+    /// block titles. Padding keeps only `Cells` sides — see
+    /// [`Edges::to_ratatui_padding`].
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui_block(&self) -> Option<ratatui::widgets::Block<'static>> {
+        use ratatui::widgets::block::Position;
+        use ratatui::widgets::{Block, Borders};
+
+        let sides = self
+            .border
+            .unwrap_or(Edges::all(None))
+            .map(|b| b.filter(|b| !b.is_none()));
+        let first = [sides.top, sides.right, sides.bottom, sides.left]
+            .into_iter()
+            .flatten()
+            .next();
+        if first.is_none() && self.padding.is_none() {
+            return None;
+        }
+        let mut block = Block::new();
+        if let Some(border) = first
+            && let Some(set) = border.style.and_then(border::BorderStyle::to_ratatui_set)
+        {
+            let flags = [
+                (sides.top, Borders::TOP),
+                (sides.right, Borders::RIGHT),
+                (sides.bottom, Borders::BOTTOM),
+                (sides.left, Borders::LEFT),
+            ]
+            .into_iter()
+            .filter(|(side, _)| side.is_some())
+            .fold(Borders::NONE, |acc, (_, flag)| acc | flag);
+            let mut style = ratatui::style::Style::new();
+            style.fg = border.color.or(self.color).and_then(color_patch);
+            block = block.borders(flags).border_set(set).border_style(style);
+            for (label, position) in [
+                (&self.border_title, Position::Top),
+                (&self.border_footer, Position::Bottom),
+            ] {
+                if let Some(label) = label.as_ref().filter(|l| !l.text.is_empty()) {
+                    block = block.title(label.to_ratatui(style).position(position));
+                }
+            }
+        }
+        if let Some(padding) = self.padding {
+            block = block.padding(padding.to_ratatui_padding());
+        }
+        Some(block)
+    }
+}
+
+/// A declared color as a ratatui patch value: `Inherit` leaves it unset.
+#[cfg(feature = "ratatui")]
+fn color_patch(c: Color) -> Option<ratatui::style::Color> {
+    match c {
+        Color::Inherit => None,
+        c => Some(c.to_ratatui()),
     }
 }
 
@@ -418,7 +800,7 @@ mod tests {
     use super::*;
     use border::{Border, BorderStyle, Edges};
     use color::Color;
-    use color::NamedColor;
+    use color::{ColorParseError, NamedColor};
     use font::FontStyle;
     use number::{Float, Int};
     use std::borrow::Cow;
@@ -447,10 +829,65 @@ mod tests {
     fn color_hex_invalid() {
         assert_eq!(Color::from_hex_bytes(b"ff5f00"), None); // no #
         assert_eq!(Color::from_hex_bytes(b"#ff5fgg"), None); // bad nibble
-        assert_eq!(Color::from_hex_bytes(b"#fff"), None); // shorthand not supported
+        assert_eq!(Color::from_hex_bytes(b"#ff5f0"), None); // 5 digits
+        assert_eq!(Color::from_hex_bytes(b"#ff5f00a"), None); // 7 digits
+        assert_eq!(Color::from_hex_bytes(b"#ff5f00zz"), None); // bad alpha
         assert_eq!(Color::from_hex_bytes(b""), None);
     }
 
+    #[test]
+    fn color_from_hex_short_and_alpha() {
+        assert_eq!(
+            Color::from_hex_bytes(b"#fff"),
+            Some(Color::Rgb(255, 255, 255))
+        );
+        assert_eq!(
+            Color::from_hex_bytes(b"#f50a"),
+            Some(Color::Rgb(255, 85, 0))
+        );
+        assert_eq!(
+            Color::from_hex_bytes(b"#ff5f0080"),
+            Some(Color::Rgb(255, 95, 0))
+        );
+    }
+
+    #[test]
+    fn color_parse_functions() {
+        let p = |s: &str| s.parse::<Color>();
+        assert_eq!(p("rgb(255 95 0)"), Ok(Color::rgb(255, 95, 0)));
+        assert_eq!(p("rgb(255, 95, 0)"), Ok(Color::rgb(255, 95, 0)));
+        assert_eq!(p("RGBA(255, 95, 0, 0.5)"), Ok(Color::rgb(255, 95, 0)));
+        assert_eq!(p("rgb(100% 50% 0% / 25%)"), Ok(Color::rgb(255, 128, 0)));
+        assert_eq!(p("rgb( 1.4 2.6 3 )"), Ok(Color::rgb(1, 3, 3)));
+        assert_eq!(p("hsl(0 100% 50%)"), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(p("hsl(120deg, 100%, 25%)"), Ok(Color::rgb(0, 128, 0)));
+        assert_eq!(p("hsla(-120 100% 50% / 0.3)"), Ok(Color::rgb(0, 0, 255)));
+        assert_eq!(p("hsl(0 0% 100%)"), Ok(Color::rgb(255, 255, 255)));
+        assert_eq!(p("ansi(208)"), Ok(Color::Indexed(208)));
+
+        assert_eq!(p("rgb(256 0 0)"), Err(ColorParseError::Rgb));
+        assert_eq!(p("rgb(1 2 3 4 5)"), Err(ColorParseError::Rgb));
+        assert_eq!(p("rgb(1 2 3 / 2)"), Err(ColorParseError::Rgb));
+        assert_eq!(p("hsl(0 100 50)"), Err(ColorParseError::Hsl));
+        assert_eq!(p("ansi(256)"), Err(ColorParseError::Ansi));
+        assert_eq!(p("ansi(1.5)"), Err(ColorParseError::Ansi));
+        assert_eq!(p("rgb(1 2 3"), Err(ColorParseError::Unclosed));
+        assert_eq!(p("lab(1 2 3)"), Err(ColorParseError::Function));
+        assert_eq!(p("  "), Err(ColorParseError::Empty));
+    }
+
+    #[test]
+    fn color_parse_names() {
+        let p = |s: &str| s.parse::<Color>();
+        assert_eq!(p("red"), Ok(Color::Named(NamedColor::Red)));
+        assert_eq!(p("Bright-Cyan"), Ok(Color::Named(NamedColor::BrightCyan)));
+        assert_eq!(p("rebeccapurple"), Ok(Color::rgb(0x66, 0x33, 0x99)));
+        assert_eq!(p("grey"), p("gray"));
+        assert_eq!(p("transparent"), Ok(Color::None));
+        assert_eq!(p("inherit"), Ok(Color::Inherit));
+        assert_eq!(p("reddish"), Err(ColorParseError::Name));
+    }
+
     #[test]
     fn color_is_abstract() {
         assert!(Color::None.is_abstract());
@@ -459,6 +896,144 @@ mod tests {
         assert!(!Color::Named(NamedColor::Red).is_abstract());
     }
 
+    #[test]
+    fn color_depth_from_env() {
+        use color::ColorDepth as D;
+        let depth = |vars: &[(&str, &str)]| {
+            D::from_env(|name| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            })
+        };
+        assert_eq!(depth(&[]), D::Ansi16);
+        assert_eq!(depth(&[("TERM", "xterm")]), D::Ansi16);
+        assert_eq!(depth(&[("TERM", "xterm-256color")]), D::Ansi256);
+        assert_eq!(depth(&[("TERM", "xterm-direct")]), D::TrueColor);
+        assert_eq!(
+            depth(&[("TERM", "screen-256color"), ("COLORTERM", "truecolor")]),
+            D::TrueColor
+        );
+        assert_eq!(depth(&[("COLORTERM", "24bit")]), D::TrueColor);
+        assert_eq!(depth(&[("TERM", "dumb")]), D::Monochrome);
+        assert_eq!(
+            depth(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]),
+            D::Monochrome
+        );
+        // An empty NO_COLOR doesn't count.
+        assert_eq!(
+            depth(&[("NO_COLOR", ""), ("TERM", "xterm-256color")]),
+            D::Ansi256
+        );
+    }
+
+    #[test]
+    fn color_downsample() {
+        use color::ColorDepth as D;
+        // Exact palette entries map to themselves.
+        assert_eq!(
+            Color::rgb(95, 135, 175).downsample(D::Ansi256),
+            Color::Indexed(67)
+        );
+        assert_eq!(
+            Color::rgb(8, 8, 8).downsample(D::Ansi256),
+            Color::Indexed(232)
+        );
+        // Near-greys go to the grey ramp, not a tinted cube entry.
+        assert_eq!(
+            Color::rgb(100, 101, 99).downsample(D::Ansi256),
+            Color::Indexed(241)
+        );
+        assert_eq!(
+            Color::rgb(10, 10, 200).downsample(D::Ansi16),
+            Color::Named(NamedColor::Blue)
+        );
+        assert_eq!(
+            Color::Indexed(196).downsample(D::Ansi16),
+            Color::Named(NamedColor::BrightRed)
+        );
+        assert_eq!(
+            Color::rgb(250, 250, 250).downsample(D::Ansi16),
+            Color::Named(NamedColor::BrightWhite)
+        );
+        // Already representable, or abstract: unchanged.
+        assert_eq!(
+            Color::Indexed(42).downsample(D::Ansi256),
+            Color::Indexed(42)
+        );
+        let red = Color::Named(NamedColor::Red);
+        assert_eq!(red.downsample(D::Ansi16), red);
+        assert_eq!(Color::Inherit.downsample(D::Monochrome), Color::Inherit);
+        assert_eq!(red.downsample(D::Monochrome), Color::None);
+    }
+
+    #[test]
+    fn color_lighten_darken_saturate() {
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!(red.darken(0.25), Color::rgb(128, 0, 0));
+        assert_eq!(red.lighten(1.0), Color::rgb(255, 255, 255));
+        assert_eq!(red.darken(2.0), Color::rgb(0, 0, 0));
+        assert_eq!(red.desaturate(1.0), Color::rgb(128, 128, 128));
+        assert_eq!(Color::rgb(128, 96, 96).saturate(1.0), Color::rgb(224, 0, 0));
+        // Named colors go through the palette.
+        assert_eq!(
+            Color::Named(NamedColor::BrightRed).darken(0.25),
+            Color::rgb(128, 0, 0)
+        );
+        assert_eq!(Color::Inherit.lighten(0.5), Color::Inherit);
+    }
+
+    #[test]
+    fn color_mix() {
+        let (a, b) = (Color::rgb(0, 100, 200), Color::rgb(100, 200, 0));
+        assert_eq!(a.mix(b, 0.0), a);
+        assert_eq!(a.mix(b, 1.0), b);
+        assert_eq!(a.mix(b, 0.25), Color::rgb(25, 125, 150));
+        assert_eq!(a.mix(Color::None, 0.4), a);
+        assert_eq!(a.mix(Color::None, 0.6), Color::None);
+    }
+
+    #[test]
+    fn color_contrast() {
+        let white = Color::rgb(255, 255, 255);
+        assert_eq!(white.luminance(), Some(1.0));
+        assert_eq!(Color::None.luminance(), None);
+        assert_eq!(white.contrast_ratio(white), Some(1.0));
+        // #767676 is the classic "just passes AA on white" grey.
+        let ratio = Color::rgb(0x76, 0x76, 0x76).contrast_ratio(white);
+        assert!(ratio.is_some_and(|r| (4.5..4.6).contains(&r)));
+
+        let navy = Color::rgb(0, 0, 128);
+        assert_eq!(navy.readable_foreground(), white);
+        assert_eq!(white.readable_foreground(), Color::rgb(0, 0, 0));
+        assert_eq!(Color::Inherit.readable_foreground(), Color::None);
+        assert_eq!(
+            navy.most_readable(&[Color::Named(NamedColor::Blue), Color::rgb(255, 255, 0)]),
+            Some(Color::rgb(255, 255, 0))
+        );
+    }
+
+    #[test]
+    fn palette_lookup() {
+        use color::Palette;
+        assert_eq!(Palette::XTERM.get(NamedColor::Blue), (0, 0, 238));
+        let p = Palette::XTERM.with(NamedColor::Blue, (1, 2, 3));
+        assert_eq!(p.get(NamedColor::Blue), (1, 2, 3));
+        assert_eq!(p.get(NamedColor::Red), Palette::XTERM.get(NamedColor::Red));
+    }
+
+    #[test]
+    fn indexed_to_rgb() {
+        assert_eq!(Color::Indexed(16).to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Indexed(231).to_rgb(), Some((255, 255, 255)));
+        assert_eq!(Color::Indexed(255).to_rgb(), Some((238, 238, 238)));
+        assert_eq!(
+            Color::Indexed(9).to_rgb(),
+            Some(color::Palette::XTERM.get(NamedColor::BrightRed))
+        );
+        assert_eq!(Color::None.to_rgb(), None);
+    }
+
     // --- Int ---
 
     #[test]
@@ -602,7 +1177,7 @@ mod tests {
     #[test]
     fn border_with_color() {
         let b = Border::ROUNDED.with_color(Color::Named(NamedColor::Cyan));
-        assert_eq!(b.style, BorderStyle::Rounded);
+        assert_eq!(b.style, Some(BorderStyle::Rounded));
         assert_eq!(b.color, Some(Color::Named(NamedColor::Cyan)));
     }
 
@@ -647,6 +1222,74 @@ mod tests {
         assert_eq!(merged.color, Some(Color::Named(NamedColor::Red)));
     }
 
+    #[test]
+    fn merge_edges_per_side() {
+        let mut base = Style::new().with_padding_all(Unit::cells(2));
+        let mut top = Edges::all(Unit::UNSET);
+        top.top = Unit::cells(5);
+        base.merge(&Style::new().with_padding(top));
+        assert_eq!(
+            base.padding,
+            Some(Edges::new(
+                Unit::cells(5),
+                Unit::cells(2),
+                Unit::cells(2),
+                Unit::cells(2)
+            ))
+        );
+        // A full shorthand still replaces every side.
+        base.merge(&Style::new().with_padding_all(Unit::ZERO));
+        assert_eq!(base.padding, Some(Edges::all(Unit::ZERO)));
+    }
+
+    #[test]
+    fn merge_border_per_side() {
+        let mut base = Style::new().with_border(Border::ROUNDED);
+        base.merge(&Style::new().with_border_sides(Edges {
+            bottom: Some(Border::NONE),
+            ..Edges::all(None)
+        }));
+        let sides = base.border.map(|b| b.map(|s| s.and_then(|s| s.style)));
+        assert_eq!(
+            sides,
+            Some(Edges::new(
+                Some(BorderStyle::Rounded),
+                Some(BorderStyle::Rounded),
+                Some(BorderStyle::None),
+                Some(BorderStyle::Rounded)
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_border_labels() {
+        let mut base = Style::new()
+            .with_border_title(BorderLabel::new("Files"))
+            .with_border_footer(BorderLabel::new("3 items"));
+        base.merge(&Style::new().with_border_title(BorderLabel::new("Open")));
+        assert_eq!(base.border_title, Some(BorderLabel::new("Open")));
+        assert_eq!(base.border_footer, Some(BorderLabel::new("3 items")));
+    }
+
+    #[test]
+    fn merge_text_overflow() {
+        let mut base = Style::new()
+            .with_text_overflow(TextOverflow::Marker(Str::from_static(" [more]")))
+            .with_line_clamp(LineClamp::Lines(Int::new(2)));
+        base.merge(&Style::new().with_white_space(WhiteSpace::NoWrap));
+        assert_eq!(
+            base.text_overflow,
+            Some(TextOverflow::Marker(Str::from_static(" [more]")))
+        );
+        base.merge(&Style::new().with_text_overflow(TextOverflow::MiddleEllipsis));
+        assert_eq!(base.text_overflow, Some(TextOverflow::MiddleEllipsis));
+        assert_eq!(base.white_space, Some(WhiteSpace::NoWrap));
+        assert_eq!(base.line_clamp, Some(LineClamp::Lines(Int::new(2))));
+        // `none` is a value of its own, so it undoes the clamp.
+        base.merge(&Style::new().with_line_clamp(LineClamp::None));
+        assert_eq!(base.line_clamp, Some(LineClamp::None));
+    }
+
     #[test]
     fn style_is_empty() {
         assert!(Style::new().is_empty());
@@ -664,7 +1307,7 @@ mod tests {
         assert_eq!(s.width, Some(Unit::FULL));
         assert_eq!(s.background, Some(Color::Named(NamedColor::Blue)));
         assert_eq!(s.font_style, Some(FontStyle::BOLD));
-        assert_eq!(s.border, Some(Border::ROUNDED));
+        assert_eq!(s.border, Some(Edges::all(Some(Border::ROUNDED))));
         assert!(s.color.is_none());
     }
 
@@ -677,12 +1320,313 @@ mod tests {
         let visual = Style::new().with_color(Color::Named(NamedColor::Red));
         assert!(!visual.has_layout());
         assert!(visual.has_visuals());
+
+        let text = [
+            Style::new().with_border_collapse(BorderCollapse::Collapse),
+            Style::new().with_word_break(WordBreak::BreakAll),
+            Style::new().with_white_space(WhiteSpace::Pre),
+            Style::new().with_text_overflow(TextOverflow::Ellipsis),
+            Style::new().with_line_clamp(LineClamp::None),
+        ];
+        assert!(text.iter().all(Style::has_visuals));
+
+        let thumb = Style::new().with_scrollbar_thumb(ScrollbarPart::glyph('┃'));
+        let track = Style::new().with_scrollbar_track(ScrollbarPart::default());
+        assert!(thumb.has_visuals() && track.has_visuals());
     }
 
     // Ratatui integration
     #[test]
     #[cfg(feature = "ratatui")]
     fn convert_to_ratatui() {
-        assert!(true)
+        use ratatui::style::Color as R;
+        assert_eq!(Color::Named(NamedColor::Red).to_ratatui(), R::Red);
+        assert_eq!(Color::rgb(1, 2, 3).to_ratatui(), R::Rgb(1, 2, 3));
+        assert_eq!(Color::indexed(240).to_ratatui(), R::Indexed(240));
+        assert_eq!(Color::Inherit.to_ratatui(), R::Reset);
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn font_style_bits_to_ratatui() {
+        use ratatui::style::Modifier as M;
+        let cases = [
+            (FontStyle::BOLD, M::BOLD),
+            (FontStyle::ITALIC, M::ITALIC),
+            (FontStyle::UNDERLINE, M::UNDERLINED),
+            (FontStyle::BLINK, M::SLOW_BLINK),
+            (FontStyle::STRIKETHROUGH, M::CROSSED_OUT),
+            (FontStyle::DIM, M::DIM),
+            (FontStyle::REVERSE, M::REVERSED),
+            (FontStyle::HIDDEN, M::HIDDEN),
+            (FontStyle::RAPID_BLINK, M::RAPID_BLINK),
+            (FontStyle::DOUBLE_UNDERLINE, M::UNDERLINED),
+            (FontStyle::CURLY_UNDERLINE, M::UNDERLINED),
+            (FontStyle::OVERLINE, M::empty()),
+        ];
+        let mut all = FontStyle::NORMAL;
+        for (flag, modifier) in cases {
+            // The flags ratatui lacks add a spare bit it doesn't know.
+            let m = flag.to_ratatui();
+            assert_eq!(m & M::all(), modifier, "{flag:?}");
+            assert_eq!(FontStyle::from_ratatui(m), flag, "{flag:?}");
+            all |= flag;
+        }
+        assert_eq!(all, FontStyle::ALL);
+        assert_eq!(FontStyle::NORMAL.to_ratatui(), M::empty());
+        assert_eq!(
+            (FontStyle::BOLD | FontStyle::DIM).to_ratatui(),
+            M::BOLD | M::DIM
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn font_style_clears_what_it_does_not_set() {
+        use ratatui::style::Modifier as M;
+        let cleared = FontStyle::CURLY_UNDERLINE.to_ratatui_cleared();
+        assert!(!cleared.contains(M::UNDERLINED));
+        assert!(cleared.contains(M::BOLD | M::REVERSED | M::HIDDEN));
+    }
+
+    #[test]
+    fn font_style_sgr() {
+        assert!(FontStyle::NORMAL.sgr().is_empty());
+        assert_eq!(
+            (FontStyle::OVERLINE | FontStyle::REVERSE | FontStyle::BOLD).sgr(),
+            ["1", "7", "53"]
+        );
+        assert_eq!(
+            (FontStyle::UNDERLINE | FontStyle::DOUBLE_UNDERLINE).sgr(),
+            ["4:2"]
+        );
+        assert_eq!(FontStyle::UNDERLINE.sgr(), ["4"]);
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn underline_color_to_ratatui() {
+        use ratatui::style::Color as R;
+        let s = Style::new()
+            .with_font_style(FontStyle::CURLY_UNDERLINE)
+            .with_underline_color(Color::Named(NamedColor::Red))
+            .to_ratatui();
+        assert_eq!(s.underline_color, Some(R::Red));
+        assert_eq!(Style::new().to_ratatui().underline_color, None);
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn border_style_to_ratatui() {
+        use ratatui::symbols::border;
+        use ratatui::widgets::BorderType as B;
+        let cases = [
+            (BorderStyle::None, None, None),
+            (BorderStyle::Solid, Some(B::Plain), Some(border::PLAIN)),
+            (
+                BorderStyle::Rounded,
+                Some(B::Rounded),
+                Some(border::ROUNDED),
+            ),
+            (BorderStyle::Double, Some(B::Double), Some(border::DOUBLE)),
+            (BorderStyle::Thick, Some(B::Thick), Some(border::THICK)),
+        ];
+        for (style, kind, set) in cases {
+            assert_eq!(style.to_ratatui(), kind, "{style:?}");
+            assert_eq!(style.to_ratatui_set(), set, "{style:?}");
+        }
+        let dashed = BorderStyle::Dashed.to_ratatui_set();
+        assert_eq!(BorderStyle::Dashed.to_ratatui(), Some(B::Plain));
+        assert_eq!(dashed.map(|s| s.horizontal_top), Some("╌"));
+        assert_eq!(dashed.map(|s| s.vertical_left), Some("╎"));
+        assert_eq!(dashed.map(|s| s.top_left), Some("┌"));
+    }
+
+    #[test]
+    fn border_corners_join_sides() {
+        use border::Corner;
+        let corner = |h: BorderStyle, v| h.corner(v, Corner::BottomRight);
+        assert_eq!(corner(BorderStyle::Thick, BorderStyle::Thick), Some("┛"));
+        assert_eq!(corner(BorderStyle::Rounded, BorderStyle::Solid), Some("┘"));
+        assert_eq!(corner(BorderStyle::Solid, BorderStyle::Thick), Some("┚"));
+        // No glyph joins these: the horizontal side keeps its own corner.
+        assert_eq!(corner(BorderStyle::Double, BorderStyle::Thick), Some("╝"));
+        assert_eq!(corner(BorderStyle::Ascii, BorderStyle::Solid), Some("+"));
+        assert_eq!(corner(BorderStyle::None, BorderStyle::Solid), None);
+    }
+
+    #[test]
+    fn border_junctions() {
+        use border::join;
+        assert_eq!(join("┌", "┐"), Some("┬"));
+        assert_eq!(join("┤", "├"), Some("┼"));
+        assert_eq!(join("║", "─"), Some("╫"));
+        // Heavy and double never share a glyph: the heavy arms go light.
+        assert_eq!(join("━", "║"), Some("╫"));
+        assert_eq!(join("╌", "╌"), Some("╌"));
+        assert_eq!(join("|", "│"), None);
+    }
+
+    #[test]
+    fn border_style_names() {
+        use border::BorderGlyphs;
+        static HASH: BorderGlyphs = BorderGlyphs {
+            horizontal: "#",
+            ..BorderGlyphs::ASCII
+        };
+        assert_eq!(BorderStyle::named("ascii"), Some(BorderStyle::Ascii));
+        assert_eq!(BorderStyle::named("lib-test-hash"), None);
+        BorderGlyphs::register("lib-test-hash", &HASH);
+        assert_eq!(
+            BorderStyle::named("lib-test-hash"),
+            Some(BorderStyle::Custom(&HASH))
+        );
+        // Built-in names can't be taken over.
+        BorderGlyphs::register("solid", &HASH);
+        assert_eq!(BorderStyle::named("solid"), Some(BorderStyle::Solid));
+        assert_eq!(
+            BorderStyle::Custom(&HASH).glyphs().map(|g| g.horizontal),
+            Some("#")
+        );
+    }
+
+    #[test]
+    fn charset_from_env() {
+        use border::Charset;
+        let env = |pairs: &'static [(&str, &str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| (*v).to_owned())
+            }
+        };
+        assert_eq!(Charset::from_env(env(&[])), Charset::Unicode);
+        assert_eq!(
+            Charset::from_env(env(&[("LANG", "en_US.UTF-8")])),
+            Charset::Unicode
+        );
+        assert_eq!(
+            Charset::from_env(env(&[("LANG", "de_DE.utf8")])),
+            Charset::Unicode
+        );
+        assert_eq!(Charset::from_env(env(&[("LANG", "C")])), Charset::Ascii);
+        // `LC_ALL` overrides `LANG`; empty values are skipped.
+        assert_eq!(
+            Charset::from_env(env(&[("LC_ALL", "POSIX"), ("LANG", "en_US.UTF-8")])),
+            Charset::Ascii
+        );
+        assert_eq!(
+            Charset::from_env(env(&[("LC_ALL", ""), ("LANG", "en_US.UTF-8")])),
+            Charset::Unicode
+        );
+        assert_eq!(
+            BorderStyle::Rounded.for_charset(Charset::Ascii),
+            BorderStyle::Ascii
+        );
+        assert_eq!(
+            BorderStyle::None.for_charset(Charset::Ascii),
+            BorderStyle::None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn style_to_ratatui_style() {
+        use ratatui::style::{Color as R, Modifier as M, Style as RStyle};
+        assert_eq!(Style::new().to_ratatui(), RStyle::new());
+
+        let s = Style::new()
+            .with_color(Color::Named(NamedColor::Cyan))
+            .with_background(Color::None)
+            .with_font_style(FontStyle::BOLD | FontStyle::ITALIC)
+            .to_ratatui();
+        assert_eq!(s.fg, Some(R::Cyan));
+        assert_eq!(s.bg, Some(R::Reset));
+        assert_eq!(s.add_modifier, M::BOLD | M::ITALIC);
+        // The spare bits are cleared too, so an overline doesn't linger.
+        assert!(s.sub_modifier.contains(FontStyle::OVERLINE.to_ratatui()));
+        assert_eq!(
+            s.sub_modifier & M::all(),
+            M::UNDERLINED
+                | M::SLOW_BLINK
+                | M::RAPID_BLINK
+                | M::CROSSED_OUT
+                | M::DIM
+                | M::REVERSED
+                | M::HIDDEN
+        );
+
+        // `Inherit` leaves the ratatui field unset so it patches through.
+        let s = Style::new().with_color(Color::Inherit).to_ratatui();
+        assert_eq!(s.fg, None);
+    }
+
+    #[test]
+    #[cfg(feature = "ratatui")]
+    fn style_to_ratatui_block() {
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+        use ratatui::style::Color as R;
+        use ratatui::widgets::{Padding, Widget};
+
+        assert!(Style::new().to_ratatui_block().is_none());
+        assert!(
+            Style::new()
+                .with_border(Border::NONE)
+                .to_ratatui_block()
+                .is_none()
+        );
+
+        let style = Style::new()
+            .with_color(Color::Named(NamedColor::Green))
+            .with_border(Border::ROUNDED)
+            .with_padding(Edges::new(
+                Unit::cells(1),
+                Unit::cells(2),
+                Unit::ZERO,
+                Unit::percent(10),
+            ));
+        let Some(block) = style.to_ratatui_block() else {
+            panic!("border and padding are set");
+        };
+        let area = Rect::new(0, 0, 8, 5);
+        // left 1 border + 0 (percent dropped), right 1 + 2, top 1 + 1, bottom 1.
+        assert_eq!(block.inner(area), Rect::new(1, 2, 4, 2));
+
+        let mut buf = Buffer::empty(area);
+        block.render(area, &mut buf);
+        assert_eq!(buf.get(0, 0).symbol(), "╭");
+        assert_eq!(buf.get(7, 4).symbol(), "╯");
+        assert_eq!(buf.get(0, 0).fg, R::Green);
+
+        // Border color overrides `color`.
+        let style = style.with_border(Border::ROUNDED.with_color(Color::Named(NamedColor::Red)));
+        let mut buf = Buffer::empty(area);
+        if let Some(block) = style.to_ratatui_block() {
+            block.render(area, &mut buf);
+        }
+        assert_eq!(buf.get(0, 0).fg, R::Red);
+
+        // Titles sit on the top edge, footers on the bottom.
+        let labelled = style
+            .with_border_title(BorderLabel::new("ab"))
+            .with_border_footer(BorderLabel::new("cd").with_align(TextAlign::Right));
+        let mut buf = Buffer::empty(area);
+        if let Some(block) = labelled.to_ratatui_block() {
+            block.render(area, &mut buf);
+        }
+        assert_eq!(buf.get(1, 0).symbol(), "a");
+        assert_eq!(buf.get(6, 4).symbol(), "d");
+
+        // Padding alone still yields a (borderless) block.
+        let padded = Style::new()
+            .with_padding_all(Unit::cells(1))
+            .to_ratatui_block();
+        assert_eq!(padded.map(|b| b.inner(area)), Some(Rect::new(1, 1, 6, 3)));
+        assert_eq!(
+            Edges::all(Unit::cells(-3)).to_ratatui_padding(),
+            Padding::zero()
+        );
     }
 }
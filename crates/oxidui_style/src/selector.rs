@@ -0,0 +1,500 @@
+//! Selectors — which elements a stylesheet rule applies to.
+//!
+//! Supported grammar (a CSS subset):
+//!
+//! | Syntax    | Matches                                        |
+//! |-----------|------------------------------------------------|
+//! | `Box`     | elements whose tag is `Box`                    |
+//! | `*`       | any element                                    |
+//! | `.panel`  | elements with class `panel`                    |
+//! | `#nav`    | the element with id `nav`                      |
+//! | `Box.a.b` | compound — all of the above must hold          |
+//! | `A B`     | `B` anywhere inside an `A` (descendant)        |
+//! | `A > B`   | `B` whose parent is an `A` (child)             |
+//!
+//! Elements are described to the matcher as a path of [`PathElement`]s from
+//! the root down to the element being styled, so this crate does not need
+//! to know about the element tree itself.
+//!
+//! ```rust
+//! # use oxidui_style::{selector::{PathElement, Selector, Specificity}, str::Str};
+//! let sel: Selector = "#nav > Text.title".parse().unwrap();
+//! assert_eq!(sel.specificity(), Specificity { ids: 1, classes: 1, types: 1 });
+//!
+//! let title = [Str::from_static("title")];
+//! let path = [
+//!     PathElement::new("Box").with_id("nav"),
+//!     PathElement::new("Text").with_classes(&title),
+//! ];
+//! assert!(sel.matches(&path));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::str::Str;
+
+// ---------------------------------------------------------------------------
+// Specificity
+// ---------------------------------------------------------------------------
+
+/// CSS specificity — `(ids, classes, types)`, compared lexicographically.
+///
+/// A more specific selector wins over a less specific one regardless of
+/// source order; equal specificity falls back to source order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Specificity {
+    pub ids: u16,
+    pub classes: u16,
+    pub types: u16,
+}
+
+impl fmt::Display for Specificity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{},{})", self.ids, self.classes, self.types)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Element path
+// ---------------------------------------------------------------------------
+
+/// The identity of one element, as seen by selectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathElement<'a> {
+    pub tag: &'a str,
+    pub id: Option<&'a str>,
+    pub classes: &'a [Str],
+}
+
+impl<'a> PathElement<'a> {
+    pub const fn new(tag: &'a str) -> Self {
+        Self {
+            tag,
+            id: None,
+            classes: &[],
+        }
+    }
+    pub const fn with_id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+    pub const fn with_classes(mut self, classes: &'a [Str]) -> Self {
+        self.classes = classes;
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Selector
+// ---------------------------------------------------------------------------
+
+/// How two compounds in a selector relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combinator {
+    /// `A B` — `B` has an `A` ancestor at any depth.
+    Descendant,
+    /// `A > B` — `B`'s parent is an `A`.
+    Child,
+}
+
+/// A run of simple selectors with no combinator between them, e.g.
+/// `Box#nav.panel`. `tag: None` is the universal selector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Compound {
+    pub tag: Option<Str>,
+    pub id: Option<Str>,
+    pub classes: Vec<Str>,
+}
+
+impl Compound {
+    /// `true` if this compound alone matches `el`.
+    pub fn matches(&self, el: &PathElement<'_>) -> bool {
+        self.tag.as_ref().is_none_or(|t| t.as_str() == el.tag)
+            && self.id.as_ref().is_none_or(|id| Some(id.as_str()) == el.id)
+            && self
+                .classes
+                .iter()
+                .all(|c| el.classes.iter().any(|e| e.as_str() == c.as_str()))
+    }
+
+    fn specificity(&self) -> Specificity {
+        Specificity {
+            ids: u16::from(self.id.is_some()),
+            classes: self.classes.len().try_into().unwrap_or(u16::MAX),
+            types: u16::from(self.tag.is_some()),
+        }
+    }
+}
+
+impl fmt::Display for Compound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.tag {
+            Some(tag) => f.write_str(tag.as_str())?,
+            None if self.id.is_none() && self.classes.is_empty() => f.write_str("*")?,
+            None => {}
+        }
+        if let Some(id) = &self.id {
+            write!(f, "#{}", id.as_str())?;
+        }
+        for class in &self.classes {
+            write!(f, ".{}", class.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// A parsed selector: compounds joined by combinators, left to right.
+///
+/// `Display` prints the normalized form (`A > B.c`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Selector {
+    compounds: Vec<Compound>,
+    /// `combinators[i]` sits between `compounds[i]` and `compounds[i + 1]`.
+    combinators: Vec<Combinator>,
+}
+
+impl Selector {
+    /// Parse selector text. See the [module docs](self) for the grammar.
+    pub fn parse(src: &str) -> Result<Self, SelectorError> {
+        SelectorParser { src, pos: 0 }.selector()
+    }
+
+    /// The compounds, outermost first. The last one is the subject — the
+    /// element the rule styles.
+    pub fn compounds(&self) -> &[Compound] {
+        &self.compounds
+    }
+
+    /// The combinators between consecutive [`Selector::compounds`].
+    pub fn combinators(&self) -> &[Combinator] {
+        &self.combinators
+    }
+
+    /// Sum of the specificity of every compound.
+    pub fn specificity(&self) -> Specificity {
+        self.compounds
+            .iter()
+            .map(Compound::specificity)
+            .fold(Specificity::default(), |a, b| Specificity {
+                ids: a.ids.saturating_add(b.ids),
+                classes: a.classes.saturating_add(b.classes),
+                types: a.types.saturating_add(b.types),
+            })
+    }
+
+    /// `true` if the selector matches the last element of `path`.
+    ///
+    /// `path` runs from the root to the element being styled; the earlier
+    /// entries are its ancestors.
+    pub fn matches(&self, path: &[PathElement<'_>]) -> bool {
+        match (self.compounds.len(), path.len()) {
+            (0, _) | (_, 0) => false,
+            (n, len) => self.matches_at(n - 1, path, len - 1),
+        }
+    }
+
+    /// Does `compounds[i]` (and everything left of it) match with
+    /// `compounds[i]` anchored at `path[j]`?
+    fn matches_at(&self, i: usize, path: &[PathElement<'_>], j: usize) -> bool {
+        if !self.compounds[i].matches(&path[j]) {
+            return false;
+        }
+        if i == 0 {
+            return true;
+        }
+        match self.combinators[i - 1] {
+            Combinator::Child => j > 0 && self.matches_at(i - 1, path, j - 1),
+            Combinator::Descendant => (0..j).rev().any(|k| self.matches_at(i - 1, path, k)),
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, compound) in self.compounds.iter().enumerate() {
+            if i > 0 {
+                f.write_str(match self.combinators[i - 1] {
+                    Combinator::Descendant => " ",
+                    Combinator::Child => " > ",
+                })?;
+            }
+            compound.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare against selector text in its normalized form.
+impl PartialEq<str> for Selector {
+    fn eq(&self, other: &str) -> bool {
+        *self.to_string() == *other
+    }
+}
+impl PartialEq<&str> for Selector {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Why a selector was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorErrorKind {
+    /// Nothing to match.
+    Empty,
+    /// `.` or `#` not followed by a name.
+    ExpectedName(char),
+    /// A combinator with nothing on one side, e.g. `> A` or `A >`.
+    DanglingCombinator,
+    /// More than one `#id` in a compound.
+    DuplicateId,
+    /// A character that is not part of the grammar.
+    Unexpected(char),
+}
+
+/// A selector error with the byte offset it was found at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub kind: SelectorErrorKind,
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SelectorErrorKind::Empty => f.write_str("empty selector"),
+            SelectorErrorKind::ExpectedName(c) => write!(f, "expected a name after `{c}`"),
+            SelectorErrorKind::DanglingCombinator => {
+                f.write_str("`>` must have a selector on both sides")
+            }
+            SelectorErrorKind::DuplicateId => f.write_str("a selector can only have one `#id`"),
+            SelectorErrorKind::Unexpected(c) => write!(f, "unexpected `{c}` in selector"),
+        }
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+struct SelectorParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+impl SelectorParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn error(&self, kind: SelectorErrorKind) -> SelectorError {
+        SelectorError {
+            kind,
+            offset: self.pos,
+        }
+    }
+
+    /// Skip whitespace; returns `true` if any was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.src[self.pos..];
+        let n = rest.len() - rest.trim_start().len();
+        self.pos += n;
+        n > 0
+    }
+
+    fn name(&mut self) -> Option<Str> {
+        let rest = &self.src[self.pos..];
+        let len = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(Str::from_string(rest[..len].to_owned()))
+    }
+
+    fn selector(mut self) -> Result<Selector, SelectorError> {
+        let mut sel = Selector {
+            compounds: Vec::new(),
+            combinators: Vec::new(),
+        };
+        self.skip_whitespace();
+        if self.pos == self.src.len() {
+            return Err(self.error(SelectorErrorKind::Empty));
+        }
+        loop {
+            if self.peek() == Some('>') {
+                return Err(self.error(SelectorErrorKind::DanglingCombinator));
+            }
+            sel.compounds.push(self.compound()?);
+
+            let spaced = self.skip_whitespace();
+            let combinator = match self.peek() {
+                None => return Ok(sel),
+                Some('>') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    if self.pos == self.src.len() {
+                        return Err(self.error(SelectorErrorKind::DanglingCombinator));
+                    }
+                    Combinator::Child
+                }
+                Some(_) if spaced => Combinator::Descendant,
+                Some(c) => return Err(self.error(SelectorErrorKind::Unexpected(c))),
+            };
+            sel.combinators.push(combinator);
+        }
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let mut compound = Compound::default();
+        let start = self.pos;
+        if self.peek() == Some('*') {
+            self.pos += 1;
+        } else {
+            compound.tag = self.name();
+        }
+        while let Some(c @ ('.' | '#')) = self.peek() {
+            let at = self.pos;
+            self.pos += 1;
+            let Some(name) = self.name() else {
+                return Err(self.error(SelectorErrorKind::ExpectedName(c)));
+            };
+            if c == '.' {
+                compound.classes.push(name);
+            } else if compound.id.is_some() {
+                self.pos = at;
+                return Err(self.error(SelectorErrorKind::DuplicateId));
+            } else {
+                compound.id = Some(name);
+            }
+        }
+        if self.pos == start {
+            let c = self.peek().unwrap_or(' ');
+            return Err(self.error(SelectorErrorKind::Unexpected(c)));
+        }
+        Ok(compound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sel(s: &str) -> Selector {
+        s.parse().unwrap()
+    }
+
+    fn err(s: &str) -> (SelectorErrorKind, usize) {
+        let e = Selector::parse(s).unwrap_err();
+        (e.kind, e.offset)
+    }
+
+    #[test]
+    fn parse_and_display_roundtrip() {
+        for (src, normalized) in [
+            ("Box", "Box"),
+            ("*", "*"),
+            (".a.b", ".a.b"),
+            ("Box#nav.panel", "Box#nav.panel"),
+            ("  A   B  ", "A B"),
+            ("A>B", "A > B"),
+            ("A >  B   C", "A > B C"),
+            ("*.x", ".x"),
+        ] {
+            assert_eq!(sel(src).to_string(), normalized, "{src}");
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(err(""), (SelectorErrorKind::Empty, 0));
+        assert_eq!(err("A."), (SelectorErrorKind::ExpectedName('.'), 2));
+        assert_eq!(err("> A"), (SelectorErrorKind::DanglingCombinator, 0));
+        assert_eq!(err("A >"), (SelectorErrorKind::DanglingCombinator, 3));
+        assert_eq!(err("#a#b"), (SelectorErrorKind::DuplicateId, 2));
+        assert_eq!(err("A:hover"), (SelectorErrorKind::Unexpected(':'), 1));
+        assert_eq!(err("A > > B"), (SelectorErrorKind::DanglingCombinator, 4));
+    }
+
+    #[test]
+    fn specificity_ordering() {
+        let s = |src| sel(src).specificity();
+        assert_eq!(
+            s("Box#a.b.c Text"),
+            Specificity {
+                ids: 1,
+                classes: 2,
+                types: 2
+            }
+        );
+        assert!(s("#a") > s(".a.b.c.d"));
+        assert!(s(".a") > s("A B C"));
+        assert!(s("A.b") > s(".b"));
+        assert_eq!(s("*"), Specificity::default());
+    }
+
+    #[test]
+    fn compound_matching() {
+        let classes = [Str::from_static("panel"), Str::from_static("wide")];
+        let el = PathElement::new("Box")
+            .with_id("nav")
+            .with_classes(&classes);
+        for yes in ["Box", "*", "#nav", ".panel", ".wide.panel", "Box#nav.panel"] {
+            assert!(sel(yes).matches(&[el]), "{yes}");
+        }
+        for no in ["Text", "#other", ".narrow", ".panel.narrow", "Text#nav"] {
+            assert!(!sel(no).matches(&[el]), "{no}");
+        }
+        assert!(!sel("Box").matches(&[]));
+    }
+
+    #[test]
+    fn combinator_matching() {
+        let side = [Str::from_static("sidebar")];
+        let path = [
+            PathElement::new("App"),
+            PathElement::new("Box").with_classes(&side),
+            PathElement::new("List"),
+            PathElement::new("Text"),
+        ];
+        assert!(sel("App Text").matches(&path));
+        assert!(sel(".sidebar Text").matches(&path));
+        assert!(sel("List > Text").matches(&path));
+        assert!(sel("App .sidebar List > Text").matches(&path));
+        assert!(!sel(".sidebar > Text").matches(&path));
+        assert!(!sel("Text App").matches(&path));
+        // Only the last element is the subject.
+        assert!(!sel("List").matches(&path));
+    }
+
+    #[test]
+    fn descendant_backtracks() {
+        // `A > B C` must find the B whose parent is an A, not the nearest B.
+        let path = [
+            PathElement::new("A"),
+            PathElement::new("B"),
+            PathElement::new("X"),
+            PathElement::new("B"),
+            PathElement::new("C"),
+        ];
+        assert!(sel("A > B C").matches(&path));
+        assert!(!sel("X > B > B C").matches(&path));
+    }
+}
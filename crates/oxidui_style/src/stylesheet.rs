@@ -0,0 +1,463 @@
+//! Runtime stylesheets — `.oxss` files.
+//!
+//! A CSS subset: a list of rules, each a selector followed by a block of
+//! `property: value;` declarations. Values go through
+//! [`crate::declaration`], the grammar `scss!` uses at compile time, so a
+//! declaration means the same thing in both places.
+//!
+//! ```text
+//! /* Comments are ignored. */
+//! Box.sidebar, #nav {
+//!     width: 30;
+//!     border: rounded cyan;
+//! }
+//! ```
+//!
+//! A selector list (`a, b { … }`) produces one [`Rule`] per selector, all
+//! sharing the same declarations.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use oxidui_style::{stylesheet::Stylesheet, color::{Color, NamedColor}};
+//! let sheet = Stylesheet::parse("Text { color: red; }").unwrap();
+//! assert_eq!(sheet.rules[0].selector, "Text");
+//! assert_eq!(sheet.rules[0].style.color, Some(Color::Named(NamedColor::Red)));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Style;
+use crate::declaration::{self, DeclarationError};
+use crate::selector::{Selector, SelectorError};
+
+/// File extension of runtime stylesheets.
+pub const EXTENSION: &str = "oxss";
+
+/// One selector and the declarations that apply to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    /// Which elements the rule applies to. Displays in normalized form,
+    /// e.g. `"Box.sidebar > Text"`.
+    pub selector: Selector,
+    /// The declared properties. Undeclared fields are `None`.
+    pub style: Style,
+    /// 1-based line of the selector, for diagnostics.
+    pub line: usize,
+}
+
+/// A parsed stylesheet — rules in source order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Stylesheet {
+    pub rules: Vec<Rule>,
+}
+
+impl Stylesheet {
+    /// Parse stylesheet source text.
+    pub fn parse(src: &str) -> Result<Self, ParseError> {
+        Parser::new(src)?.stylesheet()
+    }
+}
+
+impl FromStr for Stylesheet {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Why a stylesheet was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// `/*` without a matching `*/`.
+    UnterminatedComment,
+    /// `"` without a matching `"`.
+    UnterminatedString,
+    /// A `{` with nothing before it.
+    ExpectedSelector,
+    /// The selector itself is invalid.
+    Selector(SelectorError),
+    /// A selector not followed by `{`.
+    ExpectedBlock,
+    /// A `{` without a matching `}`.
+    UnclosedBlock,
+    /// A character that cannot appear here (`}` outside a block, `{` inside one).
+    Unexpected(char),
+    /// A declaration without `:`.
+    ExpectedColon,
+    /// The declaration itself is invalid.
+    Declaration(DeclarationError),
+}
+
+/// A stylesheet error with its 1-based source position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::UnterminatedComment => f.write_str("unterminated comment"),
+            ParseErrorKind::UnterminatedString => f.write_str("unterminated string"),
+            ParseErrorKind::ExpectedSelector => f.write_str("expected a selector before `{`"),
+            ParseErrorKind::Selector(e) => e.fmt(f),
+            ParseErrorKind::ExpectedBlock => f.write_str("expected `{` after selector"),
+            ParseErrorKind::UnclosedBlock => f.write_str("unclosed block: expected `}`"),
+            ParseErrorKind::Unexpected(c) => write!(f, "unexpected `{c}`"),
+            ParseErrorKind::ExpectedColon => f.write_str("expected `property: value`"),
+            ParseErrorKind::Declaration(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+struct Parser<'a> {
+    /// Original text — used for line/column reporting.
+    src: &'a str,
+    /// `src` with comments blanked out; byte offsets are identical.
+    text: String,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Result<Self, ParseError> {
+        let mut p = Self {
+            src,
+            text: String::new(),
+            pos: 0,
+        };
+        p.text = p.strip_comments()?;
+        Ok(p)
+    }
+
+    /// 1-based line and column (in characters) of a byte offset.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let before = &self.src[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
+    fn error(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+        let (line, column) = self.position(offset);
+        ParseError { line, column, kind }
+    }
+
+    /// Replace `/* */` comments with spaces (keeping newlines) so offsets
+    /// into the result still index `src`. As in CSS, `//` starts no
+    /// comment, and nothing inside a string does.
+    fn strip_comments(&self) -> Result<String, ParseError> {
+        let bytes = self.src.as_bytes();
+        let mut out = self.src.as_bytes().to_vec();
+        let mut i = 0;
+        while i < bytes.len() {
+            match (bytes[i], bytes.get(i + 1)) {
+                (b'"', _) => i = self.string_end(i)?,
+                (b'/', Some(b'*')) => {
+                    let Some(len) = self.src[i + 2..].find("*/") else {
+                        return Err(self.error(i, ParseErrorKind::UnterminatedComment));
+                    };
+                    let end = i + 2 + len + 2;
+                    blank(&mut out[i..end]);
+                    i = end;
+                }
+                _ => i += 1,
+            }
+        }
+        // Only whole multi-byte characters were replaced, so this is valid UTF-8.
+        Ok(String::from_utf8(out).unwrap_or_default())
+    }
+
+    /// Offset just past the string literal starting at `start`.
+    fn string_end(&self, start: usize) -> Result<usize, ParseError> {
+        let bytes = self.src.as_bytes();
+        let mut i = start + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return Ok(i + 1),
+                _ => i += 1,
+            }
+        }
+        Err(self.error(start, ParseErrorKind::UnterminatedString))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Offset of the next top-level byte in `stops`, skipping strings.
+    fn find(&self, from: usize, stops: &[u8]) -> Result<Option<usize>, ParseError> {
+        let bytes = self.text.as_bytes();
+        let mut i = from;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'"' => i = self.string_end(i)?,
+                b if stops.contains(&b) => return Ok(Some(i)),
+                _ => i += 1,
+            }
+        }
+        Ok(None)
+    }
+
+    fn stylesheet(mut self) -> Result<Stylesheet, ParseError> {
+        let mut sheet = Stylesheet::default();
+        loop {
+            self.skip_whitespace();
+            if self.pos >= self.text.len() {
+                return Ok(sheet);
+            }
+            self.rule(&mut sheet)?;
+        }
+    }
+
+    fn rule(&mut self, sheet: &mut Stylesheet) -> Result<(), ParseError> {
+        let start = self.pos;
+        let open = match self.find(start, b"{};")? {
+            Some(i) if self.text.as_bytes()[i] == b'{' => i,
+            Some(i) => {
+                let c = char::from(self.text.as_bytes()[i]);
+                return Err(self.error(i, ParseErrorKind::Unexpected(c)));
+            }
+            None => return Err(self.error(start, ParseErrorKind::ExpectedBlock)),
+        };
+
+        let mut selectors = Vec::new();
+        let mut from = start;
+        for text in self.text[start..open].split(',') {
+            if text.trim().is_empty() {
+                return Err(self.error(start, ParseErrorKind::ExpectedSelector));
+            }
+            let selector = Selector::parse(text)
+                .map_err(|e| self.error(from + e.offset, ParseErrorKind::Selector(e)))?;
+            selectors.push(selector);
+            from += text.len() + 1;
+        }
+
+        let close = match self.find(open + 1, b"{}")? {
+            Some(i) if self.text.as_bytes()[i] == b'}' => i,
+            Some(i) => return Err(self.error(i, ParseErrorKind::Unexpected('{'))),
+            None => return Err(self.error(open, ParseErrorKind::UnclosedBlock)),
+        };
+
+        let style = self.block(open + 1, close)?;
+        let (line, _) = self.position(start);
+        sheet
+            .rules
+            .extend(selectors.into_iter().map(|selector| Rule {
+                selector,
+                style: style.clone(),
+                line,
+            }));
+        self.pos = close + 1;
+        Ok(())
+    }
+
+    /// Parse the declarations between `start` and `end` (exclusive).
+    fn block(&self, start: usize, end: usize) -> Result<Style, ParseError> {
+        let mut style = Style::new();
+        let mut from = start;
+        while from < end {
+            let semi = self.find(from, b";")?.filter(|&i| i < end).unwrap_or(end);
+            self.declaration(&mut style, from, semi)?;
+            from = semi + 1;
+        }
+        Ok(style)
+    }
+
+    fn declaration(&self, style: &mut Style, start: usize, end: usize) -> Result<(), ParseError> {
+        let decl = &self.text[start..end];
+        let Some(lead) = decl.find(|c: char| !c.is_whitespace()) else {
+            return Ok(());
+        };
+        let Some(colon) = decl.find(':') else {
+            return Err(self.error(start + lead, ParseErrorKind::ExpectedColon));
+        };
+
+        let property = decl[..colon].trim();
+        let raw = &decl[colon + 1..];
+        let value = raw.trim();
+        let value_start = start + colon + 1 + (raw.len() - raw.trim_start().len());
+
+        declaration::apply(style, property, value).map_err(|e| {
+            let at = match e.kind {
+                declaration::DeclarationErrorKind::UnknownProperty(_) => start + lead,
+                declaration::DeclarationErrorKind::MissingValue => start + colon,
+                _ => value_start + e.range.start,
+            };
+            self.error(at, ParseErrorKind::Declaration(e))
+        })
+    }
+}
+
+/// Overwrite a byte range with spaces, keeping newlines.
+fn blank(bytes: &mut [u8]) {
+    for b in bytes {
+        if *b != b'\n' {
+            *b = b' ';
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::border::{Border, Edges};
+    use crate::color::{Color, NamedColor};
+    use crate::declaration::DeclarationErrorKind;
+    use crate::layout::TextOverflow;
+    use crate::unit::Unit;
+
+    fn kind(src: &str) -> Option<(usize, usize, ParseErrorKind)> {
+        Stylesheet::parse(src)
+            .err()
+            .map(|e| (e.line, e.column, e.kind))
+    }
+
+    #[test]
+    fn parses_rules_in_order() {
+        let sheet = Stylesheet::parse(
+            "Box { width: 10; }\n\
+             .sidebar   >  Text { color: cyan; border: rounded }",
+        )
+        .unwrap();
+        assert_eq!(sheet.rules.len(), 2);
+        assert_eq!(sheet.rules[0].selector, "Box");
+        assert_eq!(sheet.rules[0].style.width, Some(Unit::cells(10)));
+        assert_eq!(sheet.rules[1].selector, ".sidebar > Text");
+        assert_eq!(sheet.rules[1].line, 2);
+        assert_eq!(
+            sheet.rules[1].style.color,
+            Some(Color::Named(NamedColor::Cyan))
+        );
+        assert_eq!(
+            sheet.rules[1].style.border,
+            Some(Edges::all(Some(Border::ROUNDED)))
+        );
+    }
+
+    #[test]
+    fn selector_lists_share_declarations() {
+        let sheet = Stylesheet::parse("A, #b { gap: 1 }").unwrap();
+        let selectors: Vec<_> = sheet.rules.iter().map(|r| r.selector.to_string()).collect();
+        assert_eq!(selectors, ["A", "#b"]);
+        assert_eq!(sheet.rules[0].style, sheet.rules[1].style);
+    }
+
+    #[test]
+    fn comments_are_ignored() {
+        let src = "/* header\n   ünïcode */ A {\n color: red; /* x */ }";
+        let sheet = Stylesheet::parse(src).unwrap();
+        assert_eq!(sheet.rules.len(), 1);
+        assert_eq!(sheet.rules[0].line, 2);
+
+        // `//` is not CSS: it reaches the value, quoted or not.
+        let sheet = Stylesheet::parse(r#"A { text-overflow: "/*//*/"; }"#).unwrap();
+        assert_eq!(
+            sheet.rules[0].style.text_overflow,
+            Some(TextOverflow::Marker("/*//*/".into()))
+        );
+        assert!(Stylesheet::parse("A { gap: 1 // 2; }").is_err());
+    }
+
+    #[test]
+    fn empty_and_whitespace_only() {
+        assert_eq!(Stylesheet::parse(""), Ok(Stylesheet::default()));
+        assert_eq!(Stylesheet::parse("  \n /* */ "), Ok(Stylesheet::default()));
+        assert_eq!(
+            Stylesheet::parse("A {}").map(|s| s.rules[0].style.clone()),
+            Ok(Style::new())
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(
+            kind("A {\n  padding: 1 x;\n}"),
+            Some((
+                2,
+                14,
+                ParseErrorKind::Declaration(DeclarationError {
+                    kind: DeclarationErrorKind::InvalidValue {
+                        found: "x".into(),
+                        expected: "a size (`10`, `50%`, `1fr` or `auto`)",
+                    },
+                    range: 2..3,
+                })
+            ))
+        );
+        let unknown = kind("A {\n    colr: red;\n}");
+        assert_eq!(unknown.map(|(l, c, _)| (l, c)), Some((2, 5)));
+    }
+
+    #[test]
+    fn structural_errors() {
+        assert_eq!(
+            kind("A { color: red;"),
+            Some((1, 3, ParseErrorKind::UnclosedBlock))
+        );
+        assert_eq!(
+            kind("A color: red;"),
+            Some((1, 13, ParseErrorKind::Unexpected(';')))
+        );
+        assert_eq!(kind("A"), Some((1, 1, ParseErrorKind::ExpectedBlock)));
+        assert_eq!(
+            kind("{ color: red }"),
+            Some((1, 1, ParseErrorKind::ExpectedSelector))
+        );
+        assert_eq!(
+            kind("A, { color: red }"),
+            Some((1, 1, ParseErrorKind::ExpectedSelector))
+        );
+        assert_eq!(kind("}"), Some((1, 1, ParseErrorKind::Unexpected('}'))));
+        assert_eq!(
+            kind("A { B { } }"),
+            Some((1, 7, ParseErrorKind::Unexpected('{')))
+        );
+        assert_eq!(
+            kind("A { color red }"),
+            Some((1, 5, ParseErrorKind::ExpectedColon))
+        );
+        assert_eq!(
+            kind("/* x"),
+            Some((1, 1, ParseErrorKind::UnterminatedComment))
+        );
+        assert_eq!(
+            kind("A { x: \"a }"),
+            Some((1, 8, ParseErrorKind::UnterminatedString))
+        );
+    }
+
+    #[test]
+    fn selector_errors_point_into_the_selector() {
+        let err = Stylesheet::parse("A,\n  B:hover { }").err();
+        assert_eq!(
+            err.map(|e| e.to_string()),
+            Some("line 2, column 4: unexpected `:` in selector".to_owned())
+        );
+    }
+
+    #[test]
+    fn display_includes_position() {
+        let err = Stylesheet::parse("A {\n  colr: red;\n}").err();
+        assert_eq!(
+            err.map(|e| e.to_string()),
+            Some("line 2, column 3: unknown property `colr`".to_owned())
+        );
+    }
+}
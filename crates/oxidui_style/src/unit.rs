@@ -27,12 +27,13 @@
 /// # Examples
 ///
 /// ```rust
+/// # use oxidui_style::unit::Unit;
 /// let w    = Unit::cells(40);    // exactly 40 columns
 /// let h    = Unit::percent(50);  // 50% of parent height
 /// let flex = Unit::fill(1);      // take 1 share of remaining space
 /// let auto = Unit::AUTO;         // size to content
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Unit {
     /// Absolute size in terminal character cells.
     ///
@@ -64,6 +65,7 @@ pub enum Unit {
     /// never emit `Unset` directly; the parser produces `None` at the
     /// `Style` field level instead. Exists for `Edges<Unit>` where a
     /// `Unit` must be present but is logically absent.
+    #[default]
     Unset,
 }
 
@@ -129,8 +131,276 @@ impl Unit {
     }
 }
 
-impl Default for Unit {
-    fn default() -> Self {
-        Self::Unset
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+impl Unit {
+    /// Cell count of a definite unit, given the parent's inner size.
+    ///
+    /// `Percent` rounds half up; `Fill`, `Auto` and `Unset` need more than
+    /// the parent size and return `None`. Negative `Cells` pass through —
+    /// they are meaningful for margins.
+    ///
+    /// ```rust
+    /// # use oxidui_style::unit::Unit;
+    /// assert_eq!(Unit::cells(-1).resolve(80), Some(-1));
+    /// assert_eq!(Unit::percent(25).resolve(10), Some(3)); // 2.5 → 3
+    /// assert_eq!(Unit::AUTO.resolve(80), None);
+    /// ```
+    pub const fn resolve(self, parent: i32) -> Option<i32> {
+        match self {
+            Self::Cells(n) => Some(n),
+            Self::Percent(p) => Some(percent_of(parent, p)),
+            Self::Fill(_) | Self::Auto | Self::Unset => None,
+        }
+    }
+
+    /// Size of a box along one axis: definite units resolve against
+    /// `parent`, `Auto` and `Unset` take the intrinsic `content` size.
+    ///
+    /// `Fill` returns `None` — its size depends on its siblings; see
+    /// [`distribute`]. `Fill(0)` counts as `Auto`.
+    pub const fn resolve_size(self, parent: i32, content: i32) -> Option<i32> {
+        match self {
+            Self::Fill(w) if w > 0 => None,
+            Self::Fill(_) | Self::Auto | Self::Unset => Some(content),
+            _ => self.resolve(parent),
+        }
+    }
+
+    /// The weight of a `Fill` unit, or `None` if it isn't one. `Fill(0)`
+    /// counts as `Auto`.
+    pub const fn fill_weight(self) -> Option<u16> {
+        match self {
+            Self::Fill(w) if w > 0 => Some(w),
+            _ => None,
+        }
+    }
+}
+
+/// `parent * p / 100`, rounded half up.
+const fn percent_of(parent: i32, p: u8) -> i32 {
+    let scaled = parent as i64 * p as i64;
+    (scaled * 2 + 100).div_euclid(200) as i32
+}
+
+/// Apply `min-*` / `max-*` constraints to a resolved size.
+///
+/// Bounds resolve against `parent`; `Auto`, `Fill` and `Unset` bounds are
+/// ignored. When they conflict `min` wins, as in CSS. The result is never
+/// negative.
+///
+/// ```rust
+/// # use oxidui_style::unit::{Unit, clamp_size};
+/// assert_eq!(clamp_size(50, Unit::ZERO, Unit::percent(50), 80), 40);
+/// assert_eq!(clamp_size(5, Unit::cells(10), Unit::cells(8), 80), 10);
+/// ```
+pub fn clamp_size(size: i32, min: Unit, max: Unit, parent: i32) -> i32 {
+    let size = max.resolve(parent).map_or(size, |max| size.min(max));
+    min.resolve(parent).map_or(size, |min| size.max(min)).max(0)
+}
+
+/// One box's sizing inputs along an axis, for [`distribute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Track {
+    /// Declared size (`width` / `height`).
+    pub size: Unit,
+    /// `min-width` / `min-height`.
+    pub min: Unit,
+    /// `max-width` / `max-height`.
+    pub max: Unit,
+    /// Intrinsic content size, used for `Auto`.
+    pub content: i32,
+}
+
+impl Track {
+    pub const fn new(size: Unit) -> Self {
+        Self {
+            size,
+            min: Unit::Unset,
+            max: Unit::Unset,
+            content: 0,
+        }
+    }
+    pub const fn with_min(mut self, min: Unit) -> Self {
+        self.min = min;
+        self
+    }
+    pub const fn with_max(mut self, max: Unit) -> Self {
+        self.max = max;
+        self
+    }
+    pub const fn with_content(mut self, content: i32) -> Self {
+        self.content = content;
+        self
+    }
+}
+
+/// Resolve sibling sizes sharing `available` cells along one axis.
+///
+/// 1. `Cells`, `Percent` and `Auto` tracks resolve on their own
+///    ([`Unit::resolve_size`]) and are clamped ([`clamp_size`]).
+/// 2. Whatever is left is split between `Fill` tracks in proportion to
+///    their weights. Tracks pushed past their min/max are frozen there and
+///    the rest is re-split among the others — as in CSS flex, only the
+///    min violations if clamping took room, only the max ones if it gave
+///    some back.
+/// 3. Fractional shares are rounded cumulatively, so `Fill` tracks add up
+///    to exactly the space they were given.
+///
+/// ```rust
+/// # use oxidui_style::unit::{Unit, Track, distribute};
+/// let tracks = [
+///     Track::new(Unit::cells(10)),
+///     Track::new(Unit::fill(1)),
+///     Track::new(Unit::fill(2)),
+/// ];
+/// assert_eq!(distribute(&tracks, 40), [10, 10, 20]);
+/// ```
+pub fn distribute(tracks: &[Track], available: i32) -> Vec<i32> {
+    let mut sizes = vec![0; tracks.len()];
+    let mut fills = Vec::new();
+    for (i, t) in tracks.iter().enumerate() {
+        match t.size.resolve_size(available, t.content) {
+            Some(v) => sizes[i] = clamp_size(v, t.min, t.max, available),
+            None => fills.push(i),
+        }
+    }
+
+    let fixed: i32 = sizes.iter().sum();
+    let weight = |i: usize| f64::from(tracks[i].size.fill_weight().unwrap_or(0));
+    // Share per unfrozen fill track; frozen ones are settled in `sizes`.
+    let mut frozen = vec![false; tracks.len()];
+    let mut shares = vec![0.0; tracks.len()];
+    loop {
+        let open: Vec<usize> = fills.iter().copied().filter(|&i| !frozen[i]).collect();
+        let total: f64 = open.iter().map(|&i| weight(i)).sum();
+        if total == 0.0 {
+            break;
+        }
+        let settled: i32 = fills
+            .iter()
+            .filter(|&&i| frozen[i])
+            .map(|&i| sizes[i])
+            .sum();
+        let remaining = f64::from((available - fixed - settled).max(0));
+        // Every share of the pass first, then the clamps.
+        let mut clamped = Vec::new();
+        let mut violation = 0.0;
+        for &i in &open {
+            let share = remaining * weight(i) / total;
+            let t = &tracks[i];
+            let lo = t.min.resolve(available).map_or(0.0, f64::from);
+            let hi = t.max.resolve(available).map_or(f64::INFINITY, f64::from);
+            let bounded = share.min(hi).max(lo);
+            shares[i] = share;
+            if bounded != share {
+                violation += bounded - share;
+                clamped.push((i, bounded));
+            }
+        }
+        if clamped.is_empty() {
+            break;
+        }
+        for (i, bounded) in clamped {
+            if violation == 0.0 || (violation > 0.0) == (bounded > shares[i]) {
+                sizes[i] = bounded as i32;
+                frozen[i] = true;
+            }
+        }
+    }
+
+    // Cumulative rounding over the unfrozen fills keeps their sum exact.
+    let mut acc = 0.0;
+    let mut prev = 0;
+    for &i in fills.iter().filter(|&&i| !frozen[i]) {
+        acc += shares[i];
+        let edge = (acc + 0.5).floor() as i32;
+        sizes[i] = edge - prev;
+        prev = edge;
+    }
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_definite_units() {
+        assert_eq!(Unit::cells(7).resolve(100), Some(7));
+        assert_eq!(Unit::cells(-2).resolve(100), Some(-2));
+        assert_eq!(Unit::percent(50).resolve(81), Some(41));
+        assert_eq!(Unit::percent(100).resolve(0), Some(0));
+        assert_eq!(Unit::percent(150).resolve(10), Some(15));
+        assert_eq!(Unit::fill(1).resolve(100), None);
+        assert_eq!(Unit::UNSET.resolve(100), None);
+    }
+
+    #[test]
+    fn resolve_size_uses_content_for_auto() {
+        assert_eq!(Unit::AUTO.resolve_size(80, 12), Some(12));
+        assert_eq!(Unit::UNSET.resolve_size(80, 12), Some(12));
+        assert_eq!(Unit::fill(0).resolve_size(80, 12), Some(12));
+        assert_eq!(Unit::fill(2).resolve_size(80, 12), None);
+        assert_eq!(Unit::HALF.resolve_size(80, 12), Some(40));
+    }
+
+    #[test]
+    fn clamp_size_min_wins() {
+        assert_eq!(clamp_size(30, Unit::UNSET, Unit::cells(20), 0), 20);
+        assert_eq!(clamp_size(3, Unit::percent(10), Unit::AUTO, 50), 5);
+        assert_eq!(clamp_size(30, Unit::cells(25), Unit::cells(20), 0), 25);
+        assert_eq!(clamp_size(-4, Unit::UNSET, Unit::UNSET, 0), 0);
+    }
+
+    #[test]
+    fn distribute_fill_by_weight() {
+        let fill = |w| Track::new(Unit::fill(w));
+        assert_eq!(distribute(&[fill(1), fill(1), fill(1)], 10), [3, 4, 3]);
+        assert_eq!(distribute(&[fill(1), fill(3)], 10), [3, 7]);
+        // Fixed, percent and auto tracks come out first.
+        let tracks = [
+            Track::new(Unit::cells(4)),
+            Track::new(Unit::percent(10)),
+            Track::new(Unit::AUTO).with_content(6),
+            fill(1),
+        ];
+        assert_eq!(distribute(&tracks, 40), [4, 4, 6, 26]);
+        // Overflowing fixed tracks leave fills at zero.
+        assert_eq!(
+            distribute(&[Track::new(Unit::cells(50)), fill(1)], 40),
+            [50, 0]
+        );
+    }
+
+    #[test]
+    fn distribute_sums_exactly() {
+        let fill = |w| Track::new(Unit::fill(w));
+        for available in 0..50 {
+            let sizes = distribute(&[fill(1), fill(2), fill(3), fill(1)], available);
+            assert_eq!(sizes.iter().sum::<i32>(), available);
+        }
+    }
+
+    #[test]
+    fn distribute_respects_fill_bounds() {
+        let tracks = [
+            Track::new(Unit::fill(1)).with_max(Unit::cells(5)),
+            Track::new(Unit::fill(1)),
+            Track::new(Unit::fill(1)).with_min(Unit::cells(20)),
+        ];
+        // 30 / 3 = 10 each: the first caps at 5, the third floors at 20,
+        // and the middle gets what is left.
+        assert_eq!(distribute(&tracks, 30), [5, 5, 20]);
+
+        // Only the cap is frozen; the other two share what it gives back.
+        let tracks = [
+            Track::new(Unit::fill(1)).with_max(Unit::cells(5)),
+            Track::new(Unit::fill(1)).with_min(Unit::cells(9)),
+            Track::new(Unit::fill(1)),
+        ];
+        assert_eq!(distribute(&tracks, 30), [5, 13, 12]);
     }
 }
@@ -0,0 +1,339 @@
+//! The application entry point — terminal setup, the event loop and
+//! teardown.
+//!
+//! [`App`] puts the terminal into raw mode on the alternate screen, with
+//! mouse reporting on, and restores it when dropped, including when the
+//! program panics. Its [`App::run`] loop redraws the view after every
+//! event and whenever a watched stylesheet reloads; while one fails to,
+//! the error is shown on the bottom row. Scroll containers
+//! follow the mouse wheel and PageUp/PageDown on their own; see
+//! [`App::scroll_state`] to scroll them from code.
+//!
+//! ```rust,no_run
+//! use std::cell::Cell;
+//!
+//! use oxidui::app::{App, Event, Flow, KeyCode};
+//! use oxidui::rsx;
+//!
+//! fn main() -> std::io::Result<()> {
+//!     let count = Cell::new(0);
+//!     App::new()?.run(
+//!         || rsx! { <Text>{format!("pressed {} keys", count.get())}</Text> },
+//!         |event| match event {
+//!             Event::Key(key) if key.code == KeyCode::Char('q') => Flow::Quit,
+//!             Event::Key(_) => {
+//!                 count.set(count.get() + 1);
+//!                 Flow::Continue
+//!             }
+//!             _ => Flow::Continue,
+//!         },
+//!     )
+//! }
+//! ```
+//!
+//! `Ctrl+C` always quits, whatever the handler returns.
+
+use std::io::{self, Stdout};
+use std::sync::{Arc, Once};
+use std::time::Duration;
+
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{
+    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+};
+use oxidui_style::border::Charset;
+use oxidui_style::cascade::{Cascade, Origin};
+use oxidui_style::color::ColorDepth;
+use oxidui_style::stylesheet::Stylesheet;
+use ratatui::Terminal;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+
+use crate::backend::SgrBackend;
+use crate::element::Element;
+use crate::render::render;
+use crate::scroll::ScrollState;
+use crate::watcher::StylesheetWatcher;
+
+pub use crossterm::event::{Event, KeyCode, KeyEvent, MouseEvent};
+
+/// How long [`App::run`] waits for input before checking for stylesheet
+/// reloads.
+const TICK: Duration = Duration::from_millis(50);
+
+/// What the event loop does after an event has been handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Flow {
+    /// Redraw and keep going.
+    #[default]
+    Continue,
+    /// Leave [`App::run`].
+    Quit,
+}
+
+/// A terminal application: owns the terminal and the stylesheets.
+pub struct App {
+    terminal: Terminal<SgrBackend<Stdout>>,
+    theme: Arc<Stylesheet>,
+    watcher: Option<StylesheetWatcher>,
+    components: Vec<Stylesheet>,
+    depth: ColorDepth,
+    charset: Charset,
+    scroll: ScrollState,
+}
+
+impl App {
+    /// Take over the terminal: raw mode, alternate screen, mouse capture,
+    /// hidden cursor.
+    /// The color depth and charset are detected from the environment.
+    ///
+    /// Everything is restored when the `App` is dropped or the program
+    /// panics.
+    pub fn new() -> io::Result<Self> {
+        install_panic_hook();
+        enable_raw_mode()?;
+        if let Err(err) =
+            crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
+        {
+            restore();
+            return Err(err);
+        }
+        let terminal = match Terminal::new(SgrBackend::new(io::stdout())) {
+            Ok(terminal) => terminal,
+            Err(err) => {
+                restore();
+                return Err(err);
+            }
+        };
+        let mut app = Self {
+            terminal,
+            theme: Arc::default(),
+            watcher: None,
+            components: Vec::new(),
+            depth: ColorDepth::detect(),
+            charset: Charset::detect(),
+            scroll: ScrollState::new(),
+        };
+        app.terminal.hide_cursor()?;
+        Ok(app)
+    }
+
+    /// Use `sheet` as the theme — the lowest-priority rules.
+    pub fn with_theme(mut self, sheet: Stylesheet) -> Self {
+        self.theme = Arc::new(sheet);
+        self.watcher = None;
+        self
+    }
+
+    /// Take the theme from `watcher`, redrawing whenever it reloads.
+    pub fn with_watcher(mut self, watcher: StylesheetWatcher) -> Self {
+        self.watcher = Some(watcher);
+        self
+    }
+
+    /// Add component rules, which override the theme at any specificity.
+    pub fn with_component_styles(mut self, sheet: Stylesheet) -> Self {
+        self.components.push(sheet);
+        self
+    }
+
+    /// Override the detected color depth.
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Override the detected charset.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// The scroll positions of the app's scroll containers. The handle
+    /// shares them with the app, so it can be moved into the `update`
+    /// closure of [`App::run`]:
+    ///
+    /// ```rust,no_run
+    /// # use oxidui::app::{App, Event, Flow, KeyCode};
+    /// # use oxidui::rsx;
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut app = App::new()?;
+    /// let scroll = app.scroll_state();
+    /// app.run(
+    ///     || rsx! { <Log id="log">"…"</Log> },
+    ///     move |event| {
+    ///         if let Event::Key(key) = event
+    ///             && key.code == KeyCode::End
+    ///         {
+    ///             scroll.scroll_to("log", i32::MAX);
+    ///         }
+    ///         Flow::Continue
+    ///     },
+    /// )
+    /// # }
+    /// ```
+    pub fn scroll_state(&self) -> ScrollState {
+        self.scroll.clone()
+    }
+
+    /// Run until `update` returns [`Flow::Quit`] or the user presses
+    /// `Ctrl+C`.
+    ///
+    /// `view` builds the element tree; it is called before each redraw.
+    /// `update` sees every terminal event, resizes included — also those
+    /// that already scrolled a scroll container.
+    pub fn run<V, U>(&mut self, mut view: V, mut update: U) -> io::Result<()>
+    where
+        V: FnMut() -> Element,
+        U: FnMut(&Event) -> Flow,
+    {
+        let mut dirty = true;
+        loop {
+            if let Some(watcher) = &mut self.watcher {
+                let shown = watcher.last_error().map(ToString::to_string);
+                dirty |= watcher.poll();
+                dirty |= watcher.last_error().map(ToString::to_string) != shown;
+            }
+            if dirty {
+                self.draw(&view())?;
+                dirty = false;
+            }
+
+            if !crossterm::event::poll(TICK)? {
+                continue;
+            }
+            let event = crossterm::event::read()?;
+            if is_interrupt(&event) {
+                return Ok(());
+            }
+            self.scroll.handle(&event);
+            if update(&event) == Flow::Quit {
+                return Ok(());
+            }
+            dirty = true;
+        }
+    }
+
+    /// Render `root` once. While a watched stylesheet fails to reload,
+    /// the error covers the bottom row.
+    pub fn draw(&mut self, root: &Element) -> io::Result<()> {
+        let theme = match &self.watcher {
+            Some(watcher) => watcher.current(),
+            None => &self.theme,
+        };
+        let error = self
+            .watcher
+            .as_ref()
+            .and_then(StylesheetWatcher::last_error);
+        let mut cascade = Cascade::new().with_stylesheet(Origin::Theme, theme);
+        for sheet in &self.components {
+            cascade.push(Origin::Component, sheet);
+        }
+        self.terminal
+            .draw(|frame| {
+                render(
+                    frame,
+                    root,
+                    &cascade,
+                    self.depth,
+                    self.charset,
+                    &self.scroll,
+                );
+                if let Some(error) = error {
+                    let area = frame.size();
+                    status_line(frame.buffer_mut(), area, &error.to_string());
+                }
+            })
+            .map(drop)
+    }
+}
+
+impl Drop for App {
+    fn drop(&mut self) {
+        let _ = self.terminal.show_cursor();
+        restore();
+    }
+}
+
+/// Leave the alternate screen, mouse capture and raw mode. Errors are ignored: this runs
+/// during teardown, where there is nothing left to report them to.
+fn restore() {
+    let _ = crossterm::execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        crossterm::cursor::Show
+    );
+    let _ = disable_raw_mode();
+}
+
+/// Restore the terminal before the default hook prints the panic, so the
+/// message lands on the normal screen and stays readable.
+fn install_panic_hook() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore();
+            previous(info);
+        }));
+    });
+}
+
+/// Write `message` on the bottom row of `area`, white on red, cut to fit.
+fn status_line(buf: &mut Buffer, area: Rect, message: &str) {
+    if area.is_empty() {
+        return;
+    }
+    let row = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+    let style = Style::new().fg(Color::White).bg(Color::Red);
+    for x in row.left()..row.right() {
+        buf.get_mut(x, row.y).reset();
+    }
+    buf.set_style(row, style);
+    let message = message.replace('\n', " ");
+    buf.set_stringn(row.x, row.y, message, usize::from(row.width), style);
+}
+
+fn is_interrupt(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) if modifiers.contains(KeyModifiers::CONTROL)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reload_errors_cover_the_bottom_row() {
+        let area = Rect::new(0, 0, 8, 2);
+        let mut buf = Buffer::with_lines(vec!["view    ", "view    "]);
+        status_line(&mut buf, area, "a.oxss:1:2\nbad");
+        assert_eq!(buf.get(0, 0).symbol(), "v");
+        let row: String = (0..8).map(|x| buf.get(x, 1).symbol()).collect();
+        assert_eq!(row, "a.oxss:1");
+        assert_eq!(buf.get(7, 1).bg, Color::Red);
+    }
+
+    #[test]
+    fn ctrl_c_interrupts() {
+        let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+        assert!(is_interrupt(&key(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!is_interrupt(&key(KeyCode::Char('c'), KeyModifiers::NONE)));
+        assert!(!is_interrupt(&key(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL
+        )));
+    }
+}
@@ -0,0 +1,161 @@
+//! A crossterm backend that also draws the text styles ratatui has no
+//! modifier for.
+//!
+//! [`FontStyle::to_ratatui`] keeps double and curly underlines and
+//! overlines in modifier bits ratatui leaves unused, where they survive
+//! the buffer diff. ratatui's own `CrosstermBackend` ignores those bits;
+//! [`SgrBackend`] writes the cells that carry them itself, with
+//! [`FontStyle::sgr`], and hands every other cell to it. Terminals that
+//! don't know the underline variants show a plain underline.
+//!
+//! [`App`](crate::app::App) draws through it.
+
+use std::io::{self, Write};
+
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Attribute, Colors, Print, SetAttribute, SetColors, SetUnderlineColor};
+use oxidui_style::font::FontStyle;
+use ratatui::backend::{Backend, ClearType, CrosstermBackend, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+
+/// A `CrosstermBackend` that writes every [`FontStyle`] flag.
+#[derive(Debug)]
+pub struct SgrBackend<W: Write> {
+    inner: CrosstermBackend<W>,
+}
+
+impl<W: Write> SgrBackend<W> {
+    /// Draw to `writer`, usually `io::stdout()`.
+    pub const fn new(writer: W) -> Self {
+        Self {
+            inner: CrosstermBackend::new(writer),
+        }
+    }
+
+    /// Write one cell whose modifier has spare bits set, leaving the
+    /// terminal's attributes reset after it.
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        let sgr = FontStyle::from_ratatui(cell.modifier).sgr().join(";");
+        queue!(
+            self.inner,
+            MoveTo(x, y),
+            SetColors(Colors::new(cell.fg.into(), cell.bg.into())),
+            SetUnderlineColor(cell.underline_color.into()),
+        )?;
+        write!(self.inner, "\x1b[{sgr}m")?;
+        queue!(
+            self.inner,
+            Print(cell.symbol()),
+            SetAttribute(Attribute::Reset)
+        )
+    }
+}
+
+impl<W: Write> Backend for SgrBackend<W> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut plain = Vec::new();
+        for (x, y, cell) in content {
+            if Modifier::all().contains(cell.modifier) {
+                plain.push((x, y, cell));
+            } else {
+                self.inner.draw(plain.drain(..))?;
+                self.draw_cell(x, y, cell)?;
+            }
+        }
+        self.inner.draw(plain.into_iter())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.inner.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.inner.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.inner.append_lines(n)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        self.inner.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Backend::flush(&mut self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// A writer the test can read back after the backend took it.
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn cell(symbol: &str, font: FontStyle) -> Cell {
+        let mut cell = Cell::default();
+        cell.set_symbol(symbol).modifier = font.to_ratatui();
+        cell
+    }
+
+    #[test]
+    fn spare_flags_are_written_as_sgr() {
+        let out = Output::default();
+        let mut backend = SgrBackend::new(out.clone());
+        let squiggle = cell("a", FontStyle::BOLD | FontStyle::CURLY_UNDERLINE);
+        let plain = cell("b", FontStyle::UNDERLINE);
+        let over = cell("c", FontStyle::OVERLINE);
+        let cells = [(0, 0, &squiggle), (1, 0, &plain), (2, 0, &over)];
+        backend.draw(cells.into_iter()).unwrap();
+
+        let written = String::from_utf8(out.0.take()).unwrap();
+        let a = written.find("\x1b[1;4:3ma").unwrap();
+        // The plain underline goes through crossterm, after a reset.
+        let b = written.find("\x1b[4mb").unwrap();
+        let c = written.find("\x1b[53mc").unwrap();
+        assert!(a < b && b < c, "{written:?}");
+        assert!(!written.contains("4:2"));
+    }
+}
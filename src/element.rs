@@ -0,0 +1,241 @@
+//! The runtime element tree.
+//!
+//! This is what [`crate::rsx!`] expands into: every `<Tag>` becomes an
+//! [`Element`], every string literal a [`Node::Text`]. Elements carry the
+//! identity used for selector matching (tag, id, classes) plus their
+//! inline [`Style`].
+//!
+//! ```rust
+//! use oxidui::element::{Element, Node};
+//!
+//! let ui = Element::new("Box")
+//!     .with_id("sidebar")
+//!     .with_class("panel focused")
+//!     .with_child(Element::new("Text").with_child("hello"));
+//!
+//! assert!(ui.has_class("focused"));
+//! assert_eq!(ui.children.len(), 1);
+//! ```
+
+use std::borrow::Cow;
+
+use oxidui_style::Style;
+use oxidui_style::selector::PathElement;
+use oxidui_style::str::Str;
+
+/// One node in the element tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// A styled container with children. Boxed — an [`Element`] is much
+    /// larger than a text run.
+    Element(Box<Element>),
+    /// A run of text. Inherits its parent's text styling.
+    Text(Str),
+}
+
+impl Node {
+    /// Borrow the element, if this node is one.
+    pub fn as_element(&self) -> Option<&Element> {
+        match self {
+            Self::Element(el) => Some(el),
+            Self::Text(_) => None,
+        }
+    }
+
+    /// Borrow the text, if this node is a text node.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(t) => Some(t.as_str()),
+            Self::Element(_) => None,
+        }
+    }
+}
+
+impl From<Element> for Node {
+    fn from(el: Element) -> Self {
+        Self::Element(Box::new(el))
+    }
+}
+impl From<Str> for Node {
+    fn from(s: Str) -> Self {
+        Self::Text(s)
+    }
+}
+impl From<&'static str> for Node {
+    fn from(s: &'static str) -> Self {
+        Self::Text(Str::from_static(s))
+    }
+}
+impl From<String> for Node {
+    fn from(s: String) -> Self {
+        Self::Text(Str::from_string(s))
+    }
+}
+
+/// A single element — the unit of styling and layout.
+///
+/// `tag`, `id` and `classes` identify the element to stylesheet selectors;
+/// `style` holds its inline declarations, which win over stylesheet rules.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Element {
+    /// Element type, e.g. `"Box"` or `"Text"`. Matched by type selectors.
+    pub tag: Str,
+    /// Optional unique id. Matched by `#id` selectors.
+    pub id: Option<Str>,
+    /// Class names. Matched by `.class` selectors.
+    pub classes: Vec<Str>,
+    /// Inline style declarations.
+    pub style: Style,
+    /// Child nodes in document order.
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    /// A childless, unstyled element of the given type.
+    pub fn new(tag: impl Into<Str>) -> Self {
+        Self {
+            tag: tag.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the element id.
+    pub fn with_id(mut self, id: impl Into<Str>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Add one or more whitespace-separated class names.
+    ///
+    /// Static strings are split without allocating.
+    pub fn with_class(mut self, classes: impl Into<Str>) -> Self {
+        match classes.into().0 {
+            Cow::Borrowed(s) => self
+                .classes
+                .extend(s.split_whitespace().map(Str::from_static)),
+            Cow::Owned(s) => self
+                .classes
+                .extend(s.split_whitespace().map(|c| Str::from_string(c.to_owned()))),
+        }
+        self
+    }
+
+    /// Merge `style` on top of the element's inline style.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style.merge(&style);
+        self
+    }
+
+    /// Append a child node.
+    pub fn with_child(mut self, child: impl Into<Node>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Append several child nodes.
+    pub fn with_children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Node>,
+    {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// `true` if `class` is one of the element's class names.
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c.as_str() == class)
+    }
+
+    /// The element's identity for selector matching. Push one per ancestor
+    /// to build the path [`oxidui_style::cascade::Cascade`] matches against.
+    pub fn path_element(&self) -> PathElement<'_> {
+        PathElement {
+            tag: self.tag.as_str(),
+            id: self.id.as_ref().map(Str::as_str),
+            classes: &self.classes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rsx;
+    use oxidui_style::color::{Color, NamedColor};
+
+    #[test]
+    fn rsx_builds_tree() {
+        let ui = rsx! {
+            <Box id="root" class="panel wide">
+                <Text>"hi"</Text>
+                <Spacer />
+            </Box>
+        };
+
+        assert_eq!(ui.tag.as_str(), "Box");
+        assert_eq!(ui.id, Some(Str::from_static("root")));
+        assert!(ui.has_class("panel"));
+        assert!(ui.has_class("wide"));
+        assert_eq!(ui.children.len(), 2);
+
+        let text = ui.children[0].as_element().map(|el| &el.children[0]);
+        assert_eq!(text.and_then(Node::as_text), Some("hi"));
+        assert_eq!(
+            ui.children[1].as_element().map(|el| el.tag.as_str()),
+            Some("Spacer")
+        );
+    }
+
+    #[test]
+    fn rsx_expression_attributes_and_children() {
+        let red = Style::new().with_color(Color::Named(NamedColor::Red));
+        let label = format!("{} items", 3);
+        let ui = rsx! {
+            <Box style={red.clone()}>
+                {label}
+                {Element::new("Footer")}
+            </Box>
+        };
+
+        assert_eq!(ui.style, red);
+        assert_eq!(ui.children[0].as_text(), Some("3 items"));
+        assert_eq!(
+            ui.children[1].as_element().map(|el| el.tag.as_str()),
+            Some("Footer")
+        );
+    }
+
+    #[test]
+    fn with_class_splits_owned_strings() {
+        let el = Element::new("Box").with_class(String::from(" a  b "));
+        assert_eq!(
+            el.classes,
+            vec![Str::from_static("a"), Str::from_static("b")]
+        );
+    }
+
+    #[test]
+    fn path_element_matches_selectors() {
+        use oxidui_style::selector::Selector;
+
+        let ui = rsx! {
+            <Box class="sidebar">
+                <Text id="title" class="bold" />
+            </Box>
+        };
+        let text = ui.children[0].as_element().unwrap();
+        let path = [ui.path_element(), text.path_element()];
+        let sel: Selector = ".sidebar > Text#title.bold".parse().unwrap();
+        assert!(sel.matches(&path));
+    }
+
+    #[test]
+    fn with_style_merges() {
+        let el = Element::new("Box")
+            .with_style(Style::new().with_color(Color::Named(NamedColor::Red)))
+            .with_style(Style::new().with_background(Color::Named(NamedColor::Blue)));
+        assert_eq!(el.style.color, Some(Color::Named(NamedColor::Red)));
+        assert_eq!(el.style.background, Some(Color::Named(NamedColor::Blue)));
+    }
+}
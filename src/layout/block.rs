@@ -0,0 +1,238 @@
+//! Block flow: children stacked top to bottom, one per line.
+//!
+//! Each in-flow child takes the container's content width less its
+//! margins, unless it declares a width; then `auto` left and right margins
+//! share the room that is left, which centers it. Its height is declared
+//! or fits its content, and text wraps to the width first. `Fill` heights
+//! share the room the other children leave, by weight. Nothing shrinks:
+//! children that don't fit overflow the content box.
+//!
+//! Margins are not collapsed. Percentages on every side refer to the
+//! container's width, as in CSS, and negative margins pull a child over
+//! its neighbours. Children with `display: none` take no room at all.
+
+use oxidui_style::layout::FlexDirection;
+use oxidui_style::unit::{Track, Unit, distribute};
+
+use super::{
+    Layout, LayoutNode, Rect, clamp, flex, interleave, layout_box, length, margins, measure, round,
+};
+
+/// One child's box along both axes, relative to the content box, in
+/// fractional cells.
+struct Item {
+    x: f64,
+    width: f64,
+    height: f64,
+    /// `true` if its height is a `Fill` share.
+    fill: bool,
+    /// Top and bottom margins.
+    margin: (f64, f64),
+}
+
+/// Lay out `node`'s children inside `content`.
+pub(super) fn layout_children(node: &LayoutNode, content: Rect) -> Vec<Layout> {
+    let shown: Vec<&LayoutNode> = node
+        .children
+        .iter()
+        .filter(|c| c.style.is_in_flow())
+        .collect();
+
+    let pulls = flex::collapse(node, &shown, FlexDirection::Column);
+    let mut items: Vec<Item> = shown
+        .iter()
+        .zip(pulls)
+        .map(|(child, pull)| {
+            let s = &child.style;
+            let m = margins(s, content.width).zip(pull, |m, pull| m + pull);
+            let (x, width) = horizontal(child, (m.left, m.right), content.width);
+            let fill = s.height.fill_weight().is_some();
+            let height = if fill {
+                0.0
+            } else {
+                length(s.height, content.height)
+                    .unwrap_or_else(|| f64::from(measure(child, Some(round(width))).height))
+            };
+            Item {
+                x,
+                width,
+                height: clamp(height, s.min_height, s.max_height, content.height),
+                fill,
+                margin: (m.top, m.bottom),
+            }
+        })
+        .collect();
+
+    let used: f64 = items
+        .iter()
+        .map(|i| i.height + i.margin.0 + i.margin.1)
+        .sum();
+    let free = round((f64::from(content.height) - used).max(0.0));
+    // Bounds in cells of the content height, which `distribute` would
+    // otherwise take of the free room.
+    let bound = |u: Unit| length(u, content.height).map_or(Unit::Unset, |v| Unit::cells(round(v)));
+    let fills: Vec<usize> = (0..items.len()).filter(|&i| items[i].fill).collect();
+    let tracks: Vec<Track> = fills
+        .iter()
+        .map(|&i| {
+            let s = &shown[i].style;
+            Track::new(s.height)
+                .with_min(bound(s.min_height))
+                .with_max(bound(s.max_height))
+        })
+        .collect();
+    for (&i, share) in fills.iter().zip(distribute(&tracks, free)) {
+        items[i].height = f64::from(share);
+    }
+
+    let mut cursor = 0.0;
+    let mut placed = Vec::with_capacity(items.len());
+    for (item, child) in items.iter().zip(&shown) {
+        let top = cursor + item.margin.0;
+        cursor = top + item.height + item.margin.1;
+        let (x0, x1) = (round(item.x), round(item.x + item.width));
+        let (y0, y1) = (round(top), round(top + item.height));
+        let rect = Rect::new(content.x + x0, content.y + y0, x1 - x0, y1 - y0);
+        placed.push(layout_box(child, rect, content.width));
+    }
+
+    interleave(node, placed, content)
+}
+
+/// Where a child starts and how wide it is, given its resolved left and
+/// right `margin` and the container's content width, `room`.
+fn horizontal(child: &LayoutNode, (left, right): (f64, f64), room: i32) -> (f64, f64) {
+    let s = &child.style;
+    let width = length(s.width, room).unwrap_or(f64::from(room) - left - right);
+    let width = clamp(width, s.min_width, s.max_width, room);
+    // Over-constrained, `auto` margins are zero and the box overflows on
+    // the right.
+    let free = (f64::from(room) - left - width - right).max(0.0);
+    let x = match (s.margin.left, s.margin.right) {
+        (Unit::Auto, Unit::Auto) => free / 2.0,
+        (Unit::Auto, _) => free,
+        _ => left,
+    };
+    (x, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::node;
+    use super::super::{Size, layout};
+    use super::*;
+    use oxidui_style::Style;
+    use oxidui_style::border::Edges;
+    use oxidui_style::layout::Display;
+
+    fn leaf(style: Style, height: i32) -> LayoutNode<'static> {
+        node(style).with_content(Size::new(3, height))
+    }
+
+    #[test]
+    fn children_take_the_width_and_their_content_height() {
+        let root = node(Style::new().with_padding_all(Unit::cells(1)))
+            .with_child(leaf(Style::new(), 2))
+            .with_child(node(Style::new()).with_text("aaa bbb ccc"))
+            .with_child(leaf(Style::new().with_width(Unit::percent(50)), 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert_eq!(out.children[0].rect, Rect::new(1, 1, 8, 2));
+        // Text wraps to the width before its height is measured.
+        assert_eq!(out.children[1].rect, Rect::new(1, 3, 8, 2));
+        assert_eq!(out.children[2].rect, Rect::new(1, 5, 4, 1));
+    }
+
+    #[test]
+    fn margins_and_padding_apply() {
+        let child = Style::new()
+            .with_margin(Edges::new(
+                Unit::cells(1),
+                Unit::cells(2),
+                Unit::cells(1),
+                Unit::percent(10),
+            ))
+            .with_padding_all(Unit::cells(1));
+        let root = node(Style::new())
+            .with_child(leaf(child, 1).with_child(leaf(Style::new(), 1)))
+            .with_child(leaf(Style::new(), 1));
+        let out = layout(&root, Rect::new(0, 0, 30, 10));
+        // 10% of the container's width, on the left as on every side.
+        assert_eq!(out.children[0].rect, Rect::new(3, 1, 25, 3));
+        assert_eq!(out.children[0].children[0].rect, Rect::new(4, 2, 23, 1));
+        assert_eq!(out.children[1].rect, Rect::new(0, 5, 30, 1));
+
+        let vertical = Style::new().with_margin(Edges::symmetric(Unit::percent(10), Unit::ZERO));
+        let root = node(Style::new()).with_child(leaf(vertical, 1));
+        let out = layout(&root, Rect::new(0, 0, 30, 10));
+        assert_eq!(out.children[0].rect, Rect::new(0, 3, 30, 1));
+    }
+
+    #[test]
+    fn negative_margins_overlap() {
+        let pulled = Style::new().with_margin(Edges::new(
+            Unit::cells(-1),
+            Unit::ZERO,
+            Unit::ZERO,
+            Unit::cells(-2),
+        ));
+        let root = node(Style::new().with_padding_all(Unit::cells(2)))
+            .with_child(leaf(Style::new(), 2))
+            .with_child(leaf(pulled, 1))
+            .with_child(leaf(Style::new(), 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        // Up over the previous sibling, and out into the padding.
+        assert_eq!(out.children[1].rect, Rect::new(0, 3, 8, 1));
+        assert_eq!(out.children[2].rect, Rect::new(2, 4, 6, 1));
+    }
+
+    #[test]
+    fn auto_margins_center_a_sized_box() {
+        let centered = Style::new()
+            .with_width(Unit::cells(4))
+            .with_margin(Edges::symmetric(Unit::ZERO, Unit::AUTO));
+        let right = Style::new()
+            .with_width(Unit::cells(4))
+            .with_margin(Edges::new(Unit::ZERO, Unit::ZERO, Unit::ZERO, Unit::AUTO));
+        let root = node(Style::new())
+            .with_child(leaf(centered, 1))
+            .with_child(leaf(right, 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert_eq!(out.children[0].rect, Rect::new(3, 0, 4, 1));
+        assert_eq!(out.children[1].rect, Rect::new(6, 1, 4, 1));
+    }
+
+    #[test]
+    fn fill_heights_share_the_rest() {
+        let root = node(Style::new())
+            .with_child(leaf(Style::new(), 2))
+            .with_child(leaf(Style::new().with_height(Unit::fill(1)), 1))
+            .with_child(leaf(Style::new().with_height(Unit::fill(2)), 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 8));
+        assert_eq!(out.children[1].rect, Rect::new(0, 2, 10, 2));
+        assert_eq!(out.children[2].rect, Rect::new(0, 4, 10, 4));
+
+        // Room a capped share gives back goes to the others.
+        let fill = Style::new().with_height(Unit::fill(1));
+        let root = node(Style::new())
+            .with_child(leaf(fill.clone().with_max_height(Unit::cells(2)), 1))
+            .with_child(leaf(fill.clone(), 1))
+            .with_child(leaf(fill, 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert_eq!(out.children[1].rect, Rect::new(0, 2, 10, 4));
+        assert_eq!(out.children[2].rect, Rect::new(0, 6, 10, 4));
+    }
+
+    #[test]
+    fn hidden_children_take_no_room() {
+        let none = Style::new().with_display(Display::None);
+        let root = node(Style::new())
+            .with_child(leaf(Style::new(), 1))
+            .with_child(leaf(none, 5).with_child(leaf(Style::new(), 1)))
+            .with_child(leaf(Style::new(), 1));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert!(out.children[1].rect.is_empty());
+        assert!(out.children[1].children[0].rect.is_empty());
+        assert_eq!(out.children[2].rect, Rect::new(0, 1, 10, 1));
+        assert_eq!(measure(&root, None), Size::new(3, 2));
+    }
+}
@@ -0,0 +1,611 @@
+//! Flexbox: one line of children along a main axis.
+//!
+//! Follows the CSS flex algorithm for a single line: base sizes, then
+//! growing or shrinking with min/max freezing, then `justify-content` on
+//! the main axis and `align-items` on the cross axis. Reversed directions
+//! are laid out forwards and mirrored.
+//!
+//! `min-width: auto` on an item in a row keeps it from shrinking below its
+//! min-content width, so text wraps rather than overflows. The initial
+//! `min-width` is `0`, which lets items shrink to nothing.
+//!
+//! In a `border-collapse: collapse` container, items are pulled one cell
+//! back onto a border line their container or previous sibling already
+//! draws — see [`collapse`].
+
+use oxidui_style::border::{BorderCollapse, Edges, Side};
+use oxidui_style::layout::{Align, Display, FlexDirection, Justify};
+use oxidui_style::unit::Unit;
+
+use super::{
+    Layout, LayoutNode, Rect, clamp, interleave, is_zero, layout_box, length, margins, measure,
+    min_content, round,
+};
+
+/// Container properties driving the algorithm.
+pub(super) struct Params {
+    pub direction: FlexDirection,
+    pub justify: Justify,
+    pub align: Align,
+    /// Main-axis space between adjacent items, in cells.
+    pub gap: f64,
+}
+
+/// One child's sizes along an axis pair, in fractional cells.
+struct Item {
+    /// Main-axis size before growing/shrinking.
+    basis: f64,
+    min: f64,
+    max: f64,
+    grow: f64,
+    shrink: f64,
+    /// Main-axis margins (start, end), in forward order.
+    margin_main: (f64, f64),
+    /// Cross-axis margins (start, end).
+    margin_cross: (f64, f64),
+    /// Final main-axis size.
+    size: f64,
+    frozen: bool,
+}
+
+/// Lay out `node`'s children inside `content`.
+pub(super) fn layout_children(node: &LayoutNode, content: Rect, p: Params) -> Vec<Layout> {
+    let horizontal = p.direction.is_horizontal();
+    let (main_size, cross_size) = if horizontal {
+        (content.width, content.height)
+    } else {
+        (content.height, content.width)
+    };
+    let main_len = f64::from(main_size);
+
+    let shown: Vec<&LayoutNode> = node
+        .children
+        .iter()
+        .filter(|c| c.style.is_in_flow())
+        .collect();
+
+    let pulls = collapse(node, &shown, p.direction);
+    let mut items: Vec<Item> = shown
+        .iter()
+        .zip(&pulls)
+        .map(|(child, pull)| {
+            let s = &child.style;
+            let m = margins(s, content.width).zip(*pull, |m, pull| m + pull);
+            // In a column the width is settled first, and text wraps to it.
+            let width = if horizontal {
+                None
+            } else if stretches(s.width, p.align) {
+                Some(round(f64::from(content.width) - m.left - m.right))
+            } else {
+                length(s.width, content.width).map(round)
+            };
+            let intrinsic = measure(child, width);
+            let (unit, min, max, natural) = if horizontal {
+                (s.width, s.min_width, s.max_width, intrinsic.width)
+            } else {
+                (s.height, s.min_height, s.max_height, intrinsic.height)
+            };
+            let (margin_main, margin_cross) = split(m, horizontal);
+            // `Fill(w)` takes a `w`-weighted share of the free space in any
+            // container: a zero basis that grows by `w`.
+            let fill = unit.fill_weight().map(f64::from);
+            Item {
+                basis: match fill {
+                    Some(_) => 0.0,
+                    None => length(unit, main_size).unwrap_or(f64::from(natural)),
+                },
+                min: match min {
+                    Unit::Auto if horizontal => f64::from(min_content(child)),
+                    _ => length(min, main_size).unwrap_or(0.0).max(0.0),
+                },
+                max: length(max, main_size).unwrap_or(f64::INFINITY),
+                grow: match fill {
+                    Some(w) => w,
+                    None => f64::from(s.flex_grow.get()).max(0.0),
+                },
+                shrink: f64::from(s.flex_shrink.get()).max(0.0),
+                margin_main,
+                margin_cross,
+                size: 0.0,
+                frozen: false,
+            }
+        })
+        .collect();
+
+    let gaps = p.gap * (items.len().saturating_sub(1)) as f64;
+    let margins_main: f64 = items
+        .iter()
+        .map(|i| i.margin_main.0 + i.margin_main.1)
+        .sum();
+    let inner = main_len - gaps - margins_main;
+    resolve_flexible_lengths(&mut items, inner);
+
+    // Main-axis positions, forward order.
+    let used: f64 = items.iter().map(|i| i.size).sum::<f64>();
+    let free = inner - used;
+    let n = items.len() as f64;
+    let (lead, between) = match p.justify {
+        Justify::Start => (0.0, 0.0),
+        Justify::End => (free, 0.0),
+        Justify::Center => (free / 2.0, 0.0),
+        // Negative free space: distributed alignment falls back, as in CSS.
+        Justify::SpaceBetween if free <= 0.0 || n < 2.0 => (0.0, 0.0),
+        Justify::SpaceBetween => (0.0, free / (n - 1.0)),
+        Justify::SpaceAround | Justify::SpaceEvenly if free <= 0.0 => (free / 2.0, 0.0),
+        Justify::SpaceAround => (free / n / 2.0, free / n),
+        Justify::SpaceEvenly => (free / (n + 1.0), free / (n + 1.0)),
+    };
+
+    let mut cursor = lead;
+    let mut placed = Vec::with_capacity(items.len());
+    for (item, child) in items.iter().zip(&shown) {
+        let start = cursor + item.margin_main.0;
+        let end = start + item.size;
+        cursor = end + item.margin_main.1 + p.gap + between;
+
+        let (start, end) = if p.direction.is_reversed() {
+            (main_len - end, main_len - start)
+        } else {
+            (start, end)
+        };
+        let (c_start, c_end) = cross(child, item, cross_size, p.align, horizontal);
+
+        let (main_origin, cross_origin) = if horizontal {
+            (content.x, content.y)
+        } else {
+            (content.y, content.x)
+        };
+        let (m0, m1) = (main_origin + round(start), main_origin + round(end));
+        let (c0, c1) = (cross_origin + round(c_start), cross_origin + round(c_end));
+        let rect = if horizontal {
+            Rect::new(m0, c0, m1 - m0, c1 - c0)
+        } else {
+            Rect::new(c0, m0, c1 - c0, m1 - m0)
+        };
+        placed.push(layout_box(child, rect, content.width));
+    }
+
+    interleave(node, placed, content)
+}
+
+/// Main and cross margins for an axis.
+fn split(m: Edges<f64>, horizontal: bool) -> ((f64, f64), (f64, f64)) {
+    if horizontal {
+        ((m.left, m.right), (m.top, m.bottom))
+    } else {
+        ((m.top, m.bottom), (m.left, m.right))
+    }
+}
+
+/// `border-collapse: collapse`: extra margins of `-1` that pull each of
+/// `shown` onto a border line drawn right next to its own. That is the
+/// container's border where the child touches it (no padding between),
+/// and the previous sibling's facing border unless a gap parts them.
+///
+/// All zeros when `node` doesn't collapse.
+pub(super) fn collapse(
+    node: &LayoutNode,
+    shown: &[&LayoutNode],
+    direction: FlexDirection,
+) -> Vec<Edges<f64>> {
+    let s = &node.style;
+    if s.border_collapse == BorderCollapse::Separate {
+        return vec![Edges::all(0.0); shown.len()];
+    }
+    let touching = s
+        .border_widths()
+        .zip(s.padding, |border, padding| border > 0 && is_zero(padding));
+    let adjacent = s.display != Display::Flex || is_zero(s.gap);
+    // The sides items meet their neighbours on, in layout order.
+    let (before, after) = match direction {
+        FlexDirection::Row => (Side::Left, Side::Right),
+        FlexDirection::RowReverse => (Side::Right, Side::Left),
+        FlexDirection::Column => (Side::Top, Side::Bottom),
+        FlexDirection::ColumnReverse => (Side::Bottom, Side::Top),
+    };
+    let last = shown.len().saturating_sub(1);
+    let mut previous = false;
+    shown
+        .iter()
+        .enumerate()
+        .map(|(i, child)| {
+            let bordered = child.style.border_widths().map(|w| w > 0);
+            let mut pull = touching.zip(bordered, |t, b| if t && b { -1.0 } else { 0.0 });
+            // Only the ends of the line touch the container on the main axis.
+            if i > 0 {
+                let shared = previous && adjacent && bordered.get(before);
+                *pull.get_mut(before) = if shared { -1.0 } else { 0.0 };
+            }
+            if i < last {
+                *pull.get_mut(after) = 0.0;
+            }
+            previous = bordered.get(after);
+            pull
+        })
+        .collect()
+}
+
+/// CSS §9.7 "resolve flexible lengths": grow or shrink toward `inner`,
+/// freezing items that hit their min/max and redistributing the rest.
+fn resolve_flexible_lengths(items: &mut [Item], inner: f64) {
+    let hypothetical: f64 = items.iter().map(|i| i.basis.min(i.max).max(i.min)).sum();
+    let growing = hypothetical < inner;
+
+    for item in items.iter_mut() {
+        item.size = item.basis;
+        let factor = if growing { item.grow } else { item.shrink };
+        if factor == 0.0
+            || (growing && item.basis > item.max)
+            || (!growing && item.basis < item.min)
+        {
+            item.size = item.basis.min(item.max).max(item.min);
+            item.frozen = true;
+        }
+    }
+
+    // Each pass freezes at least one item, so this terminates.
+    for _ in 0..=items.len() {
+        if items.iter().all(|i| i.frozen) {
+            break;
+        }
+        let taken: f64 = items
+            .iter()
+            .map(|i| if i.frozen { i.size } else { i.basis })
+            .sum();
+        let free = inner - taken;
+
+        let weight = |i: &Item| {
+            if growing { i.grow } else { i.shrink * i.basis }
+        };
+        let total: f64 = items.iter().filter(|i| !i.frozen).map(weight).sum();
+        // Per item: how far clamping moved it from its target.
+        let mut clamped = vec![0.0; items.len()];
+        for (item, moved) in items.iter_mut().zip(&mut clamped) {
+            if item.frozen {
+                continue;
+            }
+            let share = if total > 0.0 {
+                free * weight(item) / total
+            } else {
+                0.0
+            };
+            let target = item.basis + share;
+            item.size = target.min(item.max).max(item.min).max(0.0);
+            *moved = item.size - target;
+        }
+
+        // Positive total: some items hit their minimum — freeze those and
+        // share out again. Negative: same for maximums. Zero: done.
+        let total_moved: f64 = clamped.iter().sum();
+        for (item, moved) in items.iter_mut().zip(clamped) {
+            if !item.frozen {
+                item.frozen = total_moved == 0.0
+                    || (total_moved > 0.0 && moved > 0.0)
+                    || (total_moved < 0.0 && moved < 0.0);
+            }
+        }
+    }
+}
+
+/// Cross-axis start and end of an item, relative to the content box.
+fn cross(
+    child: &LayoutNode,
+    item: &Item,
+    cross_size: i32,
+    align: Align,
+    horizontal: bool,
+) -> (f64, f64) {
+    let s = &child.style;
+    let (unit, min, max) = if horizontal {
+        (s.height, s.min_height, s.max_height)
+    } else {
+        (s.width, s.min_width, s.max_width)
+    };
+    let available = f64::from(cross_size);
+    let (m0, m1) = item.margin_cross;
+
+    let size = if stretches(unit, align) {
+        available - m0 - m1
+    } else {
+        let intrinsic = measure(child, horizontal.then(|| round(item.size)));
+        let natural = if horizontal {
+            intrinsic.height
+        } else {
+            intrinsic.width
+        };
+        length(unit, cross_size).unwrap_or(f64::from(natural))
+    };
+    let size = clamp(size, min, max, cross_size);
+
+    let start = match align {
+        Align::Start | Align::Stretch | Align::Baseline => m0,
+        Align::Center => m0 + (available - m0 - m1 - size) / 2.0,
+        Align::End => available - m1 - size,
+    };
+    (start, start + size)
+}
+
+/// `true` if an item sized `unit` on the cross axis fills it.
+fn stretches(unit: Unit, align: Align) -> bool {
+    matches!(unit, Unit::Fill(_))
+        || align == Align::Stretch && matches!(unit, Unit::Auto | Unit::Unset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::node;
+    use super::super::{Size, layout};
+    use super::*;
+    use oxidui_style::Style;
+    use oxidui_style::layout::Display;
+    use oxidui_style::number::Float;
+
+    fn flex(direction: FlexDirection) -> Style {
+        Style::new()
+            .with_display(Display::Flex)
+            .with_flex_direction(direction)
+    }
+
+    fn fixed(w: i32) -> LayoutNode<'static> {
+        node(Style::new().with_width(Unit::cells(w)))
+    }
+
+    fn grow(g: f32) -> LayoutNode<'static> {
+        node(Style::new().with_flex_grow(Float::new(g)))
+    }
+
+    /// `(x, width)` of each child of a row laid out in `width` cells.
+    fn row(style: Style, children: Vec<LayoutNode>, width: i32) -> Vec<(i32, i32)> {
+        let root = node(style).with_children(children);
+        layout(&root, Rect::new(0, 0, width, 1))
+            .children
+            .iter()
+            .map(|c| (c.rect.x, c.rect.width))
+            .collect()
+    }
+
+    #[test]
+    fn grow_distributes_by_weight() {
+        let out = row(
+            flex(FlexDirection::Row),
+            vec![grow(1.0), grow(2.0), fixed(4)],
+            16,
+        );
+        assert_eq!(out, [(0, 4), (4, 8), (12, 4)]);
+    }
+
+    #[test]
+    fn rounding_never_overlaps_or_gaps() {
+        for width in 0..40 {
+            let out = row(
+                flex(FlexDirection::Row),
+                vec![grow(1.0), grow(1.0), grow(1.0)],
+                width,
+            );
+            assert_eq!(out[0].0, 0);
+            for pair in out.windows(2) {
+                assert_eq!(pair[0].0 + pair[0].1, pair[1].0, "width {width}");
+            }
+            let last = out[2];
+            assert_eq!(last.0 + last.1, width);
+            let (min, max) = out
+                .iter()
+                .fold((i32::MAX, 0), |(lo, hi), c| (lo.min(c.1), hi.max(c.1)));
+            assert!(max - min <= 1, "width {width}: {out:?}");
+        }
+    }
+
+    #[test]
+    fn shrink_is_weighted_by_basis() {
+        // 30 + 10 = 40 in 20 cells: 20 over, taken 3:1.
+        let out = row(flex(FlexDirection::Row), vec![fixed(30), fixed(10)], 20);
+        assert_eq!(out, [(0, 15), (15, 5)]);
+
+        let rigid = node(
+            Style::new()
+                .with_width(Unit::cells(30))
+                .with_flex_shrink(Float::ZERO),
+        );
+        let out = row(flex(FlexDirection::Row), vec![rigid, fixed(10)], 20);
+        assert_eq!(out, [(0, 30), (30, 0)]);
+    }
+
+    #[test]
+    fn min_and_max_freeze_items() {
+        let capped = node(
+            Style::new()
+                .with_flex_grow(Float::ONE)
+                .with_max_width(Unit::cells(3)),
+        );
+        let out = row(flex(FlexDirection::Row), vec![capped, grow(1.0)], 20);
+        assert_eq!(out, [(0, 3), (3, 17)]);
+
+        let floored = node(
+            Style::new()
+                .with_width(Unit::cells(10))
+                .with_min_width(Unit::cells(8)),
+        );
+        let out = row(flex(FlexDirection::Row), vec![floored, fixed(10)], 10);
+        assert_eq!(out, [(0, 8), (8, 2)]);
+    }
+
+    #[test]
+    fn justify_variants() {
+        let cases = [
+            (Justify::Start, [(0, 2), (2, 2)]),
+            (Justify::End, [(6, 2), (8, 2)]),
+            (Justify::Center, [(3, 2), (5, 2)]),
+            (Justify::SpaceBetween, [(0, 2), (8, 2)]),
+            (Justify::SpaceAround, [(2, 2), (7, 2)]),
+            (Justify::SpaceEvenly, [(2, 2), (6, 2)]),
+        ];
+        for (justify, expected) in cases {
+            let style = flex(FlexDirection::Row).with_justify_content(justify);
+            assert_eq!(
+                row(style, vec![fixed(2), fixed(2)], 10),
+                expected,
+                "{justify:?}"
+            );
+        }
+        // Around vs evenly differ with three items: 9 free cells.
+        let three = || vec![fixed(1), fixed(1), fixed(1)];
+        let around = flex(FlexDirection::Row).with_justify_content(Justify::SpaceAround);
+        assert_eq!(row(around, three(), 12), [(2, 1), (6, 1), (10, 1)]);
+        let evenly = flex(FlexDirection::Row).with_justify_content(Justify::SpaceEvenly);
+        assert_eq!(row(evenly, three(), 12), [(2, 1), (6, 1), (9, 1)]);
+    }
+
+    #[test]
+    fn gaps_sit_between_items_only() {
+        let style = flex(FlexDirection::Row).with_gap(Unit::cells(2));
+        assert_eq!(
+            row(style.clone(), vec![fixed(1), fixed(1), fixed(1)], 10),
+            [(0, 1), (3, 1), (6, 1)]
+        );
+        assert_eq!(row(style, vec![grow(1.0), grow(1.0)], 10), [(0, 4), (6, 4)]);
+    }
+
+    #[test]
+    fn reversed_directions_mirror() {
+        let out = row(
+            flex(FlexDirection::RowReverse),
+            vec![fixed(2), fixed(3)],
+            10,
+        );
+        assert_eq!(out, [(8, 2), (5, 3)]);
+
+        let root = node(flex(FlexDirection::ColumnReverse))
+            .with_child(node(Style::new().with_height(Unit::cells(2))))
+            .with_child(node(Style::new().with_height(Unit::cells(3))));
+        let out = layout(&root, Rect::new(0, 0, 4, 10));
+        let ys: Vec<_> = out
+            .children
+            .iter()
+            .map(|c| (c.rect.y, c.rect.height))
+            .collect();
+        assert_eq!(ys, [(8, 2), (5, 3)]);
+    }
+
+    #[test]
+    fn align_items_on_cross_axis() {
+        let leaf = || node(Style::new()).with_content(Size::new(2, 2));
+        let place = |align| {
+            let root = node(flex(FlexDirection::Row).with_align_items(align)).with_child(leaf());
+            layout(&root, Rect::new(0, 0, 10, 6)).children[0].rect
+        };
+        assert_eq!(place(Align::Stretch), Rect::new(0, 0, 2, 6));
+        assert_eq!(place(Align::Start), Rect::new(0, 0, 2, 2));
+        assert_eq!(place(Align::Center), Rect::new(0, 2, 2, 2));
+        assert_eq!(place(Align::End), Rect::new(0, 4, 2, 2));
+
+        // An explicit cross size opts out of stretching.
+        let root = node(flex(FlexDirection::Row))
+            .with_child(node(Style::new().with_height(Unit::cells(1))));
+        assert_eq!(
+            layout(&root, Rect::new(0, 0, 10, 6)).children[0]
+                .rect
+                .height,
+            1
+        );
+    }
+
+    #[test]
+    fn margins_offset_items() {
+        let spaced = node(Style::new().with_width(Unit::cells(2)).with_margin(
+            oxidui_style::border::Edges::symmetric(Unit::cells(1), Unit::cells(1)),
+        ));
+        let root = node(flex(FlexDirection::Row)).with_children([spaced, fixed(2)]);
+        let out = layout(&root, Rect::new(0, 0, 10, 5));
+        assert_eq!(out.children[0].rect, Rect::new(1, 1, 2, 3));
+        assert_eq!(out.children[1].rect, Rect::new(4, 0, 2, 5));
+    }
+
+    #[test]
+    fn fill_units_share_free_space() {
+        let fill = |w| node(Style::new().with_width(Unit::fill(w)));
+        let out = row(
+            flex(FlexDirection::Row),
+            vec![fill(1), fixed(4), fill(3)],
+            20,
+        );
+        assert_eq!(out, [(0, 4), (4, 4), (8, 12)]);
+
+        // Fill on the cross axis stretches whatever `align-items` says.
+        let root = node(flex(FlexDirection::Row).with_align_items(Align::Start))
+            .with_child(node(Style::new().with_height(Unit::FILL)));
+        let out = layout(&root, Rect::new(0, 0, 10, 6));
+        assert_eq!(out.children[0].rect.height, 6);
+    }
+
+    #[test]
+    fn negative_margins_overlap_neighbours() {
+        let pulled = node(
+            Style::new()
+                .with_width(Unit::cells(3))
+                .with_margin(Edges::new(
+                    Unit::ZERO,
+                    Unit::ZERO,
+                    Unit::ZERO,
+                    Unit::cells(-1),
+                )),
+        );
+        let out = row(flex(FlexDirection::Row), vec![fixed(3), pulled], 10);
+        assert_eq!(out, [(0, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn collapsed_borders_overlap() {
+        use oxidui_style::border::{Border, BorderCollapse};
+        let boxed = || {
+            node(
+                Style::new()
+                    .with_width(Unit::cells(3))
+                    .with_border(Border::SOLID),
+            )
+        };
+        let collapsed = flex(FlexDirection::Row).with_border_collapse(BorderCollapse::Collapse);
+        assert_eq!(
+            row(collapsed.clone(), vec![boxed(), boxed(), fixed(3)], 10),
+            [(0, 3), (2, 3), (5, 3)]
+        );
+        // A gap keeps the borders apart.
+        let gapped = collapsed.clone().with_gap(Unit::cells(1));
+        assert_eq!(row(gapped, vec![boxed(), boxed()], 10), [(0, 3), (4, 3)]);
+
+        // Children land on the container's border, and it measures smaller.
+        let root = node(collapsed.with_border(Border::SOLID)).with_children([boxed(), boxed()]);
+        let out = layout(&root, Rect::new(0, 0, 10, 3));
+        assert_eq!(out.children[0].rect, Rect::new(0, 0, 3, 3));
+        assert_eq!(out.children[1].rect, Rect::new(2, 0, 3, 3));
+        assert_eq!(measure(&root, None), Size::new(5, 2));
+    }
+
+    #[test]
+    fn auto_min_width_stops_at_min_content() {
+        // 9 + 20 in 10 cells: shrinking by basis would leave the text 3.
+        let text = |min| node(Style::new().with_min_width(min)).with_text("abcd efgh");
+        let out = row(
+            flex(FlexDirection::Row),
+            vec![text(Unit::AUTO), fixed(20)],
+            10,
+        );
+        assert_eq!(out, [(0, 4), (4, 6)]);
+        let out = row(
+            flex(FlexDirection::Row),
+            vec![text(Unit::ZERO), fixed(20)],
+            10,
+        );
+        assert_eq!(out[0].1, 3);
+    }
+
+    #[test]
+    fn hidden_children_take_no_space() {
+        let gone = node(
+            Style::new()
+                .with_width(Unit::cells(5))
+                .with_display(Display::None),
+        );
+        let out = row(flex(FlexDirection::Row), vec![gone, fixed(2)], 10);
+        assert_eq!(out, [(0, 0), (0, 2)]);
+    }
+}
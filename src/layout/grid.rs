@@ -0,0 +1,570 @@
+//! Grid: children placed in the cells of a table of columns and rows.
+//!
+//! Follows CSS grid in outline. Items with a fixed `grid-column` and
+//! `grid-row` go where they say; the rest fill the free cells row by row,
+//! each after the last one placed automatically. Rows past the template
+//! are added as items need them, and so are columns for items placed
+//! past the last one; the extra tracks are `auto`.
+//!
+//! Tracks are sized columns first, then rows at the column widths, so
+//! text wraps before its height is measured. Cell and percentage tracks
+//! take their size; `auto` tracks fit their items, from min-content up to
+//! max-content as room allows; `Fill` tracks (`fr`) share what is left by
+//! weight. With no `Fill` track, leftover room is shared by the `auto`
+//! tracks instead. `gap` separates both columns and rows.
+//!
+//! Items stretch over their cells unless sized; `align-items` places them
+//! within their rows.
+
+use oxidui_style::layout::Align;
+use oxidui_style::unit::{Track, Unit, distribute};
+
+use super::{
+    Layout, LayoutNode, Rect, Size, clamp, interleave, layout_box, length, margins, measure,
+    min_content, round,
+};
+
+/// How much room the tracks of one axis have.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Room {
+    /// Shrink every track to its min-content size.
+    MinContent,
+    /// Every track at its max-content size.
+    MaxContent,
+    /// Fill this many cells.
+    Definite(f64),
+}
+
+/// The tracks an item covers: half-open ranges of 0-based indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cells {
+    columns: (usize, usize),
+    rows: (usize, usize),
+}
+
+/// Placed and sized tracks, in fractional cells.
+pub(super) struct Grid {
+    cells: Vec<Cells>,
+    columns: Vec<f64>,
+    rows: Vec<f64>,
+    gap: (f64, f64),
+}
+
+impl Grid {
+    /// Place `shown` — `node`'s displayed children — and size the tracks.
+    /// Percentages resolve against definite room, and count as `auto`
+    /// otherwise.
+    pub fn new(node: &LayoutNode, shown: &[&LayoutNode], width: Room, height: Room) -> Self {
+        let s = &node.style;
+        let base = |room| match room {
+            Room::Definite(v) => round(v),
+            _ => 0,
+        };
+        let gap = (
+            length(s.gap, base(width)).unwrap_or(0.0).max(0.0),
+            length(s.gap, base(height)).unwrap_or(0.0).max(0.0),
+        );
+        let cells = place(node, shown);
+        let column_count = cells.iter().map(|c| c.columns.1).max().unwrap_or(0);
+        let column_count = column_count.max(s.grid_template_columns.len()).max(1);
+        let row_count = cells.iter().map(|c| c.rows.1).max().unwrap_or(0);
+        let row_count = row_count.max(s.grid_template_rows.len());
+
+        let spans: Vec<Span> = shown
+            .iter()
+            .zip(&cells)
+            .map(|(child, cells)| {
+                let m = margins(&child.style, 0).map(round).horizontal_sum();
+                Span {
+                    tracks: cells.columns,
+                    min: f64::from(min_content(child) + m),
+                    max: f64::from(measure(child, None).width + m),
+                }
+            })
+            .collect();
+        let template = s.grid_template_columns.as_slice();
+        let columns = track_sizes(template, column_count, width, gap.0, &spans);
+
+        let spans: Vec<Span> = shown
+            .iter()
+            .zip(&cells)
+            .map(|(child, cells)| {
+                let m = margins(&child.style, 0).map(round);
+                let (_, area) = span(&columns, gap.0, cells.columns);
+                let width = round(area) - m.horizontal_sum();
+                let height = f64::from(measure(child, Some(width)).height + m.vertical_sum());
+                Span {
+                    tracks: cells.rows,
+                    min: height,
+                    max: height,
+                }
+            })
+            .collect();
+        let template = s.grid_template_rows.as_slice();
+        let rows = track_sizes(template, row_count, height, gap.1, &spans);
+
+        Self {
+            cells,
+            columns,
+            rows,
+            gap,
+        }
+    }
+
+    /// The size of the whole grid: every track and the gaps between.
+    pub fn size(&self) -> Size {
+        let total = |sizes: &[f64], gap| span(sizes, gap, (0, sizes.len())).1;
+        Size::new(
+            round(total(&self.columns, self.gap.0)),
+            round(total(&self.rows, self.gap.1)),
+        )
+    }
+}
+
+/// Lay out `node`'s children inside `content`.
+pub(super) fn layout_children(node: &LayoutNode, content: Rect) -> Vec<Layout> {
+    let shown: Vec<&LayoutNode> = node
+        .children
+        .iter()
+        .filter(|c| c.style.is_in_flow())
+        .collect();
+    let grid = Grid::new(
+        node,
+        &shown,
+        Room::Definite(f64::from(content.width)),
+        Room::Definite(f64::from(content.height)),
+    );
+
+    let mut placed = Vec::with_capacity(shown.len());
+    for (child, cells) in shown.iter().zip(&grid.cells) {
+        let s = &child.style;
+        let (x, w) = span(&grid.columns, grid.gap.0, cells.columns);
+        let (y, h) = span(&grid.rows, grid.gap.1, cells.rows);
+        let m = margins(s, content.width);
+
+        let width = match length(s.width, round(w)) {
+            Some(width) => width,
+            None => w - m.left - m.right,
+        };
+        let width = clamp(width, s.min_width, s.max_width, round(w));
+        let stretch = matches!(s.height, Unit::Fill(_))
+            || node.style.align_items == Align::Stretch
+                && matches!(s.height, Unit::Auto | Unit::Unset);
+        let height = match length(s.height, round(h)) {
+            Some(height) => height,
+            None if stretch => h - m.top - m.bottom,
+            None => f64::from(measure(child, Some(round(width))).height),
+        };
+        let height = clamp(height, s.min_height, s.max_height, round(h));
+        let top = match node.style.align_items {
+            Align::Start | Align::Stretch | Align::Baseline => m.top,
+            Align::Center => m.top + (h - m.top - m.bottom - height) / 2.0,
+            Align::End => h - m.bottom - height,
+        };
+
+        let (x0, x1) = (x + m.left, x + m.left + width);
+        let (y0, y1) = (y + top, y + top + height);
+        let (x0, x1) = (content.x + round(x0), content.x + round(x1));
+        let (y0, y1) = (content.y + round(y0), content.y + round(y1));
+        let rect = Rect::new(x0, y0, x1 - x0, y1 - y0);
+        placed.push(layout_box(child, rect, round(w)));
+    }
+
+    interleave(node, placed, content)
+}
+
+/// The offset and size of the tracks `start..end`, gaps between included.
+fn span(sizes: &[f64], gap: f64, (start, end): (usize, usize)) -> (f64, f64) {
+    let offset = sizes[..start.min(sizes.len())]
+        .iter()
+        .map(|s| s + gap)
+        .sum();
+    let covered = &sizes[start.min(sizes.len())..end.min(sizes.len())];
+    let size = covered.iter().sum::<f64>() + gap * covered.len().saturating_sub(1) as f64;
+    (offset, size)
+}
+
+// ---------------------------------------------------------------------------
+// Placement
+// ---------------------------------------------------------------------------
+
+/// Decide which cells each of `shown` covers.
+///
+/// Items with both starts fixed go first, then items with only a row,
+/// then the rest in order behind a cursor that moves along the rows.
+fn place(node: &LayoutNode, shown: &[&LayoutNode]) -> Vec<Cells> {
+    let fixed_end = shown
+        .iter()
+        .filter_map(|c| c.style.grid_column.tracks())
+        .map(|(_, end)| end)
+        .max()
+        .unwrap_or(0);
+    let columns = node.style.grid_template_columns.len().max(fixed_end).max(1);
+    let mut taken = Taken::default();
+    let mut cells: Vec<Option<Cells>> = vec![None; shown.len()];
+
+    let mut order: Vec<usize> = (0..shown.len()).collect();
+    order.sort_by_key(|&i| {
+        let s = &shown[i].style;
+        match (s.grid_column.start, s.grid_row.start) {
+            (Some(_), Some(_)) => 0,
+            (None, Some(_)) => 1,
+            _ => 2,
+        }
+    });
+
+    let mut cursor = (0, 0);
+    for i in order {
+        let s = &shown[i].style;
+        let column_span = usize::from(s.grid_column.span).min(columns);
+        let row_span = usize::from(s.grid_row.span);
+        let placed = match (s.grid_column.tracks(), s.grid_row.tracks()) {
+            (Some(c), Some(r)) => Cells {
+                columns: c,
+                rows: r,
+            },
+            // A fixed row: the first column there that is free.
+            (None, Some(rows)) => {
+                let start = (0..=columns - column_span)
+                    .find(|&c| taken.is_free((c, c + column_span), rows))
+                    .unwrap_or(0);
+                Cells {
+                    columns: (start, start + column_span),
+                    rows,
+                }
+            }
+            // A fixed column: the first free row there, at or after the
+            // cursor's — or the row after, if the cursor has passed it.
+            (Some(c), None) => {
+                let mut row = cursor.0 + usize::from(c.0 < cursor.1);
+                while !taken.is_free(c, (row, row + row_span)) {
+                    row += 1;
+                }
+                cursor = (row, c.0);
+                Cells {
+                    columns: c,
+                    rows: (row, row + row_span),
+                }
+            }
+            (None, None) => {
+                let (mut row, mut column) = cursor;
+                loop {
+                    if column + column_span > columns {
+                        (row, column) = (row + 1, 0);
+                    } else if taken.is_free((column, column + column_span), (row, row + row_span)) {
+                        break;
+                    } else {
+                        column += 1;
+                    }
+                }
+                cursor = (row, column + column_span);
+                Cells {
+                    columns: (column, column + column_span),
+                    rows: (row, row + row_span),
+                }
+            }
+        };
+        taken.take(placed);
+        cells[i] = Some(placed);
+    }
+    cells
+        .into_iter()
+        .map(|c| {
+            c.unwrap_or(Cells {
+                columns: (0, 1),
+                rows: (0, 1),
+            })
+        })
+        .collect()
+}
+
+/// The cells items have been placed in so far.
+#[derive(Default)]
+struct Taken(Vec<Cells>);
+
+impl Taken {
+    fn is_free(&self, columns: (usize, usize), rows: (usize, usize)) -> bool {
+        let overlaps = |a: (usize, usize), b: (usize, usize)| a.0 < b.1 && b.0 < a.1;
+        !self
+            .0
+            .iter()
+            .any(|c| overlaps(c.columns, columns) && overlaps(c.rows, rows))
+    }
+
+    fn take(&mut self, cells: Cells) {
+        self.0.push(cells);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Track sizing
+// ---------------------------------------------------------------------------
+
+/// What one item needs of the tracks it covers, margins included.
+struct Span {
+    tracks: (usize, usize),
+    min: f64,
+    max: f64,
+}
+
+/// How a track is sized.
+#[derive(Clone, Copy)]
+enum Sizing {
+    Fixed,
+    Auto,
+    /// A `Fill` weight.
+    Flex(u16),
+}
+
+/// Sizes of `count` tracks: the first ones declared by `template`, the
+/// rest `auto`.
+fn track_sizes(template: &[Unit], count: usize, room: Room, gap: f64, spans: &[Span]) -> Vec<f64> {
+    let definite = match room {
+        Room::Definite(v) => Some(v),
+        _ => None,
+    };
+    let mut sizing = Vec::with_capacity(count);
+    // Each track's size so far, and how far an `auto` one would grow.
+    let mut base = Vec::with_capacity(count);
+    let mut limit = Vec::with_capacity(count);
+    for i in 0..count {
+        let unit = template.get(i).copied().unwrap_or(Unit::Auto);
+        let fixed = match definite {
+            Some(room) => length(unit, round(room)),
+            None => unit.as_cells().map(f64::from),
+        };
+        let (kind, size) = match (fixed, unit.fill_weight()) {
+            (Some(size), _) => (Sizing::Fixed, size.max(0.0)),
+            // Without room to share, `fr` tracks fit their items.
+            (None, Some(w)) if definite.is_some() => (Sizing::Flex(w), 0.0),
+            (None, _) => (Sizing::Auto, 0.0),
+        };
+        sizing.push(kind);
+        base.push(size);
+        limit.push(size);
+    }
+
+    // Items grow the `auto` tracks they cover, narrowest spans first. An
+    // item across a `Fill` track leaves the sharing to it.
+    let mut order: Vec<&Span> = spans.iter().collect();
+    order.sort_by_key(|s| s.tracks.1 - s.tracks.0);
+    for item in order {
+        let (start, end) = (item.tracks.0.min(count), item.tracks.1.min(count));
+        let covered = start..end;
+        if covered
+            .clone()
+            .any(|i| matches!(sizing[i], Sizing::Flex(_)))
+        {
+            continue;
+        }
+        let auto: Vec<usize> = covered
+            .clone()
+            .filter(|&i| matches!(sizing[i], Sizing::Auto))
+            .collect();
+        if auto.is_empty() {
+            continue;
+        }
+        let gaps = gap * (end - start).saturating_sub(1) as f64;
+        for (sizes, need) in [(&mut base, item.min), (&mut limit, item.max)] {
+            let extra = need - gaps - covered.clone().map(|i| sizes[i]).sum::<f64>();
+            if extra > 0.0 {
+                for &i in &auto {
+                    sizes[i] += extra / auto.len() as f64;
+                }
+            }
+        }
+    }
+    for i in 0..count {
+        limit[i] = limit[i].max(base[i]);
+    }
+
+    let Some(room) = definite else {
+        return if room == Room::MinContent {
+            base
+        } else {
+            limit
+        };
+    };
+    let gaps = gap * count.saturating_sub(1) as f64;
+    let mut free = room - gaps - base.iter().sum::<f64>();
+
+    // Grow `auto` tracks towards max-content, evenly, while there is room.
+    loop {
+        let growing: Vec<usize> = (0..count)
+            .filter(|&i| matches!(sizing[i], Sizing::Auto) && base[i] < limit[i])
+            .collect();
+        if growing.is_empty() || free <= 1e-9 {
+            break;
+        }
+        let share = free / growing.len() as f64;
+        for i in growing {
+            let grow = share.min(limit[i] - base[i]);
+            base[i] += grow;
+            free -= grow;
+        }
+    }
+
+    let flex: Vec<(usize, u16)> = sizing
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| match s {
+            Sizing::Flex(w) => Some((i, *w)),
+            _ => None,
+        })
+        .collect();
+    if !flex.is_empty() {
+        let tracks: Vec<Track> = flex
+            .iter()
+            .map(|&(_, w)| Track::new(Unit::Fill(w)))
+            .collect();
+        let shares = distribute(&tracks, round(free.max(0.0)));
+        for (&(i, _), share) in flex.iter().zip(shares) {
+            base[i] = f64::from(share);
+        }
+    } else if free > 0.0 {
+        let auto = sizing.iter().filter(|s| matches!(s, Sizing::Auto)).count();
+        for (size, kind) in base.iter_mut().zip(&sizing) {
+            if matches!(kind, Sizing::Auto) {
+                *size += free / auto as f64;
+            }
+        }
+    }
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::layout;
+    use super::super::tests::node;
+    use super::*;
+    use oxidui_style::Style;
+    use oxidui_style::grid::{GridPlacement, TrackList};
+    use oxidui_style::layout::Display;
+
+    fn grid(columns: &[Unit]) -> Style {
+        let tracks = columns
+            .iter()
+            .fold(TrackList::EMPTY, |list, &track| list.with(track));
+        Style::new()
+            .with_display(Display::Grid)
+            .with_grid_template_columns(tracks)
+    }
+
+    fn leaf(w: i32, h: i32) -> LayoutNode<'static> {
+        node(Style::new()).with_content(Size::new(w, h))
+    }
+
+    fn rects(out: &Layout) -> Vec<Rect> {
+        out.children.iter().map(|c| c.rect).collect()
+    }
+
+    #[test]
+    fn fixed_and_fill_columns() {
+        let style = grid(&[Unit::cells(10), Unit::fill(1), Unit::fill(2)]);
+        let root = node(style).with_children((0..4).map(|_| leaf(1, 1)));
+        let out = layout(&root, Rect::new(0, 0, 40, 2));
+        assert_eq!(
+            rects(&out),
+            [
+                Rect::new(0, 0, 10, 1),
+                Rect::new(10, 0, 10, 1),
+                Rect::new(20, 0, 20, 1),
+                Rect::new(0, 1, 10, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn auto_columns_fit_content_then_share_the_rest() {
+        let root = node(grid(&[Unit::AUTO, Unit::AUTO])).with_children([
+            leaf(4, 1),
+            leaf(2, 2),
+            leaf(6, 1),
+        ]);
+        // Max-content 6 and 2, plus half of the 12 left each.
+        let out = layout(&root, Rect::new(0, 0, 20, 3));
+        assert_eq!(
+            rects(&out),
+            [
+                Rect::new(0, 0, 12, 2),
+                Rect::new(12, 0, 8, 2),
+                Rect::new(0, 2, 12, 1),
+            ]
+        );
+        let measured = measure(&root, None);
+        assert_eq!(measured, Size::new(8, 3));
+    }
+
+    #[test]
+    fn gap_separates_columns_and_rows() {
+        let style = grid(&[Unit::fill(1), Unit::fill(1)]).with_gap(Unit::cells(2));
+        let root = node(style).with_children((0..3).map(|_| leaf(1, 1)));
+        let out = layout(&root, Rect::new(0, 0, 12, 4));
+        assert_eq!(
+            rects(&out),
+            [
+                Rect::new(0, 0, 5, 1),
+                Rect::new(7, 0, 5, 1),
+                Rect::new(0, 3, 5, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_and_fixed_placements() {
+        let style = grid(&[Unit::cells(4), Unit::cells(4), Unit::cells(4)]);
+        let wide = node(Style::new().with_grid_column(GridPlacement::span(2)));
+        let corner = node(
+            Style::new()
+                .with_grid_column(GridPlacement::at(3))
+                .with_grid_row(GridPlacement::at(1).with_span(2)),
+        );
+        let root = node(style).with_children([
+            wide.with_content(Size::new(1, 1)),
+            leaf(1, 1),
+            leaf(1, 1),
+            corner.with_content(Size::new(1, 1)),
+        ]);
+        let out = layout(&root, Rect::new(0, 0, 12, 2));
+        // The corner goes first; the rest flow around it.
+        assert_eq!(
+            rects(&out),
+            [
+                Rect::new(0, 0, 8, 1),
+                Rect::new(0, 1, 4, 1),
+                Rect::new(4, 1, 4, 1),
+                Rect::new(8, 0, 4, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_fit_wrapped_text_and_align() {
+        let style = grid(&[Unit::cells(5), Unit::cells(5)]).with_align_items(Align::End);
+        let text = node(Style::new()).with_text("aa bb cc");
+        let root = node(style).with_children([text, leaf(2, 1)]);
+        let out = layout(&root, Rect::new(0, 0, 10, 2));
+        // Two lines of text at 5 wide; the short item sits at the bottom.
+        assert_eq!(rects(&out), [Rect::new(0, 0, 5, 2), Rect::new(5, 1, 5, 1)]);
+    }
+
+    #[test]
+    fn fill_rows_share_the_height() {
+        let rows = TrackList::EMPTY.with(Unit::cells(1)).with(Unit::fill(1));
+        let style = grid(&[]).with_grid_template_rows(rows);
+        let hidden = node(Style::new().with_display(Display::None));
+        let root = node(style).with_children([leaf(1, 1), hidden, leaf(1, 1)]);
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert_eq!(out.children[0].rect, Rect::new(0, 0, 10, 1));
+        assert!(out.children[1].rect.is_empty());
+        assert_eq!(out.children[2].rect, Rect::new(0, 1, 10, 9));
+    }
+
+    #[test]
+    fn min_content_sums_columns() {
+        let style = grid(&[Unit::cells(3), Unit::AUTO]).with_gap(Unit::cells(1));
+        let root =
+            node(style).with_children([leaf(1, 1), node(Style::new()).with_text("wide words")]);
+        assert_eq!(min_content(&root), 3 + 1 + 5);
+    }
+}
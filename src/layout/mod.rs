@@ -0,0 +1,610 @@
+//! Layout — computed styles in, integer cell rectangles out.
+//!
+//! The input is a [`LayoutNode`] tree: each node's [`ComputedStyle`], its
+//! content (text, or an intrinsic size) and its children. [`layout`]
+//! places the root in a terminal area and returns a parallel [`Layout`]
+//! tree of rectangles.
+//!
+//! Text wraps to the width its box gets, so a text box's height is
+//! measured at that width — see [`crate::text`].
+//!
+//! A block container (`display: block`, the default) stacks its children
+//! top to bottom; a flex container lays them out along one axis.
+//!
+//! A grid container (`display: grid`) places its children in the cells
+//! of its track templates.
+//!
+//! Positioned boxes (`position: relative | absolute | fixed`) are placed
+//! once the flow is laid out, against the nearest positioned ancestor.
+//!
+//! A scroll container (`overflow: scroll`) keeps a column for its
+//! scrollbar, and its children are laid out unscrolled, overflowing its
+//! content box as they need; the renderer shifts them by the scroll
+//! offset.
+//!
+//! All arithmetic is done in fractional cells and rounded only when a box
+//! edge is emitted. Neighbouring boxes share the same fractional edge, so
+//! they round to the same cell: no overlaps and no stray one-cell gaps.
+//!
+//! ```rust
+//! use oxidui::layout::{layout, LayoutNode, Rect};
+//! use oxidui::style::{Style, computed::ComputedStyle, layout::Display, number::Float};
+//!
+//! let compute = |s: Style| ComputedStyle::resolve(&s, &ComputedStyle::ROOT);
+//! let grow = compute(Style::new().with_flex_grow(Float::ONE));
+//! let root = LayoutNode::new(compute(Style::new().with_display(Display::Flex)))
+//!     .with_child(LayoutNode::new(grow))
+//!     .with_child(LayoutNode::new(grow));
+//!
+//! let out = layout(&root, Rect::new(0, 0, 9, 1));
+//! assert_eq!(out.children[0].rect, Rect::new(0, 0, 5, 1));
+//! assert_eq!(out.children[1].rect, Rect::new(5, 0, 4, 1));
+//! ```
+
+mod block;
+mod flex;
+mod grid;
+mod position;
+
+use oxidui_style::border::Edges;
+use oxidui_style::computed::ComputedStyle;
+use oxidui_style::layout::{Display, FlexDirection, LineClamp, Overflow};
+use oxidui_style::unit::{Unit, clamp_size};
+
+use crate::text;
+
+// ---------------------------------------------------------------------------
+// Geometry
+// ---------------------------------------------------------------------------
+
+/// A rectangle of terminal cells. Signed, so boxes pulled outside their
+/// container by negative margins keep their true position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// One past the last column.
+    pub const fn right(self) -> i32 {
+        self.x + self.width
+    }
+
+    /// One past the last row.
+    pub const fn bottom(self) -> i32 {
+        self.y + self.height
+    }
+
+    /// `true` if the rectangle covers no cells.
+    pub const fn is_empty(self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    /// `true` if the cell at `(x, y)` lies inside.
+    pub const fn contains(self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Shrink by `edges` on each side. Never produces a negative size.
+    pub fn inset(self, edges: Edges<i32>) -> Self {
+        Self {
+            x: self.x + edges.left,
+            y: self.y + edges.top,
+            width: (self.width - edges.horizontal_sum()).max(0),
+            height: (self.height - edges.vertical_sum()).max(0),
+        }
+    }
+
+    /// The same rectangle moved by `dx` columns and `dy` rows.
+    pub const fn translate(self, dx: i32, dy: i32) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    /// The overlapping part of two rectangles (empty if they don't meet).
+    pub fn intersection(self, other: Self) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        Self {
+            x,
+            y,
+            width: (self.right().min(other.right()) - x).max(0),
+            height: (self.bottom().min(other.bottom()) - y).max(0),
+        }
+    }
+}
+
+/// A width and height in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Size {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Size {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self { width, height }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tree
+// ---------------------------------------------------------------------------
+
+/// Layout input: one element's computed style and children.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LayoutNode<'t> {
+    pub style: ComputedStyle,
+    /// Natural size of the element's own content, used when a dimension
+    /// is `Auto` and there is no text or children to measure.
+    pub content: Size,
+    /// Text content, wrapped to the box's width when measured.
+    pub text: Option<&'t str>,
+    pub children: Vec<LayoutNode<'t>>,
+}
+
+impl<'t> LayoutNode<'t> {
+    pub fn new(style: ComputedStyle) -> Self {
+        Self {
+            style,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_content(mut self, content: Size) -> Self {
+        self.content = content;
+        self
+    }
+
+    pub fn with_text(mut self, text: &'t str) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    pub fn with_child(mut self, child: LayoutNode<'t>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = LayoutNode<'t>>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+/// Layout output for one node, mirroring the [`LayoutNode`] tree.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Layout {
+    /// Border box — what the element's background and border cover.
+    pub rect: Rect,
+    /// Content box — `rect` inside border and padding; children live here.
+    pub content: Rect,
+    pub children: Vec<Layout>,
+}
+
+impl Layout {
+    /// Move the box and its whole subtree by `dx` columns and `dy` rows.
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        self.rect = self.rect.translate(dx, dy);
+        self.content = self.content.translate(dx, dy);
+        for child in &mut self.children {
+            child.translate(dx, dy);
+        }
+    }
+}
+
+/// Lay out `root` inside `area`.
+///
+/// The root fills `area` unless it declares its own size; its margins are
+/// taken from `area`'s edges.
+pub fn layout(root: &LayoutNode, area: Rect) -> Layout {
+    let s = &root.style;
+    let margin = margins(s, area.width);
+    let avail = area.inset(margin.map(round));
+    let width = s.width.resolve_size(area.width, avail.width);
+    let height = s.height.resolve_size(area.height, avail.height);
+    let width = clamp_size(
+        width.unwrap_or(avail.width),
+        s.min_width,
+        s.max_width,
+        area.width,
+    );
+    let height = clamp_size(
+        height.unwrap_or(avail.height),
+        s.min_height,
+        s.max_height,
+        area.height,
+    );
+    let rect = Rect::new(avail.x, avail.y, width, height);
+    let mut out = layout_box(root, rect, area.width);
+    position::place(root, &mut out, area, area);
+    out
+}
+
+/// Lay out `node`, already placed at `rect`, and its subtree.
+///
+/// `container_width` is the parent's content width, which percentage
+/// padding is resolved against.
+fn layout_box(node: &LayoutNode, rect: Rect, container_width: i32) -> Layout {
+    let content = rect.inset(frame(&node.style, container_width));
+    let children = match node.style.display {
+        Display::Flex => flex::layout_children(
+            node,
+            content,
+            flex::Params {
+                direction: node.style.flex_direction,
+                justify: node.style.justify_content,
+                align: node.style.align_items,
+                gap: length(node.style.gap, content_main(node, content)).unwrap_or(0.0),
+            },
+        ),
+        Display::Grid => grid::layout_children(node, content),
+        Display::Block => block::layout_children(node, content),
+        Display::None => node.children.iter().map(|c| hidden(c, content)).collect(),
+    };
+    Layout {
+        rect,
+        content,
+        children,
+    }
+}
+
+fn content_main(node: &LayoutNode, content: Rect) -> i32 {
+    if node.style.flex_direction.is_horizontal() {
+        content.width
+    } else {
+        content.height
+    }
+}
+
+/// A subtree that takes no space (`display: none`): every box is empty and
+/// sits at `at`. Also stands in for an out-of-flow child until
+/// [`position::place`] lays it out.
+fn hidden(node: &LayoutNode, at: Rect) -> Layout {
+    let at = Rect::new(at.x, at.y, 0, 0);
+    Layout {
+        rect: at,
+        content: at,
+        children: node.children.iter().map(|c| hidden(c, at)).collect(),
+    }
+}
+
+/// The boxes of all `node`'s children, from those of its in-flow ones,
+/// `placed`, in order. Hidden and out-of-flow children get [`hidden`]
+/// boxes at `content`, so the output mirrors the input tree; out-of-flow
+/// ones are placed afterwards.
+fn interleave(node: &LayoutNode, placed: Vec<Layout>, content: Rect) -> Vec<Layout> {
+    let mut placed = placed.into_iter();
+    node.children
+        .iter()
+        .map(|c| {
+            if c.style.is_in_flow() {
+                placed.next().unwrap_or_default()
+            } else {
+                hidden(c, content)
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Measuring
+// ---------------------------------------------------------------------------
+
+/// Intrinsic border-box size: declared cell sizes where given, otherwise
+/// the size the content needs. Percentages count as `Auto` here — there is
+/// no container to take a percentage of.
+///
+/// `width` is the border-box width the node will be given, if already
+/// known. Text wraps to fit it, which is what decides its height; without
+/// one, text is measured unwrapped.
+fn measure(node: &LayoutNode, width: Option<i32>) -> Size {
+    let s = &node.style;
+    let frame = frame(s, 0);
+    let shown = || node.children.iter().filter(|c| c.style.is_in_flow());
+    let inner_width = s
+        .width
+        .as_cells()
+        .or(width)
+        .map(|w| (w - frame.horizontal_sum()).max(0));
+
+    let inner = if let Some(text) = node.text {
+        let text = text::collapse(text, s.white_space);
+        let width = inner_width.filter(|_| s.white_space.wraps()).unwrap_or(0);
+        let mut lines = text::wrap(&text, width, s.word_break);
+        if let LineClamp::Lines(clamp) = s.line_clamp {
+            lines.truncate(clamp.get() as usize);
+        }
+        let widest = lines.iter().map(|l| l.width).max().unwrap_or(0);
+        Size::new(widest, lines.len() as i32)
+    } else if node.children.is_empty() {
+        node.content
+    } else if s.display == Display::Grid {
+        let shown: Vec<_> = shown().collect();
+        let width = inner_width.map_or(grid::Room::MaxContent, |w| {
+            grid::Room::Definite(f64::from(w))
+        });
+        grid::Grid::new(node, &shown, width, grid::Room::MaxContent).size()
+    } else {
+        let horizontal = s.display == Display::Flex && s.flex_direction.is_horizontal();
+        let gap = match s.display {
+            Display::Flex => s.gap.as_cells().unwrap_or(0),
+            _ => 0,
+        };
+        let count = shown().count() as i32;
+        let gaps = gap * (count - 1).max(0);
+        let direction = match s.display {
+            Display::Flex => s.flex_direction,
+            _ => FlexDirection::Column,
+        };
+        let pulls = flex::collapse(node, &shown().collect::<Vec<_>>(), direction);
+        let outer = shown().zip(pulls).map(|(c, pull)| {
+            let m = margins(&c.style, 0)
+                .zip(pull, |m, pull| m + pull)
+                .map(round);
+            // Stacked children stretch across the content width.
+            let width = inner_width
+                .filter(|_| !horizontal)
+                .map(|w| w - m.horizontal_sum());
+            let size = measure(c, width);
+            Size::new(
+                size.width + m.horizontal_sum(),
+                size.height + m.vertical_sum(),
+            )
+        });
+        if horizontal {
+            outer.fold(Size::new(gaps, 0), |acc, c| {
+                Size::new(acc.width + c.width, acc.height.max(c.height))
+            })
+        } else {
+            outer.fold(Size::new(0, gaps), |acc, c| {
+                Size::new(acc.width.max(c.width), acc.height + c.height)
+            })
+        }
+    };
+
+    let natural = |u: Unit, inner: i32| u.as_cells().unwrap_or(inner);
+    Size::new(
+        clamp_size(
+            natural(s.width, inner.width + frame.horizontal_sum()),
+            s.min_width,
+            s.max_width,
+            0,
+        ),
+        clamp_size(
+            natural(s.height, inner.height + frame.vertical_sum()),
+            s.min_height,
+            s.max_height,
+            0,
+        ),
+    )
+}
+
+/// The narrowest border box the content fits without overflowing: the
+/// widest word of text, the widest child of a stack, the children of a
+/// row side by side. CSS `min-content`, which `min-width: auto` resolves
+/// to in a flex row.
+fn min_content(node: &LayoutNode) -> i32 {
+    let s = &node.style;
+    let shown = || node.children.iter().filter(|c| c.style.is_in_flow());
+    let inner = if let Some(text) = node.text {
+        let text = text::collapse(text, s.white_space);
+        if s.white_space.wraps() {
+            text::min_content(&text, s.word_break)
+        } else {
+            text::max_content(&text)
+        }
+    } else if node.children.is_empty() {
+        node.content.width
+    } else if s.display == Display::Grid {
+        let shown: Vec<_> = shown().collect();
+        let (width, height) = (grid::Room::MinContent, grid::Room::MaxContent);
+        grid::Grid::new(node, &shown, width, height).size().width
+    } else {
+        let outer =
+            shown().map(|c| min_content(c) + margins(&c.style, 0).map(round).horizontal_sum());
+        if s.display == Display::Flex && s.flex_direction.is_horizontal() {
+            let gaps = s.gap.as_cells().unwrap_or(0) * (shown().count() as i32 - 1).max(0);
+            outer.sum::<i32>() + gaps
+        } else {
+            outer.max().unwrap_or(0)
+        }
+    };
+    clamp_size(
+        s.width
+            .as_cells()
+            .unwrap_or(inner + frame(s, 0).horizontal_sum()),
+        s.min_width,
+        s.max_width,
+        0,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Units
+// ---------------------------------------------------------------------------
+
+/// [`Unit::resolve`] without the rounding: flex positions stay fractional
+/// until a box edge is emitted.
+fn length(unit: Unit, base: i32) -> Option<f64> {
+    match unit {
+        Unit::Cells(n) => Some(f64::from(n)),
+        Unit::Percent(p) => Some(f64::from(base) * f64::from(p) / 100.0),
+        Unit::Fill(_) | Unit::Auto | Unit::Unset => None,
+    }
+}
+
+/// `true` if `unit` takes no space: zero, or a length that doesn't apply.
+fn is_zero(unit: Unit) -> bool {
+    length(unit, 100).unwrap_or(0.0) <= 0.0
+}
+
+/// [`clamp_size`] for fractional sizes.
+fn clamp(v: f64, min: Unit, max: Unit, base: i32) -> f64 {
+    let v = length(max, base).map_or(v, |max| v.min(max));
+    length(min, base).map_or(v, |min| v.max(min)).max(0.0)
+}
+
+/// Resolved margins. Percentages refer to the container's width on every
+/// side; `Auto` counts as zero.
+fn margins(style: &ComputedStyle, container_width: i32) -> Edges<f64> {
+    style
+        .margin
+        .map(|u| length(u, container_width).unwrap_or(0.0))
+}
+
+/// Border plus padding, in whole cells. A scroll container's scrollbar
+/// takes one more column on the right.
+fn frame(style: &ComputedStyle, container_width: i32) -> Edges<i32> {
+    let mut frame = style
+        .padding
+        .map(|u| round(length(u, container_width).unwrap_or(0.0).max(0.0)))
+        .zip(style.border_widths(), |padding, border| padding + border);
+    if style.overflow == Overflow::Scroll {
+        frame.right += 1;
+    }
+    frame
+}
+
+/// Round a fractional cell position. Half-way cases go up, so the result
+/// depends only on the value — never on which box is asking.
+fn round(v: f64) -> i32 {
+    (v + 0.5).floor() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxidui_style::Style;
+    use oxidui_style::border::Border;
+    use oxidui_style::layout::WhiteSpace;
+    use oxidui_style::number::Int;
+
+    /// A node with `style` resolved against the root. Shared by the
+    /// tests of every layout mode.
+    pub(super) fn node(style: Style) -> LayoutNode<'static> {
+        LayoutNode::new(ComputedStyle::resolve(&style, &ComputedStyle::ROOT))
+    }
+
+    #[test]
+    fn rect_helpers() {
+        let r = Rect::new(2, 3, 10, 4);
+        assert_eq!((r.right(), r.bottom()), (12, 7));
+        assert!(r.contains(2, 3) && !r.contains(12, 3));
+        assert_eq!(r.inset(Edges::all(1)), Rect::new(3, 4, 8, 2));
+        assert_eq!(r.inset(Edges::all(5)).height, 0);
+        assert_eq!(
+            r.intersection(Rect::new(10, 0, 5, 5)),
+            Rect::new(10, 3, 2, 2)
+        );
+        assert!(r.intersection(Rect::new(20, 20, 1, 1)).is_empty());
+    }
+
+    #[test]
+    fn root_fills_area_inside_margins() {
+        let root = node(Style::new().with_margin_all(Unit::cells(1)));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.rect, Rect::new(1, 1, 18, 8));
+    }
+
+    #[test]
+    fn border_and_padding_shrink_content() {
+        let root = node(
+            Style::new()
+                .with_border(Border::SOLID)
+                .with_padding(Edges::symmetric(Unit::ZERO, Unit::cells(2))),
+        );
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.content, Rect::new(3, 1, 14, 8));
+    }
+
+    #[test]
+    fn single_border_side_insets_one_edge() {
+        let root = node(Style::new().with_border_sides(Edges {
+            left: Some(Border::SOLID),
+            ..Edges::all(None)
+        }));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.content, Rect::new(1, 0, 19, 10));
+    }
+
+    #[test]
+    fn scroll_containers_keep_a_scrollbar_column() {
+        let root = node(
+            Style::new()
+                .with_overflow(Overflow::Scroll)
+                .with_border(Border::SOLID),
+        )
+        .with_children((0..5).map(|_| node(Style::new()).with_content(Size::new(3, 1))));
+        let mut out = layout(&root, Rect::new(0, 0, 10, 4));
+        assert_eq!(out.content, Rect::new(1, 1, 7, 2));
+        // Children overflow the content box unscrolled.
+        assert_eq!(out.children[4].rect, Rect::new(1, 5, 7, 1));
+        assert_eq!(measure(&root, None), Size::new(6, 7));
+
+        out.translate(0, -3);
+        assert_eq!(out.children[4].rect, Rect::new(1, 2, 7, 1));
+        assert_eq!(out.rect, Rect::new(0, -3, 10, 4));
+    }
+
+    #[test]
+    fn block_children_stack_at_content_height() {
+        let root = node(Style::new())
+            .with_child(node(Style::new()).with_content(Size::new(5, 2)))
+            .with_child(node(Style::new().with_height(Unit::cells(3))));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.children[0].rect, Rect::new(0, 0, 20, 2));
+        assert_eq!(out.children[1].rect, Rect::new(0, 2, 20, 3));
+    }
+
+    #[test]
+    fn wrapped_text_grows_taller() {
+        let text = node(Style::new()).with_text("aa bb cc");
+        let root = node(Style::new().with_border(Border::SOLID)).with_child(text.clone());
+        let out = layout(&root, Rect::new(0, 0, 7, 10));
+        assert_eq!(out.children[0].rect, Rect::new(1, 1, 5, 2));
+
+        assert_eq!(measure(&text, None), Size::new(8, 1));
+        assert_eq!(measure(&text, Some(2)), Size::new(2, 3));
+        assert_eq!(min_content(&text), 2);
+    }
+
+    #[test]
+    fn white_space_and_clamp_shape_text() {
+        let text = |style: Style| node(style).with_text("aa bb\n  cc dd");
+        assert_eq!(measure(&text(Style::new()), Some(5)), Size::new(5, 2));
+        let nowrap = text(Style::new().with_white_space(WhiteSpace::NoWrap));
+        assert_eq!(measure(&nowrap, Some(5)), Size::new(11, 1));
+        assert_eq!(min_content(&nowrap), 11);
+        let pre = text(Style::new().with_white_space(WhiteSpace::Pre));
+        assert_eq!(measure(&pre, Some(5)), Size::new(7, 2));
+        let clamped = text(Style::new().with_line_clamp(LineClamp::Lines(Int::new(1))));
+        assert_eq!(measure(&clamped, Some(2)), Size::new(2, 1));
+    }
+
+    #[test]
+    fn measure_sums_along_the_main_axis() {
+        let leaf = |w, h| node(Style::new()).with_content(Size::new(w, h));
+        let row = node(
+            Style::new()
+                .with_display(Display::Flex)
+                .with_gap(Unit::cells(1))
+                .with_border(Border::SOLID),
+        )
+        .with_children([leaf(3, 1), leaf(4, 2)]);
+        assert_eq!(measure(&row, None), Size::new(3 + 1 + 4 + 2, 2 + 2));
+    }
+}
@@ -0,0 +1,231 @@
+//! Positioning: boxes shifted or placed outside the normal flow.
+//!
+//! Runs once the flow is laid out. A `relative` box moves by its offsets,
+//! subtree and all, without disturbing its siblings. An `absolute` box is
+//! laid out against its containing block — the padding box of the nearest
+//! positioned ancestor, or the whole area — and a `fixed` one against the
+//! whole area. Left in the flow as empty placeholders until now, they are
+//! then laid out like any other box, and their own positioned descendants
+//! placed in turn.
+//!
+//! An out-of-flow box with both offsets of an axis `auto` stays where its
+//! parent's content starts. With both set and an `auto` size, it stretches
+//! between them; otherwise it shrinks to fit its content.
+
+use oxidui_style::layout::Position;
+use oxidui_style::unit::Unit;
+
+use super::{Layout, LayoutNode, Rect, clamp, layout_box, length, margins, measure, round};
+
+/// Place the positioned descendants of `node`, whose boxes are `boxes`.
+///
+/// `containing` is the containing block of its `absolute` descendants,
+/// unless `node` is positioned itself; `area` is that of `fixed` ones.
+pub(super) fn place(node: &LayoutNode, boxes: &mut Layout, containing: Rect, area: Rect) {
+    let containing = if node.style.position.is_positioned() {
+        boxes.rect.inset(node.style.border_widths())
+    } else {
+        containing
+    };
+    let content = boxes.content;
+    for (child, child_boxes) in node.children.iter().zip(&mut boxes.children) {
+        if !child.style.is_displayed() {
+            continue;
+        }
+        match child.style.position {
+            Position::Static => {}
+            Position::Relative => {
+                let (dx, dy) = shift(child, content);
+                child_boxes.translate(dx, dy);
+            }
+            Position::Absolute => *child_boxes = out_of_flow(child, containing, content),
+            Position::Fixed => *child_boxes = out_of_flow(child, area, content),
+        }
+        place(child, child_boxes, containing, area);
+    }
+}
+
+/// How far a `relative` box moves: `left` wins over `right` and `top`
+/// over `bottom`, as in CSS. Percentages refer to `content`, the parent's
+/// content box.
+fn shift(node: &LayoutNode, content: Rect) -> (i32, i32) {
+    let inset = node.style.inset;
+    let offset = |start: Unit, end: Unit, base: i32| {
+        length(start, base)
+            .or_else(|| length(end, base).map(|v| -v))
+            .map_or(0, round)
+    };
+    (
+        offset(inset.left, inset.right, content.width),
+        offset(inset.top, inset.bottom, content.height),
+    )
+}
+
+/// Lay out an `absolute` or `fixed` box against `block`. `flow` is the
+/// parent's content box, where the box stays on an axis without offsets.
+fn out_of_flow(node: &LayoutNode, block: Rect, flow: Rect) -> Layout {
+    let s = &node.style;
+    let m = margins(s, block.width);
+    let x = axis(
+        (s.inset.left, s.inset.right),
+        (m.left, m.right),
+        s.width,
+        (block.x, block.width),
+        flow.x,
+        |room| f64::from(measure(node, None).width).min(room.max(0.0)),
+    );
+    let width = clamp(x.1, s.min_width, s.max_width, block.width);
+    let x = (x.0, width);
+    let y = axis(
+        (s.inset.top, s.inset.bottom),
+        (m.top, m.bottom),
+        s.height,
+        (block.y, block.height),
+        flow.y,
+        |_| f64::from(measure(node, Some(round(width))).height),
+    );
+    let height = clamp(y.1, s.min_height, s.max_height, block.height);
+
+    let (x0, x1) = (round(x.0), round(x.0 + x.1));
+    let (y0, y1) = (round(y.0), round(y.0 + height));
+    layout_box(node, Rect::new(x0, y0, x1 - x0, y1 - y0), block.width)
+}
+
+/// Start and size of a box along one axis of its containing block,
+/// `(start, size)`. `natural` is the size its content wants, given the
+/// room it has.
+fn axis(
+    (start, end): (Unit, Unit),
+    (margin_start, margin_end): (f64, f64),
+    size: Unit,
+    (origin, room): (i32, i32),
+    flow: i32,
+    natural: impl FnOnce(f64) -> f64,
+) -> (f64, f64) {
+    let start = length(start, room);
+    let end = length(end, room);
+    // The room between the offsets, or to the block's far edge.
+    let between = f64::from(room) - start.unwrap_or(0.0) - end.unwrap_or(0.0);
+    let between = between - margin_start - margin_end;
+    let size = match (length(size, room), start.zip(end)) {
+        (Some(size), _) => size,
+        (None, Some(_)) => between,
+        (None, None) if matches!(size, Unit::Fill(_)) => between,
+        (None, None) => natural(between),
+    };
+    let origin = f64::from(origin);
+    let position = match (start, end) {
+        (Some(start), _) => origin + start + margin_start,
+        (None, Some(end)) => origin + f64::from(room) - end - margin_end - size,
+        (None, None) => f64::from(flow) + margin_start,
+    };
+    (position, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::node;
+    use super::super::{Size, layout};
+    use super::*;
+    use oxidui_style::Style;
+    use oxidui_style::border::{Border, Edges};
+
+    fn positioned(position: Position, inset: [Unit; 4]) -> Style {
+        let [top, right, bottom, left] = inset;
+        Style::new()
+            .with_position(position)
+            .with_inset(Edges::new(top, right, bottom, left))
+    }
+
+    const AUTO: Unit = Unit::AUTO;
+
+    #[test]
+    fn absolute_boxes_leave_the_flow() {
+        let popup = node(positioned(
+            Position::Absolute,
+            [Unit::cells(1), AUTO, AUTO, Unit::cells(2)],
+        ))
+        .with_content(Size::new(4, 2));
+        let root = node(Style::new())
+            .with_child(node(Style::new()).with_content(Size::new(3, 1)))
+            .with_child(popup)
+            .with_child(node(Style::new()).with_content(Size::new(3, 1)));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.children[1].rect, Rect::new(2, 1, 4, 2));
+        // The next sibling takes the popup's place in the flow.
+        assert_eq!(out.children[2].rect, Rect::new(0, 1, 20, 1));
+        assert_eq!(measure(&root, None), Size::new(3, 2));
+    }
+
+    #[test]
+    fn absolute_boxes_use_the_nearest_positioned_ancestor() {
+        let menu = positioned(
+            Position::Absolute,
+            [Unit::percent(100), AUTO, AUTO, Unit::ZERO],
+        );
+        let anchor = node(
+            Style::new()
+                .with_position(Position::Relative)
+                .with_border(Border::SOLID)
+                .with_padding_all(Unit::cells(1))
+                .with_height(Unit::cells(4)),
+        )
+        .with_child(node(Style::new()).with_content(Size::new(2, 1)))
+        .with_child(node(menu).with_content(Size::new(6, 3)));
+        let root = node(Style::new().with_padding_all(Unit::cells(1))).with_child(anchor);
+        let out = layout(&root, Rect::new(0, 0, 20, 20));
+        // Just below the anchor's border, against its padding box.
+        assert_eq!(out.children[0].rect, Rect::new(1, 1, 18, 4));
+        assert_eq!(out.children[0].children[1].rect, Rect::new(2, 4, 6, 3));
+    }
+
+    #[test]
+    fn offsets_on_both_sides_stretch() {
+        let inset = [
+            Unit::cells(1),
+            Unit::cells(2),
+            Unit::cells(3),
+            Unit::cells(4),
+        ];
+        let root = node(Style::new()).with_child(node(positioned(Position::Absolute, inset)));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.children[0].rect, Rect::new(4, 1, 14, 6));
+
+        // Anchored to the far edges, a box keeps its content size.
+        let corner = positioned(Position::Absolute, [AUTO, Unit::ZERO, Unit::ZERO, AUTO]);
+        let root = node(Style::new()).with_child(node(corner).with_content(Size::new(5, 2)));
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.children[0].rect, Rect::new(15, 8, 5, 2));
+    }
+
+    #[test]
+    fn relative_boxes_shift_with_their_subtree() {
+        let shifted = node(positioned(
+            Position::Relative,
+            [AUTO, Unit::cells(2), Unit::cells(1), AUTO],
+        ))
+        .with_child(node(Style::new()).with_content(Size::new(2, 1)));
+        let root = node(Style::new())
+            .with_child(shifted)
+            .with_child(node(Style::new()).with_content(Size::new(2, 1)));
+        let out = layout(&root, Rect::new(0, 0, 10, 10));
+        assert_eq!(out.children[0].rect, Rect::new(-2, -1, 10, 1));
+        assert_eq!(out.children[0].children[0].rect, Rect::new(-2, -1, 10, 1));
+        assert_eq!(out.children[1].rect, Rect::new(0, 1, 10, 1));
+    }
+
+    #[test]
+    fn fixed_boxes_ignore_positioned_ancestors() {
+        let toast = positioned(Position::Fixed, [AUTO, Unit::ZERO, Unit::ZERO, AUTO]);
+        let anchor = node(
+            Style::new()
+                .with_position(Position::Relative)
+                .with_width(Unit::cells(5))
+                .with_height(Unit::cells(5)),
+        )
+        .with_child(node(toast).with_content(Size::new(3, 1)));
+        let root = node(Style::new()).with_child(anchor);
+        let out = layout(&root, Rect::new(0, 0, 20, 10));
+        assert_eq!(out.children[0].children[0].rect, Rect::new(17, 9, 3, 1));
+    }
+}
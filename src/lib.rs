@@ -0,0 +1,242 @@
+//! OxidUi — a framework for building terminal user interfaces.
+//!
+//! This crate ties the workspace together:
+//!
+//! - [`style`] — the CSS-like type system (`oxidui_style`, re-exported).
+//! - [`rsx!`] / [`scss!`] — JSX-like element trees and compile-time styles
+//!   (`oxidui_macros`, re-exported).
+//! - [`element`] — the runtime element tree that `rsx!` builds.
+//! - [`layout`] — block, flex and grid layout, and positioning, from
+//!   computed styles to cell rectangles.
+//! - [`text`] — grapheme-aware text measuring and wrapping.
+//! - [`watcher`] — hot-reloading of `.oxss` stylesheets.
+//! - [`render`] — cascade, layout and paint into a ratatui buffer.
+//! - [`scroll`] — scroll positions of `overflow: scroll` containers.
+//! - [`app`] — terminal setup and teardown around the event loop.
+//! - [`backend`] — a crossterm backend that writes every font style.
+//!
+//! ```rust
+//! use oxidui::rsx;
+//!
+//! let ui = rsx! {
+//!     <Box id="root">
+//!         <Text>"hello"</Text>
+//!     </Box>
+//! };
+//! assert_eq!(ui.tag.as_str(), "Box");
+//! ```
+
+// Lets `rsx!`'s `::oxidui::…` paths resolve inside this crate too.
+extern crate self as oxidui;
+
+#[cfg(feature = "ratatui")]
+pub mod app;
+#[cfg(feature = "ratatui")]
+pub mod backend;
+pub mod element;
+pub mod layout;
+#[cfg(feature = "ratatui")]
+pub mod render;
+#[cfg(feature = "ratatui")]
+pub mod scroll;
+pub mod text;
+pub mod watcher;
+
+pub use oxidui_macros::{rsx, scss};
+pub use oxidui_style as style;
+
+#[cfg(test)]
+mod tests {
+    use crate::scss;
+    use crate::style::Style;
+    use crate::style::border::{Border, BorderStyle, Edges};
+    use crate::style::color::{Color, NamedColor};
+    use crate::style::font::FontStyle;
+    use crate::style::layout::{
+        Align, Display, FlexDirection, Justify, LineClamp, Overflow, TextAlign, TextOverflow,
+        WhiteSpace,
+    };
+    use crate::style::number::{Float, Int};
+    use crate::style::stylesheet::Stylesheet;
+    use crate::style::unit::Unit;
+
+    const PANEL: Style = scss! {
+        width: 50%;
+        height: 3;
+        min-width: 10;
+        min-height: 1;
+        max-width: 1fr;
+        max-height: auto;
+        padding: 0 1;
+        margin: 1 -1 2;
+        display: flex;
+        flex-direction: column-reverse;
+        flex-grow: 2;
+        flex-shrink: 0.5;
+        align-items: center;
+        justify-content: space-evenly;
+        gap: 1;
+        color: #ff5f00;
+        background: bright-black;
+        border: double cyan;
+        border-left: thick;
+        opacity: 0.75;
+        text-align: right;
+        font-style: bold italic curly-underline;
+        underline-color: red;
+        overflow: hidden;
+    };
+
+    #[test]
+    fn scss_covers_every_field() {
+        let c = Unit::cells;
+        let expected = Style::new()
+            .with_width(Unit::percent(50))
+            .with_height(c(3))
+            .with_min_width(c(10))
+            .with_min_height(c(1))
+            .with_max_width(Unit::fill(1))
+            .with_max_height(Unit::AUTO)
+            .with_padding(Edges::symmetric(c(0), c(1)))
+            .with_margin(Edges::new(c(1), c(-1), c(2), c(-1)))
+            .with_display(Display::Flex)
+            .with_flex_direction(FlexDirection::ColumnReverse)
+            .with_flex_grow(Float::new(2.0))
+            .with_flex_shrink(Float::HALF)
+            .with_align_items(Align::Center)
+            .with_justify_content(Justify::SpaceEvenly)
+            .with_gap(c(1))
+            .with_color(Color::rgb(255, 95, 0))
+            .with_background(Color::Named(NamedColor::BrightBlack))
+            .with_border_sides(Edges {
+                left: Some(Border {
+                    style: Some(BorderStyle::Thick),
+                    color: None,
+                }),
+                ..Edges::all(Some(Border {
+                    style: Some(BorderStyle::Double),
+                    color: Some(Color::Named(NamedColor::Cyan)),
+                }))
+            })
+            .with_opacity(Float::new(0.75))
+            .with_text_align(TextAlign::Right)
+            .with_font_style(FontStyle::BOLD | FontStyle::ITALIC | FontStyle::CURLY_UNDERLINE)
+            .with_underline_color(Color::Named(NamedColor::Red))
+            .with_overflow(Overflow::Hidden);
+
+        assert_eq!(PANEL, expected);
+    }
+
+    #[test]
+    fn scss_and_stylesheets_share_grammar() {
+        let sheet = Stylesheet::parse(
+            "Panel {
+                width: 50%; height: 3; min-width: 10; min-height: 1;
+                max-width: 1fr; max-height: auto; padding: 0 1; margin: 1 -1 2;
+                display: flex; flex-direction: column-reverse; flex-grow: 2;
+                flex-shrink: 0.5; align-items: center; justify-content: space-evenly;
+                gap: 1; color: #ff5f00; background: bright-black; border: double cyan;
+                border-left: thick; opacity: 0.75; text-align: right; font-style: bold italic curly-underline;
+                underline-color: red; overflow: hidden;
+            }",
+        );
+        assert_eq!(sheet.map(|s| s.rules[0].style.clone()), Ok(PANEL));
+    }
+
+    #[test]
+    fn scss_accepts_every_color_syntax() {
+        const S: Style = scss! {
+            color: hsl(210deg 100% 50%);
+            background: rgb(10, 20, 30);
+            border: rounded #0af;
+        };
+        assert_eq!(S.color, Some(Color::rgb(0, 128, 255)));
+        assert_eq!(S.background, Some(Color::rgb(10, 20, 30)));
+        assert_eq!(
+            S.border.and_then(|b| b.top).and_then(|b| b.color),
+            Some(Color::rgb(0x00, 0xaa, 0xff))
+        );
+        assert_eq!(scss! { color: ansi(208) }.color, Some(Color::Indexed(208)));
+        assert_eq!(scss! { color: teal }.color, Some(Color::rgb(0, 128, 128)));
+    }
+
+    #[test]
+    fn scss_text_overflow_and_clamp() {
+        const S: Style = scss! {
+            white-space: pre-wrap;
+            text-overflow: " [more]";
+            line-clamp: 2;
+        };
+        assert_eq!(
+            S,
+            Style::new()
+                .with_white_space(WhiteSpace::PreWrap)
+                .with_text_overflow(TextOverflow::Marker(" [more]".into()))
+                .with_line_clamp(LineClamp::Lines(Int::new(2)))
+        );
+        let s = scss! { text-overflow: middle-ellipsis; line-clamp: none };
+        assert_eq!(s.text_overflow, Some(TextOverflow::MiddleEllipsis));
+        assert_eq!(s.line_clamp, Some(LineClamp::None));
+    }
+
+    #[test]
+    fn scss_grid() {
+        use crate::style::grid::{GridAreas, GridPlacement, TrackList};
+        const S: Style = scss! {
+            display: grid;
+            grid-template-columns: 20 repeat(2, 1fr);
+            grid-template-areas: "head head head" "side main main";
+            grid-column: 2 / span 2;
+            grid-row: span 2;
+            grid-area: main;
+        };
+        let columns = TrackList::EMPTY
+            .with(Unit::cells(20))
+            .with(Unit::fill(1))
+            .with(Unit::fill(1));
+        let areas = GridAreas::parse(["head head head", "side main main"]);
+        assert_eq!(
+            S,
+            Style::new()
+                .with_display(Display::Grid)
+                .with_grid_template_columns(columns)
+                .with_grid_template_areas(areas.unwrap())
+                .with_grid_column(GridPlacement::at(2).with_span(2))
+                .with_grid_row(GridPlacement::span(2))
+                .with_grid_area("main".into())
+        );
+    }
+
+    #[test]
+    fn scss_positioning() {
+        use crate::style::border::Edges;
+        use crate::style::layout::{Position, ZIndex};
+        const S: Style = scss! {
+            position: absolute;
+            top: 100%;
+            left: -2;
+            z-index: -1;
+        };
+        assert_eq!(
+            S,
+            Style::new()
+                .with_position(Position::Absolute)
+                .with_inset(Edges::new(
+                    Unit::percent(100),
+                    Unit::Unset,
+                    Unit::Unset,
+                    Unit::cells(-2)
+                ))
+                .with_z_index(ZIndex::Index(Int::new(-1)))
+        );
+        let s = scss! { position: fixed; inset: 0; z-index: auto };
+        assert_eq!(s.inset, Some(Edges::all(Unit::ZERO)));
+        assert_eq!(s.z_index, Some(ZIndex::Auto));
+    }
+
+    #[test]
+    fn scss_leaves_undeclared_fields_unset() {
+        let s = scss! { color: red };
+        assert_eq!(s, Style::new().with_color(Color::Named(NamedColor::Red)));
+    }
+}
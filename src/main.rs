@@ -1,4 +1,28 @@
-fn main()
-{
-    println!("Hello, world!");
-}
\ No newline at end of file
+use oxidui::app::{App, Event, Flow, KeyCode};
+use oxidui::rsx;
+use oxidui::style::stylesheet::Stylesheet;
+
+const THEME: &str = "
+    Window { border: rounded cyan; padding: 1 2; display: flex; flex-direction: column; gap: 1; }
+    Title { color: bright-white; font-style: bold; text-align: center; }
+    Hint { color: bright-black; text-align: center; }
+";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let theme = Stylesheet::parse(THEME)?;
+    App::new()?.with_theme(theme).run(
+        || {
+            rsx! {
+                <Window>
+                    <Title>"OxidUi"</Title>
+                    <Hint>"press q or Esc to quit"</Hint>
+                </Window>
+            }
+        },
+        |event| match event {
+            Event::Key(key) if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) => Flow::Quit,
+            _ => Flow::Continue,
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,1688 @@
+//! Rendering — element tree in, terminal cells out.
+//!
+//! One frame runs four passes over the tree:
+//!
+//! 1. **Cascade.** Each element's stylesheet rules and inline style are
+//!    combined by [`Cascade`] and resolved against its parent into a
+//!    [`ComputedStyle`]. Text nodes take their parent's inherited text
+//!    styling, and its `text-overflow` and `line-clamp`. A grid item's
+//!    `grid-area` becomes the rows and columns of that area of its
+//!    parent's `grid-template-areas`.
+//! 2. **Layout.** The computed tree becomes a [`LayoutNode`] tree, text
+//!    nodes contributing their text, and [`layout`] places it. Text wraps
+//!    to the width of its box — see [`crate::text`].
+//! 3. **Scroll.** The content of each scroll container is shifted by its
+//!    offset from the [`ScrollState`] — see [`crate::scroll`]. Boxes whose
+//!    containing block is outside the container stay put.
+//! 4. **Paint.** Backgrounds, borders and text are written into a ratatui
+//!    [`Buffer`] in document order, so later siblings paint over earlier
+//!    ones. `overflow: hidden` and `scroll` clip descendants to the
+//!    element's content box. Colors are fitted to the terminal's
+//!    [`ColorDepth`] as they are written, so one theme works everywhere.
+//!
+//! # Stacking
+//!
+//! Positioned elements — popovers, tooltips, dropdowns — are painted
+//! after the flow they escape, so they cover later siblings too. Within a
+//! stacking context (the root, or a positioned element with a `z-index`)
+//! those with a negative `z-index` go first, beneath the flow; the rest
+//! follow by `z-index`, ties in document order. A context paints as a
+//! unit: nothing outside it lands between its descendants.
+//!
+//! A positioned box is opaque. It blanks the cells it covers before
+//! painting, so no stray glyph shows through, and as each frame is drawn
+//! afresh the cells come back once it is gone. An `absolute` box is
+//! clipped like its containing block, not its parent; a `fixed` one only
+//! by the area.
+//!
+//! # Opacity
+//!
+//! As in CSS, `opacity` fades an element together with its subtree, so
+//! nested opacities multiply. On a truecolor terminal each color is blended
+//! towards the background beneath the faded element, ancestor by ancestor.
+//! Blending needs real RGB on both sides; when the terminal can't show it,
+//! or the backdrop is the terminal's own default, faded elements are drawn
+//! `DIM` instead. An element at opacity 0 is not drawn at all.
+//!
+//! ```rust
+//! use oxidui::render::render_to_buffer;
+//! use oxidui::rsx;
+//! use oxidui::scroll::ScrollState;
+//! use oxidui::style::border::Charset;
+//! use oxidui::style::cascade::Cascade;
+//! use oxidui::style::color::ColorDepth;
+//! use ratatui::{buffer::Buffer, layout::Rect};
+//!
+//! let ui = rsx! { <Box><Text>"hi"</Text></Box> };
+//! let area = Rect::new(0, 0, 4, 1);
+//! let mut buf = Buffer::empty(area);
+//! render_to_buffer(
+//!     &mut buf,
+//!     area,
+//!     &ui,
+//!     &Cascade::new(),
+//!     ColorDepth::TrueColor,
+//!     Charset::Unicode,
+//!     &ScrollState::new(),
+//! );
+//! assert_eq!(buf, Buffer::with_lines(vec!["hi  "]));
+//! ```
+
+use oxidui_style::Style;
+
+use oxidui_style::border::{
+    BorderCollapse, BorderLabel, BorderStyle, Charset, Corner, Edges, Side, join,
+};
+use std::collections::HashSet;
+
+use oxidui_style::cascade::Cascade;
+use oxidui_style::color::{Color, ColorDepth};
+use oxidui_style::computed::ComputedStyle;
+use oxidui_style::font::FontStyle;
+use oxidui_style::grid::GridAreas;
+use oxidui_style::layout::{LineClamp, Overflow, Position as BoxPosition, TextAlign, TextOverflow};
+use oxidui_style::selector::PathElement;
+use oxidui_style::str::Str;
+use ratatui::Frame;
+use ratatui::buffer::Buffer;
+
+use crate::element::{Element, Node};
+use crate::layout::{Layout, LayoutNode, Rect, layout};
+use crate::scroll::{self, Key, Position, ScrollState};
+use crate::text;
+
+/// Render `root` into the whole frame, with colors fitted to `depth` and
+/// borders to `charset`. Scroll containers are scrolled as `scroll` says.
+pub fn render(
+    frame: &mut Frame<'_>,
+    root: &Element,
+    cascade: &Cascade<'_>,
+    depth: ColorDepth,
+    charset: Charset,
+    scroll: &ScrollState,
+) {
+    let area = frame.size();
+    render_to_buffer(
+        frame.buffer_mut(),
+        area,
+        root,
+        cascade,
+        depth,
+        charset,
+        scroll,
+    );
+}
+
+/// Render `root` into `area` of `buf`. Nothing outside `area` is touched,
+/// and every cell inside it is drawn afresh, so a buffer kept from the
+/// last frame shows what an overlay covered once the overlay is gone.
+///
+/// The scroll offsets in `scroll` are clamped to the content, moved to
+/// show the element [`ScrollState::scroll_into_view`] asked for, and
+/// kept for the next frame along with where each container was drawn.
+pub fn render_to_buffer(
+    buf: &mut Buffer,
+    area: ratatui::layout::Rect,
+    root: &Element,
+    cascade: &Cascade<'_>,
+    depth: ColorDepth,
+    charset: Charset,
+    scroll: &ScrollState,
+) {
+    let area = from_ratatui(area).intersection(from_ratatui(buf.area));
+    if let Some(area) = to_ratatui(area) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf.get_mut(x, y).reset();
+            }
+        }
+    }
+    let tree = compute(
+        root,
+        cascade,
+        &mut Vec::new(),
+        &mut Vec::new(),
+        &ComputedStyle::root(charset),
+        None,
+    );
+    let mut boxes = layout(&tree.layout_node(), area);
+    let mut scroll = scroll.state();
+    // Forget the containers gone from the tree, so one that takes a gone
+    // one's place starts at the top.
+    let mut present = HashSet::new();
+    scroll_keys(&tree, &mut present);
+    scroll.positions.retain(|key, _| present.contains(key));
+    let reveal = scroll.reveal.take();
+    scroll_boxes(
+        &tree,
+        &mut boxes,
+        &mut scroll,
+        reveal.as_ref().map(Str::as_str),
+    );
+    scroll.areas.clear();
+    let mut canvas = Canvas {
+        buf,
+        depth,
+        charset,
+        layers: Vec::new(),
+        scroll: &mut scroll,
+    };
+    let root = Stacked {
+        z: 0,
+        node: &tree,
+        boxes: &boxes,
+        behind: ComputedStyle::ROOT.background,
+        clips: Clips::new(area),
+        layers: Vec::new(),
+    };
+    canvas.paint(root, true);
+}
+
+// ---------------------------------------------------------------------------
+// Cascade
+// ---------------------------------------------------------------------------
+
+/// An element or text run with its computed style — the paint-side twin
+/// of [`LayoutNode`].
+struct Styled<'e> {
+    style: ComputedStyle,
+    /// Border title and footer. Labels are not inherited, so they come
+    /// straight from the cascaded style.
+    labels: [Option<BorderLabel>; 2],
+    /// How lines of text too long for the box are cut short. Owns its
+    /// marker, so it stays out of the `Copy` computed style too.
+    text_overflow: TextOverflow,
+    id: Option<&'e str>,
+    /// Set for a scroll container.
+    scroll: Option<Key>,
+    text: Option<&'e str>,
+    children: Vec<Styled<'e>>,
+}
+
+/// `at` holds the child indices from the root to `el`, which name a
+/// scroll container without an id. `areas` are the parent's named grid
+/// areas.
+fn compute<'e>(
+    el: &'e Element,
+    cascade: &Cascade<'_>,
+    path: &mut Vec<PathElement<'e>>,
+    at: &mut Vec<usize>,
+    parent: &ComputedStyle,
+    areas: Option<&GridAreas>,
+) -> Styled<'e> {
+    path.push(el.path_element());
+    let cascaded = cascade.cascade(path, &el.style);
+    let mut style = ComputedStyle::resolve(&cascaded, parent);
+    let area = cascaded.grid_area.as_ref().zip(areas);
+    if let Some((column, row)) = area.and_then(|(name, areas)| areas.area(name.as_str())) {
+        style.grid_column = column;
+        style.grid_row = row;
+    }
+    let scroll = (style.overflow == Overflow::Scroll).then(|| match &el.id {
+        Some(id) => Key::Id(id.clone()),
+        None => Key::Path(at.clone()),
+    });
+    let children = el
+        .children
+        .iter()
+        .enumerate()
+        .map(|(i, child)| match child {
+            Node::Element(child) => {
+                at.push(i);
+                let areas = cascaded.grid_template_areas.as_ref();
+                let styled = compute(child, cascade, path, at, &style, areas);
+                at.pop();
+                styled
+            }
+            // `line-clamp` and `text-overflow` aren't inherited, but
+            // apply to the element's own text.
+            Node::Text(text) => Styled {
+                style: ComputedStyle {
+                    line_clamp: style.line_clamp,
+                    ..ComputedStyle::resolve(&Style::new(), &style)
+                },
+                labels: [None, None],
+                text_overflow: cascaded.text_overflow.clone().unwrap_or_default(),
+                id: None,
+                scroll: None,
+                text: Some(text.as_str()),
+                children: Vec::new(),
+            },
+        })
+        .collect();
+    path.pop();
+    Styled {
+        style,
+        labels: [cascaded.border_title, cascaded.border_footer],
+        text_overflow: cascaded.text_overflow.unwrap_or_default(),
+        id: el.id.as_ref().map(Str::as_str),
+        scroll,
+        text: None,
+        children,
+    }
+}
+
+impl<'e> Styled<'e> {
+    fn layout_node(&self) -> LayoutNode<'e> {
+        let node = LayoutNode::new(self.style);
+        match self.text {
+            Some(text) => node.with_text(text),
+            None => node.with_children(self.children.iter().map(Styled::layout_node)),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Scroll
+// ---------------------------------------------------------------------------
+
+/// Shift the content of every scroll container in `node`'s subtree up by
+/// its offset, innermost first. The offsets are clamped to the content,
+/// and moved just enough to show the element with id `reveal`. Subtrees
+/// with `display: none` are skipped: nothing in them can scroll or be
+/// revealed.
+///
+/// Returns where the `reveal` element ends up, if it is in the subtree.
+fn scroll_boxes(
+    node: &Styled<'_>,
+    boxes: &mut Layout,
+    state: &mut scroll::State,
+    reveal: Option<&str>,
+) -> Option<Rect> {
+    if reveal.is_some() && node.id == reveal {
+        // Nothing inside matters once the target itself is found, but
+        // its own scroll containers still scroll.
+        for (child, child_boxes) in shown(node, boxes) {
+            scroll_boxes(child, child_boxes, state, None);
+        }
+        scroll_content(node, boxes, state, None);
+        return Some(boxes.rect);
+    }
+    let mut target = None;
+    for (child, child_boxes) in shown(node, boxes) {
+        target = target.or(scroll_boxes(child, child_boxes, state, reveal));
+    }
+    scroll_content(node, boxes, state, target)
+}
+
+/// Collect the key of every scroll container in `node`'s subtree,
+/// displayed or not.
+fn scroll_keys<'a>(node: &'a Styled<'_>, out: &mut HashSet<&'a Key>) {
+    out.extend(&node.scroll);
+    for child in &node.children {
+        scroll_keys(child, out);
+    }
+}
+
+/// The displayed children of `node`, with their boxes.
+fn shown<'a, 'e>(
+    node: &'a Styled<'e>,
+    boxes: &'a mut Layout,
+) -> impl Iterator<Item = (&'a Styled<'e>, &'a mut Layout)> {
+    node.children
+        .iter()
+        .zip(&mut boxes.children)
+        .filter(|(child, _)| child.style.is_displayed())
+}
+
+/// Scroll `node`'s content if it is a scroll container, bringing
+/// `target`, a box inside it, into view. Returns where `target` ends up.
+fn scroll_content(
+    node: &Styled<'_>,
+    boxes: &mut Layout,
+    state: &mut scroll::State,
+    target: Option<Rect>,
+) -> Option<Rect> {
+    let Some(key) = &node.scroll else {
+        return target;
+    };
+    let content = boxes.content;
+    let bottom = node
+        .children
+        .iter()
+        .zip(&boxes.children)
+        .filter(|(c, _)| !c.style.position.is_out_of_flow())
+        .map(|(_, c)| c.rect.bottom())
+        .max()
+        .unwrap_or(content.y);
+    let max = (bottom - content.bottom()).max(0);
+    let mut offset = state.offset(key);
+    if let Some(target) = target {
+        let top = target.y - content.y;
+        let bottom = target.bottom() - content.y;
+        if top < offset {
+            offset = top;
+        } else if bottom > offset + content.height {
+            // A box taller than the view shows its top.
+            offset = (bottom - content.height).min(top);
+        }
+    }
+    let offset = offset.clamp(0, max);
+    state.positions.insert(
+        key.clone(),
+        Position {
+            offset,
+            max,
+            page: content.height,
+        },
+    );
+    for child in &mut boxes.children {
+        child.translate(0, -offset);
+    }
+    pin(node, boxes, offset, node.style.position.is_positioned());
+    target.map(|r| r.translate(0, -offset))
+}
+
+/// Move back the boxes in `node`'s subtree that a scroll container
+/// around it shifted by `offset`, but whose containing block is outside
+/// it: `fixed` ones, and `absolute` ones unless `anchored` by a
+/// positioned element inside.
+fn pin(node: &Styled<'_>, boxes: &mut Layout, offset: i32, anchored: bool) {
+    for (child, child_boxes) in node.children.iter().zip(&mut boxes.children) {
+        let escapes = match child.style.position {
+            BoxPosition::Fixed => true,
+            BoxPosition::Absolute => !anchored,
+            BoxPosition::Static | BoxPosition::Relative => false,
+        };
+        if escapes {
+            child_boxes.translate(0, offset);
+        } else {
+            let anchored = anchored || child.style.position.is_positioned();
+            pin(child, child_boxes, offset, anchored);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Paint
+// ---------------------------------------------------------------------------
+
+/// The target buffer and the colors it can show.
+struct Canvas<'b> {
+    buf: &'b mut Buffer,
+    depth: ColorDepth,
+    charset: Charset,
+    /// One entry per translucent ancestor, outermost first.
+    layers: Vec<Layer>,
+    /// Scroll positions, and where each container is drawn.
+    scroll: &'b mut scroll::State,
+}
+
+/// A translucent element: its opacity and the color beneath it.
+#[derive(Clone, Copy)]
+struct Layer {
+    alpha: f32,
+    backdrop: Color,
+}
+
+/// A positioned element waiting for its turn to paint, with what it
+/// inherits from the elements around it.
+struct Stacked<'a, 'e> {
+    z: i32,
+    node: &'a Styled<'e>,
+    boxes: &'a Layout,
+    /// The background beneath it.
+    behind: Color,
+    clips: Clips,
+    /// The translucent elements around it.
+    layers: Vec<Layer>,
+}
+
+/// Where an element and its descendants are clipped.
+#[derive(Clone, Copy)]
+struct Clips {
+    /// The element itself and its in-flow descendants.
+    flow: Rect,
+    /// `absolute` descendants: the clip of their containing block.
+    absolute: Rect,
+    /// `fixed` descendants.
+    fixed: Rect,
+}
+
+impl Clips {
+    fn new(area: Rect) -> Self {
+        Self {
+            flow: area,
+            absolute: area,
+            fixed: area,
+        }
+    }
+
+    /// The clips inside an element with `style` and a `content` box.
+    fn inside(self, style: &ComputedStyle, content: Rect) -> Self {
+        let flow = match style.overflow {
+            Overflow::Visible => self.flow,
+            Overflow::Hidden | Overflow::Scroll => self.flow.intersection(content),
+        };
+        Self {
+            flow,
+            absolute: if style.position.is_positioned() {
+                flow
+            } else {
+                self.absolute
+            },
+            fixed: self.fixed,
+        }
+    }
+
+    /// The clips of a child placed by `position`.
+    fn child(self, position: BoxPosition) -> Self {
+        let flow = match position {
+            BoxPosition::Static | BoxPosition::Relative => self.flow,
+            BoxPosition::Absolute => self.absolute,
+            BoxPosition::Fixed => self.fixed,
+        };
+        Self { flow, ..self }
+    }
+}
+
+/// `false` for an element that draws nothing, subtree and all.
+fn is_painted(node: &Styled<'_>) -> bool {
+    node.style.is_displayed() && node.style.opacity.get() > 0.0
+}
+
+impl Canvas<'_> {
+    /// Paint `entry` and its flow. A stacking context — the root, or a
+    /// positioned element with a `z_index` — also paints its positioned
+    /// descendants: those below zero after its own box, the rest after
+    /// its flow, by `z_index` with ties in document order.
+    fn paint(&mut self, entry: Stacked<'_, '_>, context: bool) {
+        let Stacked {
+            node,
+            boxes,
+            behind,
+            clips,
+            layers,
+            ..
+        } = entry;
+        if !is_painted(node) {
+            return;
+        }
+        let outer = std::mem::replace(&mut self.layers, layers);
+        let translucent = self.enter(node, behind);
+        let inner = clips.inside(&node.style, boxes.content);
+        let mut stack = Vec::new();
+        if context {
+            for (child, child_boxes) in node.children.iter().zip(&boxes.children) {
+                let clips = inner.child(child.style.position);
+                self.stack(child, child_boxes, node.style.background, clips, &mut stack);
+            }
+            stack.sort_by_key(|s| s.z);
+        }
+        let above = stack.partition_point(|s| s.z < 0);
+        let above = stack.split_off(above);
+
+        let opaque = node.style.position.is_positioned();
+        self.paint_box(node, boxes, behind, clips.flow, opaque);
+        for entry in stack {
+            self.paint_stacked(entry);
+        }
+        for (child, child_boxes) in node.children.iter().zip(&boxes.children) {
+            self.paint_flow(child, child_boxes, node.style.background, inner);
+        }
+        for entry in above {
+            self.paint_stacked(entry);
+        }
+        if translucent {
+            self.layers.pop();
+        }
+        self.layers = outer;
+    }
+
+    fn paint_stacked(&mut self, entry: Stacked<'_, '_>) {
+        let context = entry.node.style.z_index.is_context();
+        self.paint(entry, context);
+    }
+
+    /// Paint `node` and its in-flow descendants in document order, so
+    /// later siblings paint over earlier ones. Positioned elements are
+    /// left to their stacking context.
+    fn paint_flow(&mut self, node: &Styled<'_>, boxes: &Layout, behind: Color, clips: Clips) {
+        if !is_painted(node) || node.style.position.is_positioned() {
+            return;
+        }
+        let translucent = self.enter(node, behind);
+        self.paint_box(node, boxes, behind, clips.flow, false);
+        let inner = clips.inside(&node.style, boxes.content);
+        for (child, child_boxes) in node.children.iter().zip(&boxes.children) {
+            self.paint_flow(child, child_boxes, node.style.background, inner);
+        }
+        if translucent {
+            self.layers.pop();
+        }
+    }
+
+    /// Collect the positioned elements in `node`'s subtree that belong to
+    /// the stacking context being painted. One with a `z_index` is a
+    /// context of its own, and keeps its descendants.
+    fn stack<'a, 'e>(
+        &mut self,
+        node: &'a Styled<'e>,
+        boxes: &'a Layout,
+        behind: Color,
+        clips: Clips,
+        out: &mut Vec<Stacked<'a, 'e>>,
+    ) {
+        if !is_painted(node) {
+            return;
+        }
+        if node.style.position.is_positioned() {
+            out.push(Stacked {
+                z: node.style.z_index.level(),
+                node,
+                boxes,
+                behind,
+                clips,
+                layers: self.layers.clone(),
+            });
+            if node.style.z_index.is_context() {
+                return;
+            }
+        }
+        let translucent = self.enter(node, behind);
+        let inner = clips.inside(&node.style, boxes.content);
+        for (child, child_boxes) in node.children.iter().zip(&boxes.children) {
+            let clips = inner.child(child.style.position);
+            self.stack(child, child_boxes, node.style.background, clips, out);
+        }
+        if translucent {
+            self.layers.pop();
+        }
+    }
+
+    /// Push a layer if `node` is translucent. Returns `true` if it did.
+    fn enter(&mut self, node: &Styled<'_>, behind: Color) -> bool {
+        let opacity = node.style.opacity.get();
+        let translucent = opacity < 1.0;
+        if translucent {
+            self.layers.push(Layer {
+                alpha: opacity,
+                backdrop: behind,
+            });
+        }
+        translucent
+    }
+
+    /// Paint `node`'s own box: background, border, scrollbar and text.
+    /// An `opaque` box blanks the cells it covers first, so nothing
+    /// painted beneath shows through.
+    fn paint_box(
+        &mut self,
+        node: &Styled<'_>,
+        boxes: &Layout,
+        behind: Color,
+        clip: Rect,
+        opaque: bool,
+    ) {
+        let (style, dim) = self.composite(node.style);
+
+        // The computed background already falls back to the parent's, so
+        // only a change needs painting.
+        let area = boxes.rect.intersection(clip);
+        if opaque {
+            self.clear(area, style.background);
+        } else if node.style.background != behind
+            && let Some(area) = to_ratatui(area)
+        {
+            self.buf.set_style(
+                area,
+                ratatui::style::Style::new().bg(style.background.to_ratatui()),
+            );
+        }
+        if style.has_border() {
+            self.paint_border(&style, dim, boxes.rect, clip);
+            self.paint_labels(node, boxes.rect, clip);
+        }
+        if let Some(key) = &node.scroll {
+            self.scroll.areas.push((key.clone(), area));
+            if let Some(&position) = self.scroll.positions.get(key) {
+                self.paint_scrollbar(node, position, boxes.rect, clip);
+            }
+        }
+        if let Some(text) = node.text {
+            self.paint_text(&style, &node.text_overflow, text, boxes.content, clip);
+        }
+    }
+
+    /// Blank every cell of `area` in `background`. A wide glyph cut in
+    /// half by its left edge is blanked too, or it would spill into it.
+    fn clear(&mut self, area: Rect, background: Color) {
+        for y in area.y..area.bottom() {
+            if let Some(cell) = self.cell(area.x - 1, y)
+                && text::width(cell.symbol()) > 1
+            {
+                cell.set_symbol(" ");
+            }
+            for x in area.x..area.right() {
+                if let Some(cell) = self.cell(x, y) {
+                    cell.reset();
+                    cell.set_bg(background.to_ratatui());
+                }
+            }
+        }
+    }
+
+    /// The style as it will actually be drawn: faded by every translucent
+    /// ancestor, then fitted to the terminal. The flag is `true` when
+    /// fading fell back to `DIM`.
+    fn composite(&self, mut style: ComputedStyle) -> (ComputedStyle, bool) {
+        if self.layers.is_empty() {
+            return (style.downsample(self.depth), false);
+        }
+        match self.fade(style) {
+            Some(faded) => (faded.downsample(self.depth), false),
+            None => {
+                style.font_style |= FontStyle::DIM;
+                (style.downsample(self.depth), true)
+            }
+        }
+    }
+
+    /// Blend every color of `style`, or `None` if that isn't possible.
+    fn fade(&self, mut style: ComputedStyle) -> Option<ComputedStyle> {
+        if self.depth != ColorDepth::TrueColor {
+            return None;
+        }
+        style.color = self.blend(style.color)?;
+        style.background = self.blend(style.background)?;
+        let border = style.border_color;
+        style.border_color = Edges::new(
+            self.blend(border.top)?,
+            self.blend(border.right)?,
+            self.blend(border.bottom)?,
+            self.blend(border.left)?,
+        );
+        // `None` follows the text color, which is already faded.
+        if style.underline_color != Color::None {
+            style.underline_color = self.blend(style.underline_color)?;
+        }
+        Some(style)
+    }
+
+    /// `color` faded through each layer, innermost first. `None` if any
+    /// step lacks an RGB value to blend.
+    fn blend(&self, color: Color) -> Option<Color> {
+        self.layers.iter().rev().try_fold(color, |c, layer| {
+            c.to_rgb()?;
+            layer.backdrop.to_rgb()?;
+            Some(layer.backdrop.mix(c, layer.alpha))
+        })
+    }
+
+    /// Each side is drawn in its own style and color. Where two sides
+    /// meet, the corner joins them — see [`BorderStyle::corner`] — in the
+    /// horizontal side's color; where only one is drawn, its line runs on
+    /// to the edge.
+    ///
+    /// Under `border-collapse: collapse` each glyph is [`join`]ed with the
+    /// line already in its cell, so shared lines meet in junctions.
+    fn paint_border(&mut self, style: &ComputedStyle, dim: bool, rect: Rect, clip: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+        let sides = style.border_style.map(|s| s.for_charset(self.charset));
+        let colors = style.border_color.map(|color| {
+            let glyph_style = ratatui::style::Style::new()
+                .fg(color.to_ratatui())
+                .bg(style.background.to_ratatui());
+            if dim {
+                glyph_style.add_modifier(ratatui::style::Modifier::DIM)
+            } else {
+                glyph_style
+            }
+        });
+        let collapse = style.border_collapse == BorderCollapse::Collapse;
+        let (right, bottom) = (rect.right() - 1, rect.bottom() - 1);
+        let mut put = |x: i32, y: i32, symbol: &str, glyph_style| {
+            if clip.contains(x, y)
+                && let Some(cell) = self.cell(x, y)
+            {
+                let joined = collapse.then(|| join(cell.symbol(), symbol)).flatten();
+                cell.set_symbol(joined.unwrap_or(symbol))
+                    .set_style(glyph_style);
+            }
+        };
+
+        let line = |s: BorderStyle, vertical: bool| {
+            s.glyphs()
+                .map(|g| if vertical { g.vertical } else { g.horizontal })
+        };
+        let lines = Edges::new(
+            line(sides.top, false),
+            line(sides.right, true),
+            line(sides.bottom, false),
+            line(sides.left, true),
+        );
+        for x in rect.x + 1..right {
+            if let Some(line) = lines.top {
+                put(x, rect.y, line, colors.top);
+            }
+            if let Some(line) = lines.bottom {
+                put(x, bottom, line, colors.bottom);
+            }
+        }
+        for y in rect.y + 1..bottom {
+            if let Some(line) = lines.left {
+                put(rect.x, y, line, colors.left);
+            }
+            if let Some(line) = lines.right {
+                put(right, y, line, colors.right);
+            }
+        }
+
+        for (x, y, corner, horizontal, vertical) in [
+            (rect.x, rect.y, Corner::TopLeft, Side::Top, Side::Left),
+            (right, rect.y, Corner::TopRight, Side::Top, Side::Right),
+            (
+                right,
+                bottom,
+                Corner::BottomRight,
+                Side::Bottom,
+                Side::Right,
+            ),
+            (rect.x, bottom, Corner::BottomLeft, Side::Bottom, Side::Left),
+        ] {
+            let (h, v) = (sides.get(horizontal), sides.get(vertical));
+            let glyph = match (
+                h.corner(v, corner),
+                lines.get(horizontal),
+                lines.get(vertical),
+            ) {
+                (Some(joined), _, _) => Some((joined, colors.get(horizontal))),
+                (None, Some(line), _) => Some((line, colors.get(horizontal))),
+                (None, None, Some(line)) => Some((line, colors.get(vertical))),
+                (None, None, None) => None,
+            };
+            if let Some((symbol, glyph_style)) = glyph {
+                put(x, y, symbol, glyph_style);
+            }
+        }
+    }
+
+    /// The title and footer, between the corners of the top and bottom
+    /// edges. Each needs a border on its edge, and is cut short with an
+    /// ellipsis if it doesn't fit.
+    fn paint_labels(&mut self, node: &Styled<'_>, rect: Rect, clip: Rect) {
+        let [title, footer] = &node.labels;
+        for (label, side, y) in [
+            (title, Side::Top, rect.y),
+            (footer, Side::Bottom, rect.bottom() - 1),
+        ] {
+            let Some(label) = label else { continue };
+            let text = label.text.as_str().lines().next().unwrap_or_default();
+            let span = Rect::new(rect.x + 1, y, rect.width - 2, 1);
+            if text.is_empty()
+                || span.is_empty()
+                || node.style.border_style.get(side) == BorderStyle::None
+            {
+                continue;
+            }
+            let (style, _) = self.composite(ComputedStyle {
+                color: match label.color {
+                    None | Some(Color::Inherit) => node.style.border_color.get(side),
+                    Some(c) => c,
+                },
+                font_style: label.font_style.unwrap_or(node.style.font_style),
+                text_align: label.align,
+                ..node.style
+            });
+            let text = text::truncate(text, span.width, self.ellipsis());
+            let x = span.x + offset(style.text_align, span.width - text::width(&text));
+            self.put_str(x, y, &text, style.to_ratatui(), clip.intersection(span));
+        }
+    }
+
+    /// The scrollbar, in the column left of the right border: a track the
+    /// height of the box inside its border, and a thumb as much shorter
+    /// as the view is than the content, placed where the view is. With
+    /// nothing to scroll there is no thumb.
+    fn paint_scrollbar(&mut self, node: &Styled<'_>, position: Position, rect: Rect, clip: Rect) {
+        let border = node.style.border_widths();
+        let track = Rect::new(
+            rect.right() - border.right - 1,
+            rect.y + border.top,
+            1,
+            rect.height - border.vertical_sum(),
+        );
+        if track.is_empty() || track.x < rect.x + border.left {
+            return;
+        }
+        let thumb = match position.max {
+            0 => 0..0,
+            max => {
+                let content = position.page + max;
+                let len =
+                    ((track.height * position.page + content / 2) / content).clamp(1, track.height);
+                let start = ((track.height - len) * position.offset + max / 2) / max;
+                start..start + len
+            }
+        };
+        let [thumb_part, track_part] = [node.style.scrollbar_thumb, node.style.scrollbar_track]
+            .map(|part| {
+                let (style, _) = self.composite(ComputedStyle {
+                    color: part.color,
+                    ..node.style
+                });
+                (part.glyph, style.to_ratatui())
+            });
+        for row in 0..track.height {
+            let y = track.y + row;
+            let (glyph, style) = if thumb.contains(&row) {
+                thumb_part
+            } else {
+                track_part
+            };
+            if clip.contains(track.x, y)
+                && let Some(cell) = self.cell(track.x, y)
+            {
+                cell.set_char(glyph).set_style(style);
+            }
+        }
+    }
+
+    /// Text wraps to the content box's width, unless `white-space` says
+    /// otherwise, and is placed in it. A line too long for the box is cut
+    /// short as `text-overflow` says, and so is the last line `line-clamp`
+    /// keeps if it dropped any. Beyond that, as in CSS, only `clip` cuts
+    /// text off: a word too long for its box overflows a visible one.
+    fn paint_text(
+        &mut self,
+        style: &ComputedStyle,
+        overflow: &TextOverflow,
+        text: &str,
+        content: Rect,
+        clip: Rect,
+    ) {
+        let text_style = style.to_ratatui();
+        let text = text::collapse(text, style.white_space);
+        let width = if style.white_space.wraps() {
+            content.width
+        } else {
+            0
+        };
+        let mut lines = text::wrap(&text, width, style.word_break);
+        let kept = match style.line_clamp {
+            LineClamp::Lines(n) => n.get() as usize,
+            LineClamp::None => lines.len(),
+        };
+        let clamped = lines.len() > kept;
+        lines.truncate(kept);
+        let count = lines.len();
+        for (row, line) in lines.into_iter().enumerate() {
+            let shortened = self.shorten(
+                line.text,
+                overflow,
+                content.width,
+                clamped && row + 1 == count,
+            );
+            let line = match &shortened {
+                Some(text) => text::Line {
+                    text,
+                    width: text::width(text),
+                    last: true,
+                },
+                None => line,
+            };
+            let y = content.y + row as i32;
+            if y < clip.y {
+                continue;
+            }
+            if y >= clip.bottom() {
+                break;
+            }
+            let pieces = match style.text_align {
+                TextAlign::Justify => text::justify(line, content.width),
+                align => vec![(offset(align, content.width - line.width), line.text)],
+            };
+            for (dx, piece) in pieces {
+                self.put_str(content.x + dx, y, piece, text_style, clip);
+            }
+        }
+    }
+
+    /// `line` cut to `width` columns as `overflow` says, or `None` if it
+    /// is drawn as is. A clamped line is marked even if it fits; the middle
+    /// of one is never dropped, as the text goes on past its end.
+    fn shorten(
+        &self,
+        line: &str,
+        overflow: &TextOverflow,
+        width: i32,
+        clamped: bool,
+    ) -> Option<String> {
+        let marker = match overflow {
+            TextOverflow::Clip => return None,
+            TextOverflow::Ellipsis | TextOverflow::MiddleEllipsis => self.ellipsis(),
+            TextOverflow::Marker(marker) => marker.as_str(),
+        };
+        if clamped {
+            Some(text::cut(line, width, marker))
+        } else if text::width(line) > width {
+            Some(
+                match overflow {
+                    TextOverflow::MiddleEllipsis => text::truncate_middle(line, width, marker),
+                    _ => text::truncate(line, width, marker),
+                }
+                .into_owned(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// The ellipsis the terminal can show.
+    fn ellipsis(&self) -> &'static str {
+        match self.charset {
+            Charset::Unicode => "…",
+            Charset::Ascii => "...",
+        }
+    }
+
+    /// Write `s` on row `y` from column `x`, dropping whatever falls
+    /// outside `clip` — including a wide glyph that straddles its left
+    /// edge.
+    fn put_str(&mut self, mut x: i32, y: i32, s: &str, style: ratatui::style::Style, clip: Rect) {
+        if y < clip.y || y >= clip.bottom() {
+            return;
+        }
+        let mut rest = s;
+        for (i, _, w) in text::graphemes(s) {
+            if x >= clip.x {
+                rest = &s[i..];
+                break;
+            }
+            x += w;
+            rest = "";
+        }
+        if rest.is_empty() || x >= clip.right() {
+            return;
+        }
+        self.buf
+            .set_stringn(x as u16, y as u16, rest, (clip.right() - x) as usize, style);
+    }
+
+    /// The cell at `(x, y)`, if it is inside the buffer.
+    fn cell(&mut self, x: i32, y: i32) -> Option<&mut ratatui::buffer::Cell> {
+        if !from_ratatui(self.buf.area).contains(x, y) {
+            return None;
+        }
+        Some(self.buf.get_mut(x as u16, y as u16))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Geometry
+// ---------------------------------------------------------------------------
+
+fn from_ratatui(r: ratatui::layout::Rect) -> Rect {
+    Rect::new(
+        i32::from(r.x),
+        i32::from(r.y),
+        i32::from(r.width),
+        i32::from(r.height),
+    )
+}
+
+/// Where a line `free` columns narrower than its box starts. A line wider
+/// than its box starts at the left edge whatever the alignment.
+fn offset(align: TextAlign, free: i32) -> i32 {
+    let free = free.max(0);
+    match align {
+        TextAlign::Left | TextAlign::Justify => 0,
+        TextAlign::Center => free / 2,
+        TextAlign::Right => free,
+    }
+}
+
+/// `None` if `r` is empty or doesn't fit ratatui's unsigned coordinates.
+fn to_ratatui(r: Rect) -> Option<ratatui::layout::Rect> {
+    if r.is_empty() {
+        return None;
+    }
+    Some(ratatui::layout::Rect::new(
+        u16::try_from(r.x).ok()?,
+        u16::try_from(r.y).ok()?,
+        u16::try_from(r.width).ok()?,
+        u16::try_from(r.height).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rsx;
+    use oxidui_style::cascade::Origin;
+    use oxidui_style::color::NamedColor;
+    use oxidui_style::stylesheet::Stylesheet;
+
+    fn draw(ui: &Element, sheet: &str, width: u16, height: u16) -> Buffer {
+        draw_scrolled(ui, sheet, width, height, &ScrollState::new())
+    }
+
+    fn draw_scrolled(
+        ui: &Element,
+        sheet: &str,
+        width: u16,
+        height: u16,
+        scroll: &ScrollState,
+    ) -> Buffer {
+        let mut buf = Buffer::empty(ratatui::layout::Rect::new(0, 0, width, height));
+        draw_into(&mut buf, ui, sheet, scroll);
+        buf
+    }
+
+    fn draw_into(buf: &mut Buffer, ui: &Element, sheet: &str, scroll: &ScrollState) {
+        let sheet = Stylesheet::parse(sheet).unwrap();
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &sheet);
+        let area = buf.area;
+        render_to_buffer(
+            buf,
+            area,
+            ui,
+            &cascade,
+            ColorDepth::TrueColor,
+            Charset::Unicode,
+            scroll,
+        );
+    }
+
+    fn lines(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf.get(x, y).symbol())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paints_borders_and_text() {
+        let ui = rsx! { <Panel><Text>"hello"</Text></Panel> };
+        let buf = draw(&ui, "Panel { border: rounded; padding: 0 1; }", 9, 3);
+        assert_eq!(lines(&buf), ["╭───────╮", "│ hello │", "╰───────╯"]);
+    }
+
+    #[test]
+    fn single_border_sides() {
+        let ui = rsx! { <Header>"title"</Header> };
+        let buf = draw(&ui, "Header { border-bottom: solid; }", 6, 2);
+        assert_eq!(lines(&buf), ["title ", "──────"]);
+
+        let ui = rsx! { <Side>"ab"</Side> };
+        let buf = draw(&ui, "Side { border-left: thick red; height: 2; }", 3, 2);
+        assert_eq!(lines(&buf), ["┃ab", "┃  "]);
+        assert_eq!(buf.get(0, 1).fg, ratatui::style::Color::Red);
+    }
+
+    #[test]
+    fn mixed_sides_join_at_the_corners() {
+        let ui = rsx! { <Box>"x"</Box> };
+        let buf = draw(
+            &ui,
+            "Box { border: solid; border-top: double; border-right: none; }",
+            3,
+            3,
+        );
+        assert_eq!(lines(&buf), ["╒══", "│x ", "└──"]);
+
+        let buf = draw(&ui, "Box { border: rounded; border-bottom: thick; }", 3, 3);
+        assert_eq!(lines(&buf), ["╭─╮", "│x│", "┕━┙"]);
+    }
+
+    #[test]
+    fn border_titles_and_footers() {
+        let ui = rsx! { <Pane /> };
+        let buf = draw(
+            &ui,
+            r#"Pane {
+                border: solid;
+                border-title: "Files" center;
+                border-footer: "3 items" right red bold;
+            }"#,
+            11,
+            3,
+        );
+        assert_eq!(lines(&buf), ["┌──Files──┐", "│         │", "└──3 items┘"]);
+        let cell = buf.get(9, 2);
+        assert_eq!(cell.fg, ratatui::style::Color::Red);
+        assert!(cell.modifier.contains(ratatui::style::Modifier::BOLD));
+        assert!(
+            !buf.get(1, 0)
+                .modifier
+                .contains(ratatui::style::Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn long_titles_get_an_ellipsis() {
+        let ui = rsx! { <Pane /> };
+        let sheet = r#"Pane { border: rounded; border-title: "Downloads"; }"#;
+        let buf = draw(&ui, sheet, 7, 2);
+        assert_eq!(lines(&buf), ["╭Down…╮", "╰─────╯"]);
+
+        // No top border, no title.
+        let sheet = r#"Pane { border-bottom: solid; border-title: "Downloads"; }"#;
+        let buf = draw(&ui, sheet, 7, 2);
+        assert_eq!(lines(&buf), ["       ", "───────"]);
+    }
+
+    #[test]
+    fn collapsed_borders_share_lines() {
+        let ui = rsx! {
+            <Table>
+                <Cell>"a"</Cell>
+                <Cell>"b"</Cell>
+            </Table>
+        };
+        let sheet = "Table { display: flex; border: solid; border-collapse: collapse; }
+                     Cell { border: solid; flex-grow: 1; }";
+        let buf = draw(&ui, sheet, 7, 3);
+        assert_eq!(lines(&buf), ["┌──┬──┐", "│a │b │", "└──┴──┘"]);
+
+        let separate = sheet.replace("collapse;", "separate;");
+        let buf = draw(&ui, &separate, 8, 5);
+        assert_eq!(lines(&buf)[2], "││a││b││");
+    }
+
+    #[test]
+    fn collapsed_junctions_mix_styles() {
+        let ui = rsx! {
+            <Table>
+                <Row><Cell>"a"</Cell><Cell>"b"</Cell></Row>
+                <Row><Cell>"c"</Cell><Cell>"d"</Cell></Row>
+            </Table>
+        };
+        let sheet = "Table { border: double; border-collapse: collapse; }
+                     Row { display: flex; border: solid; }
+                     Cell { border: solid; flex-grow: 1; }";
+        let buf = draw(&ui, sheet, 7, 5);
+        assert_eq!(
+            lines(&buf),
+            ["╔══╤══╗", "║a │b ║", "╟──┼──╢", "║c │d ║", "╚══╧══╝"]
+        );
+    }
+
+    #[test]
+    fn every_border_style_has_glyphs() {
+        let ui = rsx! { <Box /> };
+        for (style, expected) in [
+            ("double", ["╔═╗", "╚═╝"]),
+            ("thick", ["┏━┓", "┗━┛"]),
+            ("dashed", ["┌╌┐", "└╌┘"]),
+            ("ascii", ["+-+", "+-+"]),
+        ] {
+            let buf = draw(&ui, &format!("Box {{ border: {style}; }}"), 3, 2);
+            assert_eq!(lines(&buf), expected, "{style}");
+        }
+    }
+
+    #[test]
+    fn ascii_charset_replaces_box_drawing() {
+        let sheet = Stylesheet::parse("Box { border: rounded; border-left: double; }").unwrap();
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &sheet);
+        let area = ratatui::layout::Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let ui = rsx! { <Box>"ab"</Box> };
+        render_to_buffer(
+            &mut buf,
+            area,
+            &ui,
+            &cascade,
+            ColorDepth::TrueColor,
+            Charset::Ascii,
+            &ScrollState::new(),
+        );
+        assert_eq!(lines(&buf), ["+--+", "|ab|", "+--+"]);
+    }
+
+    #[test]
+    fn registered_glyphs_from_stylesheets() {
+        use oxidui_style::border::BorderGlyphs;
+        static DOTS: BorderGlyphs = BorderGlyphs {
+            horizontal: "·",
+            vertical: ":",
+            ..BorderGlyphs::ASCII
+        };
+        BorderGlyphs::register("render-test-dots", &DOTS);
+        let ui = rsx! { <Box /> };
+        let buf = draw(&ui, "Box { border: render-test-dots; }", 3, 3);
+        assert_eq!(lines(&buf), ["+·+", ": :", "+·+"]);
+    }
+
+    #[test]
+    fn stylesheet_rules_reach_text() {
+        let ui = rsx! { <Box class="warn"><Text>"!"</Text></Box> };
+        let buf = draw(&ui, ".warn { color: red; font-style: bold; }", 2, 1);
+        let cell = buf.get(0, 0);
+        assert_eq!(cell.fg, ratatui::style::Color::Red);
+        assert!(cell.modifier.contains(ratatui::style::Modifier::BOLD));
+    }
+
+    #[test]
+    fn flex_row_and_alignment() {
+        let ui = rsx! {
+            <Row>
+                <Cell>"ab"</Cell>
+                <Cell class="end">"c"</Cell>
+            </Row>
+        };
+        let buf = draw(
+            &ui,
+            "Row { display: flex; } Cell { flex-grow: 1; } .end { text-align: right; }",
+            8,
+            1,
+        );
+        assert_eq!(lines(&buf), ["ab     c"]);
+    }
+
+    #[test]
+    fn backgrounds_fill_boxes() {
+        let ui = rsx! { <Box><Inner>"x"</Inner></Box> };
+        let buf = draw(
+            &ui,
+            "Box { background: blue; } Inner { width: 2; background: red; }",
+            3,
+            1,
+        );
+        let bg = |x| buf.get(x, 0).bg;
+        assert_eq!(
+            [bg(0), bg(1), bg(2)],
+            [NamedColor::Red, NamedColor::Red, NamedColor::Blue]
+                .map(|c| Color::Named(c).to_ratatui())
+        );
+    }
+
+    #[test]
+    fn colors_fit_the_terminal() {
+        let ui = rsx! { <Box>"x"</Box> };
+        let sheet = Stylesheet::parse("Box { color: #ff0000; background: #0000ee; }").unwrap();
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &sheet);
+        let area = ratatui::layout::Rect::new(0, 0, 1, 1);
+        let cell = |depth| {
+            let mut buf = Buffer::empty(area);
+            render_to_buffer(
+                &mut buf,
+                area,
+                &ui,
+                &cascade,
+                depth,
+                Charset::Unicode,
+                &ScrollState::new(),
+            );
+            let cell = buf.get(0, 0);
+            (cell.fg, cell.bg)
+        };
+        use ratatui::style::Color as R;
+        assert_eq!(
+            cell(ColorDepth::TrueColor),
+            (R::Rgb(255, 0, 0), R::Rgb(0, 0, 238))
+        );
+        assert_eq!(cell(ColorDepth::Ansi256), (R::Indexed(196), R::Indexed(21)));
+        assert_eq!(cell(ColorDepth::Ansi16), (R::LightRed, R::Blue));
+        assert_eq!(cell(ColorDepth::Monochrome), (R::Reset, R::Reset));
+    }
+
+    fn cell_at(ui: &Element, sheet: &str, depth: ColorDepth) -> ratatui::buffer::Cell {
+        let sheet = Stylesheet::parse(sheet).unwrap();
+        let cascade = Cascade::new().with_stylesheet(Origin::Theme, &sheet);
+        let area = ratatui::layout::Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        render_to_buffer(
+            &mut buf,
+            area,
+            ui,
+            &cascade,
+            depth,
+            Charset::Unicode,
+            &ScrollState::new(),
+        );
+        buf.get(0, 0).clone()
+    }
+
+    #[test]
+    fn opacity_blends_with_the_backdrop() {
+        use ratatui::style::Color as R;
+        let ui = rsx! { <Box><Faded>"x"</Faded></Box> };
+        let sheet = "Box { background: #000000; }
+                     Faded { opacity: 0.5; color: #ffffff; background: #ff0000; }";
+        let cell = cell_at(&ui, sheet, ColorDepth::TrueColor);
+        assert_eq!(
+            (cell.fg, cell.bg),
+            (R::Rgb(128, 128, 128), R::Rgb(128, 0, 0))
+        );
+        assert!(!cell.modifier.contains(ratatui::style::Modifier::DIM));
+    }
+
+    #[test]
+    fn nested_opacities_multiply() {
+        use ratatui::style::Color as R;
+        let ui = rsx! { <Box><Outer><Inner>"x"</Inner></Outer></Box> };
+        let sheet = "Box { background: #000000; color: #ffffff; }
+                     Outer { opacity: 0.5; } Inner { opacity: 0.5; }";
+        let cell = cell_at(&ui, sheet, ColorDepth::TrueColor);
+        assert_eq!((cell.fg, cell.bg), (R::Rgb(64, 64, 64), R::Rgb(0, 0, 0)));
+    }
+
+    #[test]
+    fn opacity_falls_back_to_dim() {
+        use ratatui::style::{Color as R, Modifier};
+        let ui = rsx! { <Box><Faded>"x"</Faded></Box> };
+        let sheet = "Box { background: #000000; } Faded { opacity: 0.5; color: #ff0000; }";
+        let cell = cell_at(&ui, sheet, ColorDepth::Ansi256);
+        assert_eq!(cell.fg, R::Indexed(196));
+        assert!(cell.modifier.contains(Modifier::DIM));
+
+        // Truecolor, but over the terminal's default background.
+        let cell = cell_at(
+            &ui,
+            "Faded { opacity: 0.5; color: #ff0000; }",
+            ColorDepth::TrueColor,
+        );
+        assert_eq!(cell.fg, R::Rgb(255, 0, 0));
+        assert!(cell.modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn zero_opacity_hides_the_subtree() {
+        let ui = rsx! { <Box><Faded><Text>"x"</Text></Faded></Box> };
+        let cell = cell_at(&ui, "Faded { opacity: 0; }", ColorDepth::TrueColor);
+        assert_eq!(cell.symbol(), " ");
+    }
+
+    #[test]
+    fn overflow_hidden_clips_descendants() {
+        let ui = rsx! { <Box><Text>"overflowing"</Text></Box> };
+        let visible = draw(&ui, "Box { width: 4; }", 8, 1);
+        assert_eq!(lines(&visible), ["overflow"]);
+        let hidden = draw(&ui, "Box { width: 4; overflow: hidden; }", 8, 1);
+        assert_eq!(lines(&hidden), ["over    "]);
+    }
+
+    fn rows() -> Element {
+        rsx! {
+            <List id="list">
+                <R id="r0">"0"</R><R id="r1">"1"</R><R id="r2">"2"</R>
+                <R id="r3">"3"</R><R id="r4">"4"</R><R id="r5">"5"</R>
+            </List>
+        }
+    }
+
+    #[test]
+    fn grid_items_fill_named_areas() {
+        let ui = rsx! {
+            <Dash><Main>"main"</Main><Head>"head"</Head><Side>"side"</Side></Dash>
+        };
+        let sheet = r#"
+            Dash {
+                display: grid;
+                grid-template-columns: 4 1fr;
+                grid-template-areas: "head head" "side main";
+                gap: 1;
+            }
+            Head { grid-area: head; }
+            Side { grid-area: side; }
+            Main { grid-area: main; }
+        "#;
+        let buf = draw(&ui, sheet, 10, 3);
+        assert_eq!(lines(&buf), ["head      ", "          ", "side main "]);
+    }
+
+    #[test]
+    fn scroll_containers_shift_and_clip_their_content() {
+        let scroll = ScrollState::new();
+        let sheet = "List { overflow: scroll; border: solid; }";
+        let buf = draw_scrolled(&rows(), sheet, 5, 5, &scroll);
+        assert_eq!(lines(&buf), ["┌───┐", "│0 █│", "│1 █│", "│2 ││", "└───┘"]);
+
+        scroll.scroll_by("list", 2);
+        let buf = draw_scrolled(&rows(), sheet, 5, 5, &scroll);
+        assert_eq!(lines(&buf), ["┌───┐", "│2 ││", "│3 █│", "│4 █│", "└───┘"]);
+
+        // Offsets past the end are clamped when drawn.
+        scroll.scroll_by("list", 10);
+        let buf = draw_scrolled(&rows(), sheet, 5, 5, &scroll);
+        assert_eq!(scroll.offset("list"), 3);
+        assert_eq!(lines(&buf)[3], "│5 █│");
+    }
+
+    #[test]
+    fn scrolling_into_view_moves_the_least() {
+        let scroll = ScrollState::new();
+        let sheet = "List { overflow: scroll; height: 3; }";
+        scroll.scroll_into_view("r4");
+        let buf = draw_scrolled(&rows(), sheet, 3, 3, &scroll);
+        assert_eq!(scroll.offset("list"), 2);
+        assert_eq!(lines(&buf), ["2 │", "3 █", "4 █"]);
+
+        scroll.scroll_into_view("r3");
+        draw_scrolled(&rows(), sheet, 3, 3, &scroll);
+        assert_eq!(scroll.offset("list"), 2);
+        scroll.scroll_into_view("r0");
+        draw_scrolled(&rows(), sheet, 3, 3, &scroll);
+        assert_eq!(scroll.offset("list"), 0);
+    }
+
+    #[test]
+    fn wheel_scrolls_what_was_drawn_under_it() {
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let scroll = ScrollState::new();
+        let ui = rsx! { <Box><Head>"head"</Head>{rows()}</Box> };
+        let sheet = "List { overflow: scroll; height: 3; }";
+        draw_scrolled(&ui, sheet, 3, 4, &scroll);
+        let wheel = |row| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollDown,
+                column: 0,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        assert!(!scroll.handle(&wheel(0)));
+        assert!(scroll.handle(&wheel(2)));
+        assert_eq!(scroll.offset("list"), 3);
+    }
+
+    #[test]
+    fn hidden_elements_take_no_room_or_events() {
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let scroll = ScrollState::new();
+        let ui = rsx! { <Box><Head>"head"</Head>{rows()}<Foot>"foot"</Foot></Box> };
+        draw_scrolled(&ui, "List { overflow: scroll; height: 3; }", 4, 5, &scroll);
+        scroll.scroll_by("list", 2);
+        let sheet = "List { overflow: scroll; height: 3; display: none; }";
+        let buf = draw_scrolled(&ui, sheet, 4, 5, &scroll);
+        assert_eq!(lines(&buf), ["head", "foot", "    ", "    ", "    "]);
+        // Hidden, the list keeps its offset for when it is shown again,
+        // and the wheel finds nothing where it was.
+        assert_eq!(scroll.offset("list"), 2);
+        let wheel = Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        });
+        assert!(!scroll.handle(&wheel));
+    }
+
+    #[test]
+    fn gone_containers_forget_their_offset() {
+        use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+
+        let scroll = ScrollState::new();
+        let log = rsx! { <Box><Log><R>"0"</R><R>"1"</R><R>"2"</R><R>"3"</R></Log></Box> };
+        let sheet = "Log { overflow: scroll; height: 2; }";
+        draw_scrolled(&log, sheet, 3, 2, &scroll);
+        assert!(scroll.handle(&Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })));
+        assert_eq!(lines(&draw_scrolled(&log, sheet, 3, 2, &scroll))[0], "2 │");
+
+        // A frame without it, then a new container in the same place.
+        draw_scrolled(&rsx! { <Box><Text>"-"</Text></Box> }, sheet, 3, 2, &scroll);
+        assert_eq!(lines(&draw_scrolled(&log, sheet, 3, 2, &scroll))[0], "0 █");
+    }
+
+    #[test]
+    fn scrollbar_glyphs_and_colors() {
+        let sheet = r#"List { overflow: scroll; height: 2; scrollbar-thumb: "┃" red; scrollbar-track: " "; }"#;
+        let buf = draw(&rows(), sheet, 2, 2);
+        assert_eq!(lines(&buf), ["0┃", "1 "]);
+        assert_eq!(buf.get(1, 0).fg, ratatui::style::Color::Red);
+    }
+
+    #[test]
+    fn display_none_paints_nothing() {
+        let ui = rsx! { <Box><Text>"gone"</Text></Box> };
+        let buf = draw(&ui, "Box { display: none; }", 4, 1);
+        assert_eq!(lines(&buf), ["    "]);
+    }
+
+    #[test]
+    fn text_wraps_to_the_box() {
+        let ui = rsx! { <Box><P>"the quick brown fox jumps"</P><End>"."</End></Box> };
+        let buf = draw(&ui, "P { text-align: justify; }", 11, 4);
+        assert_eq!(
+            lines(&buf),
+            ["the   quick", "brown   fox", "jumps      ", ".          "]
+        );
+
+        let buf = draw(&ui, "P { word-break: break-all; }", 11, 3);
+        assert_eq!(lines(&buf), ["the quick b", "rown fox ju", "mps        "]);
+    }
+
+    #[test]
+    fn white_space_keeps_or_collapses() {
+        let ui = rsx! { <Box>"  a\n    b"</Box> };
+        let buf = draw(&ui, "Box { white-space: pre; }", 6, 2);
+        assert_eq!(lines(&buf), ["  a   ", "    b "]);
+        let buf = draw(&ui, "", 6, 2);
+        assert_eq!(lines(&buf), ["a b   ", "      "]);
+    }
+
+    #[test]
+    fn text_overflow_cuts_long_lines() {
+        let ui = rsx! { <Box><Path>"/usr/local/bin/cargo"</Path><Name>"the quick brown fox"</Name></Box> };
+        let sheet = "Box { white-space: nowrap; }
+            Path { text-overflow: middle-ellipsis; }
+            Name { text-overflow: ellipsis; }";
+        let buf = draw(&ui, sheet, 12, 2);
+        assert_eq!(lines(&buf), ["/usr/l…cargo", "the quick b…"]);
+    }
+
+    #[test]
+    fn line_clamp_marks_the_last_line() {
+        let ui = rsx! { <Box><P>"the quick brown fox jumps"</P><End>"."</End></Box> };
+        let buf = draw(&ui, "P { line-clamp: 2; text-overflow: ellipsis; }", 11, 3);
+        assert_eq!(lines(&buf), ["the quick  ", "brown fox… ", ".          "]);
+        let buf = draw(
+            &ui,
+            r#"P { line-clamp: 1; text-overflow: " [more]"; }"#,
+            11,
+            2,
+        );
+        assert_eq!(lines(&buf), ["the [more] ", ".          "]);
+    }
+
+    #[test]
+    fn wide_glyphs_align_by_columns() {
+        let ui = rsx! { <Box>"日本"</Box> };
+        let buf = draw(&ui, "Box { text-align: right; }", 6, 1);
+        assert_eq!(buf.get(2, 0).symbol(), "日");
+        assert_eq!(buf.get(4, 0).symbol(), "本");
+
+        let ui = rsx! { <Box>"e\u{301}x"</Box> };
+        let buf = draw(&ui, "Box { text-align: center; }", 4, 1);
+        assert_eq!(buf.get(1, 0).symbol(), "e\u{301}");
+        assert_eq!(buf.get(2, 0).symbol(), "x");
+    }
+
+    #[test]
+    fn text_clipped_at_left_edge() {
+        let ui = rsx! { <Box><Text>"abcd"</Text></Box> };
+        let buf = draw(&ui, "Box { margin: 0 0 0 -2; }", 4, 1);
+        assert_eq!(lines(&buf), ["cd  "]);
+    }
+
+    #[test]
+    fn dropdowns_paint_over_later_siblings() {
+        let ui = rsx! {
+            <Page>
+                <Menu><Button>"File"</Button><List>"Open"</List></Menu>
+                <Body>"body text"</Body>
+            </Page>
+        };
+        let sheet = "
+            Menu { position: relative; }
+            List { position: absolute; top: 1; left: 1; }
+        ";
+        let buf = draw(&ui, sheet, 10, 3);
+        // The list is out of the flow, so the body sits right under the
+        // button — and the list covers it.
+        assert_eq!(lines(&buf), ["File      ", "bOpentext ", "          "]);
+    }
+
+    #[test]
+    fn z_index_orders_positioned_boxes() {
+        let ui = rsx! {
+            <Page>
+                <Line>"line"</Line>
+                <Under>"zzzzz"</Under>
+                <A>"aaaa"</A>
+                <B>"bbbb"</B>
+            </Page>
+        };
+        let sheet = "
+            Under { position: absolute; top: 0; left: 2; z-index: -1; }
+            A { position: absolute; top: 1; z-index: 2; }
+            B { position: absolute; top: 1; left: 2; z-index: 1; }
+        ";
+        let buf = draw(&ui, sheet, 8, 2);
+        assert_eq!(lines(&buf), ["linezzz ", "aaaabb  "]);
+    }
+
+    #[test]
+    fn overlays_blank_the_cells_beneath() {
+        let ui = rsx! { <Page><Text>"日本"</Text><Tip>"x"</Tip></Page> };
+        let buf = draw(&ui, "Tip { position: absolute; top: 0; left: 1; }", 6, 1);
+        // The wide glyph cut in half by the overlay goes with it.
+        assert_eq!(lines(&buf), [" x本   "]);
+    }
+
+    #[test]
+    fn closed_overlays_restore_the_cells_beneath() {
+        let sheet = "Tip { position: absolute; top: 0; left: 1; background: blue; }";
+        let open = rsx! { <Page><Text>"label"</Text><Tip>"tip"</Tip></Page> };
+        let closed = rsx! { <Page><Text>"label"</Text></Page> };
+        let mut buf = Buffer::empty(ratatui::layout::Rect::new(0, 0, 8, 1));
+        draw_into(&mut buf, &open, sheet, &ScrollState::new());
+        assert_eq!(lines(&buf), ["ltipl   "]);
+        draw_into(&mut buf, &closed, sheet, &ScrollState::new());
+        assert_eq!(buf, draw(&closed, sheet, 8, 1));
+    }
+
+    #[test]
+    fn fixed_boxes_stay_put_in_scroll_containers() {
+        let ui = rsx! {
+            <List id="list">
+                <R>"0"</R><R>"1"</R><R>"2"</R><R>"3"</R><R>"4"</R><R>"5"</R>
+                <Badge>"!"</Badge>
+            </List>
+        };
+        let scroll = ScrollState::new();
+        let sheet = "
+            List { overflow: scroll; height: 3; }
+            Badge { position: fixed; bottom: 0; left: 2; }
+        ";
+        scroll.scroll_by("list", 10);
+        let buf = draw_scrolled(&ui, sheet, 4, 3, &scroll);
+        assert_eq!(scroll.offset("list"), 3);
+        assert_eq!(buf.get(2, 2).symbol(), "!");
+        assert_eq!(buf.get(0, 2).symbol(), "5");
+    }
+}
@@ -0,0 +1,303 @@
+//! Scroll containers — scroll positions that outlive a frame.
+//!
+//! An element with `overflow: scroll` is a scroll container. Its children
+//! are laid out at their full height and shifted up by its scroll offset;
+//! what falls outside its content box is clipped, and a scrollbar in its
+//! right-hand column shows where the view is.
+//!
+//! The element tree is rebuilt for every frame, so the offsets live in a
+//! [`ScrollState`] that [`crate::render`] reads and updates. A container
+//! is known by its `id`, or by where it sits in the tree if it has none,
+//! and its offset is forgotten once a frame is drawn without it.
+//! [`ScrollState::handle`] scrolls with the mouse wheel and
+//! PageUp/PageDown, and [`ScrollState::scroll_into_view`] brings an
+//! element into view on the next frame.
+//!
+//! ```rust
+//! use oxidui::render::render_to_buffer;
+//! use oxidui::rsx;
+//! use oxidui::scroll::ScrollState;
+//! use oxidui::style::border::Charset;
+//! use oxidui::style::cascade::{Cascade, Origin};
+//! use oxidui::style::color::ColorDepth;
+//! use oxidui::style::stylesheet::Stylesheet;
+//! use ratatui::{buffer::Buffer, layout::Rect};
+//!
+//! let ui = rsx! {
+//!     <List id="log"><Row>"a"</Row><Row>"b"</Row><Row id="last">"c"</Row></List>
+//! };
+//! let sheet = Stylesheet::parse("List { overflow: scroll; }").unwrap();
+//! let scroll = ScrollState::new();
+//! scroll.scroll_into_view("last");
+//!
+//! let area = Rect::new(0, 0, 3, 2);
+//! let mut buf = Buffer::empty(area);
+//! let cascade = Cascade::new().with_stylesheet(Origin::Theme, &sheet);
+//! let (depth, charset) = (ColorDepth::TrueColor, Charset::Unicode);
+//! render_to_buffer(&mut buf, area, &ui, &cascade, depth, charset, &scroll);
+//! assert_eq!(scroll.offset("log"), 1);
+//! assert_eq!(buf, Buffer::with_lines(vec!["b │", "c █"]));
+//! ```
+
+use std::cell::{RefCell, RefMut};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use oxidui_style::str::Str;
+
+use crate::layout::Rect;
+
+/// Rows one notch of the mouse wheel scrolls.
+pub const WHEEL_ROWS: i32 = 3;
+
+/// The scroll positions of every scroll container, kept between frames.
+///
+/// A cheap handle: clones share the same positions, so one can be moved
+/// into an event handler while the app keeps drawing with another.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollState(Rc<RefCell<State>>);
+
+impl ScrollState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rows the container with `id` is scrolled down by.
+    pub fn offset(&self, id: &str) -> i32 {
+        self.state()
+            .positions
+            .get(&Key::id(id))
+            .map_or(0, |p| p.offset)
+    }
+
+    /// Scroll the container with `id` so `row` of its content is at the
+    /// top, or as close as it can get.
+    pub fn scroll_to(&self, id: &str, row: i32) {
+        let mut state = self.state();
+        let position = state.positions.entry(Key::id(id)).or_default();
+        position.offset = row.clamp(0, position.max);
+    }
+
+    /// Scroll the container with `id` down by `rows`, or up if negative.
+    pub fn scroll_by(&self, id: &str, rows: i32) {
+        let offset = self.offset(id);
+        self.scroll_to(id, offset.saturating_add(rows));
+    }
+
+    /// Scroll every container around the element with `id` just enough
+    /// to show it, innermost first. Takes effect on the next frame.
+    pub fn scroll_into_view(&self, id: &str) {
+        self.state().reveal = Some(Str::from_string(id.to_owned()));
+    }
+
+    /// Scroll for `event`, returning `true` if anything moved.
+    ///
+    /// The wheel scrolls the innermost container under the pointer by
+    /// [`WHEEL_ROWS`], or the nearest one around it that can still move
+    /// that way. PageUp and PageDown scroll a page of the container the
+    /// pointer was last over, or the first one drawn.
+    pub fn handle(&self, event: &Event) -> bool {
+        let mut state = self.state();
+        match event {
+            Event::Mouse(mouse) => {
+                let rows = match mouse.kind {
+                    MouseEventKind::ScrollDown => WHEEL_ROWS,
+                    MouseEventKind::ScrollUp => -WHEEL_ROWS,
+                    _ => 0,
+                };
+                let (x, y) = (i32::from(mouse.column), i32::from(mouse.row));
+                let under: Vec<Key> = state
+                    .areas
+                    .iter()
+                    .rev()
+                    .filter(|(_, area)| area.contains(x, y))
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                if rows == 0 {
+                    if mouse.kind == MouseEventKind::Moved && !under.is_empty() {
+                        state.active = under.into_iter().next();
+                    }
+                    return false;
+                }
+                for key in under {
+                    if state.scroll(&key, rows) {
+                        state.active = Some(key);
+                        return true;
+                    }
+                }
+                false
+            }
+            Event::Key(key) if key.kind != KeyEventKind::Release => {
+                let down = match key.code {
+                    KeyCode::PageDown => true,
+                    KeyCode::PageUp => false,
+                    _ => return false,
+                };
+                let active = state
+                    .active
+                    .clone()
+                    .filter(|key| state.areas.iter().any(|(k, _)| k == key))
+                    .or_else(|| state.areas.first().map(|(key, _)| key.clone()));
+                let Some(active) = active else {
+                    return false;
+                };
+                let page = state.positions.get(&active).map_or(1, |p| p.page.max(1));
+                state.scroll(&active, if down { page } else { -page })
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn state(&self) -> RefMut<'_, State> {
+        self.0.borrow_mut()
+    }
+}
+
+/// A scroll container: its `id`, or the child indices that lead to it
+/// from the root.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Key {
+    Id(Str),
+    Path(Vec<usize>),
+}
+
+impl Key {
+    fn id(id: &str) -> Self {
+        Self::Id(Str::from_string(id.to_owned()))
+    }
+}
+
+/// Where a container is scrolled to, and how far it can go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    /// Rows scrolled down by.
+    pub offset: i32,
+    /// The largest offset: how much taller the content is than the view.
+    pub max: i32,
+    /// Height of the view.
+    pub page: i32,
+}
+
+impl Default for Position {
+    /// Not drawn yet, so any offset goes until the first frame clamps it.
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            max: i32::MAX,
+            page: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    pub positions: HashMap<Key, Position>,
+    /// The on-screen part of every container drawn last frame, in paint
+    /// order — so an inner container comes after the one around it.
+    pub areas: Vec<(Key, Rect)>,
+    /// The container the pointer was last over.
+    active: Option<Key>,
+    /// The element to bring into view on the next frame.
+    pub reveal: Option<Str>,
+}
+
+impl State {
+    /// Rows the container at `key` is scrolled down by.
+    pub fn offset(&self, key: &Key) -> i32 {
+        self.positions.get(key).map_or(0, |p| p.offset)
+    }
+
+    /// Scroll by `rows`, returning `true` if the offset changed.
+    fn scroll(&mut self, key: &Key, rows: i32) -> bool {
+        let Some(position) = self.positions.get_mut(key) else {
+            return false;
+        };
+        let offset = position.offset.saturating_add(rows).clamp(0, position.max);
+        let moved = offset != position.offset;
+        position.offset = offset;
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyEvent, KeyModifiers, MouseEvent};
+
+    fn wheel(kind: MouseEventKind, column: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    /// An outer container with an inner one in its top half.
+    fn nested() -> ScrollState {
+        let scroll = ScrollState::new();
+        let mut state = scroll.state();
+        for (id, max, area) in [
+            ("outer", 10, Rect::new(0, 0, 10, 10)),
+            ("inner", 4, Rect::new(0, 0, 10, 5)),
+        ] {
+            let position = Position {
+                offset: 0,
+                max,
+                page: area.height,
+            };
+            state.positions.insert(Key::id(id), position);
+            state.areas.push((Key::id(id), area));
+        }
+        drop(state);
+        scroll
+    }
+
+    #[test]
+    fn wheel_scrolls_the_innermost_container() {
+        let scroll = nested();
+        assert!(scroll.handle(&wheel(MouseEventKind::ScrollDown, 1, 1)));
+        assert_eq!((scroll.offset("inner"), scroll.offset("outer")), (3, 0));
+        // At its end, the inner container hands on to the outer one.
+        assert!(scroll.handle(&wheel(MouseEventKind::ScrollDown, 1, 1)));
+        assert!(scroll.handle(&wheel(MouseEventKind::ScrollDown, 1, 1)));
+        assert_eq!((scroll.offset("inner"), scroll.offset("outer")), (4, 3));
+        assert!(scroll.handle(&wheel(MouseEventKind::ScrollUp, 1, 8)));
+        assert_eq!(scroll.offset("outer"), 0);
+        assert!(!scroll.handle(&wheel(MouseEventKind::ScrollUp, 1, 8)));
+        assert!(!scroll.handle(&wheel(MouseEventKind::ScrollDown, 20, 20)));
+    }
+
+    #[test]
+    fn page_keys_scroll_the_hovered_container() {
+        let scroll = nested();
+        assert!(scroll.handle(&key(KeyCode::PageDown)));
+        assert_eq!(scroll.offset("outer"), 10);
+
+        assert!(!scroll.handle(&wheel(MouseEventKind::Moved, 1, 1)));
+        assert!(scroll.handle(&key(KeyCode::PageDown)));
+        assert_eq!(scroll.offset("inner"), 4);
+        assert!(scroll.handle(&key(KeyCode::PageUp)));
+        assert_eq!(scroll.offset("inner"), 0);
+        assert!(!scroll.handle(&key(KeyCode::Home)));
+    }
+
+    #[test]
+    fn offsets_wait_for_the_first_frame_to_clamp() {
+        let scroll = ScrollState::new();
+        scroll.scroll_by("log", 50);
+        scroll.scroll_by("log", -20);
+        assert_eq!(scroll.offset("log"), 30);
+        scroll.scroll_to("log", -5);
+        assert_eq!(scroll.offset("log"), 0);
+
+        let scroll = nested();
+        scroll.scroll_to("inner", 50);
+        assert_eq!(scroll.offset("inner"), 4);
+    }
+}
@@ -0,0 +1,488 @@
+//! Text measuring and wrapping, one grapheme cluster at a time.
+//!
+//! A terminal cell holds one grapheme cluster: a base character with any
+//! combining marks, or an emoji sequence joined with ZWJ. Counting `char`s
+//! gets both wrong — `"é"` may be two of them, `"👩‍💻"` three — so every
+//! width here is summed per cluster. East Asian wide characters and emoji
+//! take two columns.
+//!
+//! [`wrap`] breaks text into lines of at most a given width, by word or
+//! anywhere ([`WordBreak`]); [`justify`] spreads a line to full width.
+//! [`min_content`] and [`max_content`] are the intrinsic widths layout
+//! sizes `auto` boxes with. [`collapse`] applies `white-space` before any
+//! of them; [`truncate`], [`truncate_middle`] and [`cut`] shorten the
+//! lines `text-overflow` applies to.
+//!
+//! ```rust
+//! use oxidui::style::layout::WordBreak;
+//! use oxidui::text::{max_content, min_content, width, wrap};
+//!
+//! assert_eq!(width("日本"), 4);
+//! assert_eq!(width("e\u{301}"), 1);
+//!
+//! let lines: Vec<_> = wrap("the quick brown fox", 10, WordBreak::Normal)
+//!     .iter()
+//!     .map(|l| l.text)
+//!     .collect();
+//! assert_eq!(lines, ["the quick", "brown fox"]);
+//!
+//! assert_eq!(min_content("the quick brown fox", WordBreak::Normal), 5);
+//! assert_eq!(max_content("the quick brown fox"), 19);
+//! ```
+
+use std::borrow::Cow;
+
+use oxidui_style::layout::{WhiteSpace, WordBreak};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+// ---------------------------------------------------------------------------
+// Width
+// ---------------------------------------------------------------------------
+
+/// Columns taken by `s` on one line.
+pub fn width(s: &str) -> i32 {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Columns taken by one grapheme cluster: its base character's width, or
+/// two for an emoji presentation sequence (`U+FE0F`). Control characters
+/// take none.
+pub fn grapheme_width(g: &str) -> i32 {
+    let mut chars = g.chars();
+    let Some(base) = chars.next() else {
+        return 0;
+    };
+    let base = base.width().unwrap_or(0) as i32;
+    if base > 0 && chars.any(|c| c == '\u{FE0F}') {
+        2
+    } else {
+        base.min(2)
+    }
+}
+
+/// `s` split into grapheme clusters with their byte offsets and widths.
+pub fn graphemes(s: &str) -> impl Iterator<Item = (usize, &str, i32)> {
+    s.grapheme_indices(true)
+        .map(|(i, g)| (i, g, grapheme_width(g)))
+}
+
+/// `text` cut to `width` columns, ending in `ellipsis` if anything was
+/// dropped. Too narrow for the ellipsis, it is simply cut.
+pub fn truncate<'t>(text: &'t str, width: i32, ellipsis: &str) -> Cow<'t, str> {
+    if self::width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(cut(text, width, ellipsis))
+}
+
+/// `text` ending in `ellipsis`, cut as short as it must be for both to
+/// fit in `width` columns — for text known to go on past its end, like
+/// the last line `line-clamp` keeps. Spaces before the ellipsis are
+/// dropped.
+///
+/// ```rust
+/// assert_eq!(oxidui::text::cut("the quick", 5, "…"), "the…");
+/// ```
+pub fn cut(text: &str, width: i32, ellipsis: &str) -> String {
+    let (room, ellipsis) = match width - self::width(ellipsis) {
+        room if room >= 0 => (room, ellipsis),
+        _ => (width, ""),
+    };
+    let kept = &text[..fit(text, room)];
+    format!("{}{ellipsis}", kept.trim_end())
+}
+
+/// `text` cut to `width` columns by dropping its middle for `ellipsis`,
+/// so both ends stay readable. The start gets the odd column.
+///
+/// ```rust
+/// use oxidui::text::truncate_middle;
+///
+/// let path = "/usr/local/bin/cargo";
+/// assert_eq!(truncate_middle(path, 12, "…"), "/usr/l…cargo");
+/// assert_eq!(truncate_middle(path, 20, "…"), path);
+/// ```
+pub fn truncate_middle<'t>(text: &'t str, width: i32, ellipsis: &str) -> Cow<'t, str> {
+    let total = self::width(text);
+    if total <= width {
+        return Cow::Borrowed(text);
+    }
+    let room = width - self::width(ellipsis);
+    if room < 0 {
+        return truncate(text, width, "");
+    }
+    let head = fit(text, (room + 1) / 2);
+    let tail = fit_end(text, room - self::width(&text[..head]));
+    Cow::Owned(format!("{}{ellipsis}{}", &text[..head], &text[tail..]))
+}
+
+/// Byte length of the longest prefix of `text` at most `room` columns wide.
+fn fit(text: &str, room: i32) -> usize {
+    let mut used = 0;
+    for (i, _, w) in graphemes(text) {
+        used += w;
+        if used > room {
+            return i;
+        }
+    }
+    text.len()
+}
+
+/// Byte offset of the longest suffix of `text` at most `room` columns wide.
+fn fit_end(text: &str, room: i32) -> usize {
+    let mut used = 0;
+    for (i, g) in text.grapheme_indices(true).rev() {
+        used += grapheme_width(g);
+        if used > room {
+            return i + g.len();
+        }
+    }
+    0
+}
+
+/// `text` with its whitespace handled as `white_space` says: runs of
+/// spaces, tabs and newlines become a single space, with none at either
+/// end, if it collapses, and `text` is kept as is otherwise.
+pub fn collapse(text: &str, white_space: WhiteSpace) -> Cow<'_, str> {
+    if !white_space.collapses() {
+        return Cow::Borrowed(text);
+    }
+    let collapsed = text.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(collapsed)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Intrinsic widths
+// ---------------------------------------------------------------------------
+
+/// The widest line of `text` without wrapping — the narrowest box that
+/// needs no breaks. CSS `max-content`.
+pub fn max_content(text: &str) -> i32 {
+    text.lines().map(width).max().unwrap_or(0)
+}
+
+/// The narrowest box `text` can wrap into without overflowing: its widest
+/// word, or its widest grapheme with [`WordBreak::BreakAll`]. CSS
+/// `min-content`.
+pub fn min_content(text: &str, word_break: WordBreak) -> i32 {
+    match word_break {
+        WordBreak::Normal => text.split_whitespace().map(width).max().unwrap_or(0),
+        WordBreak::BreakAll => graphemes(text)
+            .filter(|(_, g, _)| !is_space(g))
+            .map(|(.., w)| w)
+            .max()
+            .unwrap_or(0),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Wrapping
+// ---------------------------------------------------------------------------
+
+/// One line of wrapped text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'t> {
+    /// The line, without the spaces it broke at.
+    pub text: &'t str,
+    /// Its width in columns.
+    pub width: i32,
+    /// `true` for the last line of a paragraph, which [`justify`] leaves
+    /// alone.
+    pub last: bool,
+}
+
+/// Break `text` into lines at most `width` columns wide.
+///
+/// Newlines always start a new line. Lines break at spaces, which are
+/// dropped at the break — but a paragraph's leading spaces are kept as
+/// its indentation. With [`WordBreak::BreakAll`] they also break
+/// between any two graphemes. A word wider than the line overflows it on
+/// a line of its own — unless breaking anywhere, when each line still
+/// holds at least one grapheme.
+///
+/// With no room at all (`width <= 0`) nothing is wrapped.
+pub fn wrap(text: &str, width: i32, word_break: WordBreak) -> Vec<Line<'_>> {
+    let mut out = Vec::new();
+    for paragraph in text.lines() {
+        let start = out.len();
+        if width <= 0 {
+            out.push(line(paragraph));
+        } else {
+            match word_break {
+                WordBreak::Normal => wrap_words(paragraph, width, &mut out),
+                WordBreak::BreakAll => wrap_anywhere(paragraph, width, &mut out),
+            }
+            if out.len() == start {
+                out.push(line(""));
+            }
+        }
+        if let Some(last) = out.last_mut() {
+            last.last = true;
+        }
+    }
+    out
+}
+
+fn line(text: &str) -> Line<'_> {
+    Line {
+        text,
+        width: width(text),
+        last: false,
+    }
+}
+
+/// Greedy word wrap: as many words per line as fit.
+fn wrap_words<'t>(paragraph: &'t str, width: i32, out: &mut Vec<Line<'t>>) {
+    // The current line, as a byte range and width.
+    let mut current: Option<(usize, usize, i32)> = None;
+    for (start, word) in words(paragraph) {
+        let end = start + word.len();
+        let w = self::width(word);
+        current = match current {
+            Some((from, to, used)) => {
+                let joined = used + self::width(&paragraph[to..start]) + w;
+                if joined <= width {
+                    Some((from, end, joined))
+                } else {
+                    out.push(Line {
+                        text: &paragraph[from..to],
+                        width: used,
+                        last: false,
+                    });
+                    Some((start, end, w))
+                }
+            }
+            // Only the first word finds no current line, and it takes
+            // the indentation along.
+            None => Some((0, end, self::width(&paragraph[..end]))),
+        };
+    }
+    if let Some((from, to, used)) = current {
+        out.push(Line {
+            text: &paragraph[from..to],
+            width: used,
+            last: false,
+        });
+    }
+}
+
+/// Fill each line grapheme by grapheme, breaking wherever it is full.
+fn wrap_anywhere<'t>(paragraph: &'t str, width: i32, out: &mut Vec<Line<'t>>) {
+    // Start with an empty line so the indentation is kept.
+    let mut current: Option<(usize, usize, i32)> = Some((0, 0, 0));
+    for (i, g, w) in graphemes(paragraph) {
+        let space = is_space(g);
+        current = match current {
+            Some((from, _, used)) if used + w <= width => Some((from, i + g.len(), used + w)),
+            Some((from, to, _)) => {
+                if to > from {
+                    out.push(line(paragraph[from..to].trim_end()));
+                }
+                (!space).then_some((i, i + g.len(), w))
+            }
+            // Spaces at a break are dropped.
+            None if space => None,
+            None => Some((i, i + g.len(), w)),
+        };
+    }
+    if let Some((from, to, _)) = current
+        && to > from
+    {
+        out.push(line(paragraph[from..to].trim_end()));
+    }
+}
+
+/// The words of `text` with their byte offsets.
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(' ')
+        .scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len() + 1;
+            Some((start, word))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+fn is_space(g: &str) -> bool {
+    g.chars().all(char::is_whitespace)
+}
+
+/// The words of `line` with their column offsets, spread so the line
+/// fills `width`: the spare columns go to the gaps between words, the
+/// leftmost gaps taking one more when they don't divide evenly.
+///
+/// The last line of a paragraph, a line with a single word or one that
+/// already fills `width` is returned as is.
+///
+/// ```rust
+/// use oxidui::style::layout::WordBreak;
+/// use oxidui::text::{justify, wrap};
+///
+/// let lines = wrap("a bb c dd", 7, WordBreak::Normal);
+/// assert_eq!(justify(lines[0], 7), [(0, "a"), (3, "bb"), (6, "c")]);
+/// assert_eq!(justify(lines[1], 7), [(0, "dd")]);
+/// ```
+pub fn justify(line: Line<'_>, width: i32) -> Vec<(i32, &str)> {
+    let words: Vec<_> = words(line.text).collect();
+    let gaps = words.len() as i32 - 1;
+    let spare = width - line.width;
+    if line.last || gaps <= 0 || spare <= 0 {
+        return vec![(0, line.text)];
+    }
+    let mut x = 0;
+    let mut out = Vec::with_capacity(words.len());
+    for (i, &(start, word)) in words.iter().enumerate() {
+        if i > 0 {
+            let (prev_start, prev) = words[i - 1];
+            let gap = self::width(&line.text[prev_start + prev.len()..start]);
+            let extra = spare / gaps + i32::from((i as i32 - 1) < spare % gaps);
+            x += gap + extra;
+        }
+        out.push((x, word));
+        x += self::width(word);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped(text: &str, width: i32, word_break: WordBreak) -> Vec<&str> {
+        wrap(text, width, word_break)
+            .iter()
+            .map(|l| l.text)
+            .collect()
+    }
+
+    #[test]
+    fn widths_count_grapheme_clusters() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("日本語"), 6);
+        // Combining acute accent.
+        assert_eq!(width("cafe\u{301}"), 4);
+        // Woman technologist: woman + ZWJ + laptop.
+        assert_eq!(width("👩\u{200d}💻"), 2);
+        // Heart with emoji presentation.
+        assert_eq!(width("\u{2764}\u{fe0f}"), 2);
+        assert_eq!(width("\t"), 0);
+    }
+
+    #[test]
+    fn truncate_keeps_clusters_whole() {
+        assert_eq!(truncate("日本語", 5, "…"), "日本…");
+        assert_eq!(truncate("日本語", 4, ""), "日本");
+        assert_eq!(truncate("e\u{301}e\u{301}e\u{301}", 2, "…"), "e\u{301}…");
+        assert_eq!(truncate("abc", 3, "…"), "abc");
+        assert_eq!(truncate("abcdef", 2, "..."), "ab");
+    }
+
+    #[test]
+    fn truncates_in_the_middle() {
+        let path = "/home/user/projects/oxidui/src/text.rs";
+        assert_eq!(truncate_middle(path, 15, "…"), "/home/u…text.rs");
+        assert_eq!(truncate_middle(path, 15, "...").as_ref(), "/home/...ext.rs");
+        assert_eq!(truncate_middle("日本語です", 7, "…"), "日…です");
+        assert_eq!(truncate_middle("abcdef", 2, "..."), "ab");
+    }
+
+    #[test]
+    fn cut_always_ends_in_the_ellipsis() {
+        assert_eq!(cut("abc", 5, "…"), "abc…");
+        assert_eq!(cut("the quick", 10, " [more]"), "the [more]");
+        assert_eq!(cut("abc", 2, "..."), "ab");
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(collapse("  a \t b\n\nc ", WhiteSpace::Normal), "a b c");
+        assert_eq!(collapse("a\nb", WhiteSpace::NoWrap), "a b");
+        assert!(matches!(
+            collapse("a b", WhiteSpace::Normal),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(collapse("  a\n b", WhiteSpace::Pre), "  a\n b");
+    }
+
+    #[test]
+    fn indentation_is_kept() {
+        assert_eq!(
+            wrapped("  if x {\n    y\n  }", 9, WordBreak::Normal),
+            ["  if x {", "    y", "  }"]
+        );
+        assert_eq!(wrapped("  ab cd", 5, WordBreak::Normal), ["  ab", "cd"]);
+        assert_eq!(wrapped("  abcd", 4, WordBreak::BreakAll), ["  ab", "cd"]);
+    }
+
+    #[test]
+    fn wraps_at_spaces() {
+        assert_eq!(
+            wrapped("the quick brown fox", 9, WordBreak::Normal),
+            ["the quick", "brown fox"]
+        );
+        // Long words overflow on their own line.
+        assert_eq!(
+            wrapped("a extraordinary b", 5, WordBreak::Normal),
+            ["a", "extraordinary", "b"]
+        );
+        // Inner runs of spaces are kept, those at a break dropped.
+        assert_eq!(wrapped("a  b   c", 4, WordBreak::Normal), ["a  b", "c"]);
+        // Newlines always break, and blank lines stay.
+        assert_eq!(wrapped("ab\n\ncd", 9, WordBreak::Normal), ["ab", "", "cd"]);
+    }
+
+    #[test]
+    fn wraps_anywhere() {
+        assert_eq!(
+            wrapped("extraordinary", 5, WordBreak::BreakAll),
+            ["extra", "ordin", "ary"]
+        );
+        assert_eq!(
+            wrapped("ab cd ef", 4, WordBreak::BreakAll),
+            ["ab c", "d ef"]
+        );
+        // A wide glyph never splits, and a line always takes one.
+        assert_eq!(
+            wrapped("日本語", 3, WordBreak::BreakAll),
+            ["日", "本", "語"]
+        );
+        assert_eq!(wrapped("日本", 1, WordBreak::BreakAll), ["日", "本"]);
+    }
+
+    #[test]
+    fn wide_text_wraps_by_columns() {
+        let lines = wrap("日本 語です", 5, WordBreak::Normal);
+        assert_eq!(
+            lines.iter().map(|l| (l.text, l.width)).collect::<Vec<_>>(),
+            [("日本", 4), ("語です", 6)]
+        );
+        assert!(!lines[0].last && lines[1].last);
+    }
+
+    #[test]
+    fn intrinsic_widths() {
+        let text = "a bb\nccc dddd";
+        assert_eq!(max_content(text), 8);
+        assert_eq!(min_content(text, WordBreak::Normal), 4);
+        assert_eq!(min_content("日本", WordBreak::BreakAll), 2);
+        assert_eq!(min_content("", WordBreak::Normal), 0);
+    }
+
+    #[test]
+    fn justify_spreads_gaps() {
+        let line = Line {
+            text: "a b c",
+            width: 5,
+            last: false,
+        };
+        assert_eq!(justify(line, 8), [(0, "a"), (4, "b"), (7, "c")]);
+        assert_eq!(justify(line, 5), [(0, "a b c")]);
+        let last = Line { last: true, ..line };
+        assert_eq!(justify(last, 8), [(0, "a b c")]);
+    }
+}
@@ -0,0 +1,543 @@
+//! Stylesheet hot-reloading.
+//!
+//! [`StylesheetWatcher`] watches `.oxss` files (or directories of them),
+//! re-parses a file whenever it changes, and delivers the combined rule set
+//! over a crossbeam channel. The running app calls
+//! [`StylesheetWatcher::poll`] once per frame; when it returns `true` the
+//! next frame re-resolves styles against [`StylesheetWatcher::current`].
+//!
+//! A file that fails to read or parse never replaces its last good
+//! version — the error is delivered as a [`StylesheetEvent::Error`] and
+//! kept in [`StylesheetWatcher::last_error`] so the UI can show it, until
+//! that file loads cleanly again.
+//!
+//! ```rust,no_run
+//! use oxidui::watcher::StylesheetWatcher;
+//!
+//! let mut sheets = StylesheetWatcher::new(["theme.oxss", "styles/"])?;
+//! loop {
+//!     if sheets.poll() {
+//!         // restyle with sheets.current()
+//!     }
+//!     if let Some(err) = sheets.last_error() {
+//!         eprintln!("{err}");
+//!     }
+//!     # break;
+//! }
+//! # Ok::<(), oxidui::watcher::WatchError>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use oxidui_style::stylesheet::{self, ParseError, Stylesheet};
+use walkdir::WalkDir;
+
+/// Default directory for user themes: `<config dir>/oxidui/themes`.
+pub fn default_theme_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("oxidui").join("themes"))
+}
+
+/// A change delivered by the watcher.
+#[derive(Debug)]
+pub enum StylesheetEvent {
+    /// The rule set changed. Contains every rule from every watched file,
+    /// in watch order, so it can replace the previous set wholesale.
+    Reloaded(Arc<Stylesheet>),
+    /// A file could not be loaded. The previous rule set stays active.
+    Error(LoadError),
+    /// A file that could not be loaded now can, whether or not its rules
+    /// changed — say, when an edit is undone.
+    Cleared(PathBuf),
+}
+
+/// A watched file that failed to load.
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: PathBuf,
+    pub kind: LoadErrorKind,
+}
+
+#[derive(Debug)]
+pub enum LoadErrorKind {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LoadErrorKind::Io(e) => write!(f, "{}: {e}", self.path.display()),
+            LoadErrorKind::Parse(e) => write!(f, "{}:{e}", self.path.display()),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Failure to set up watching.
+#[derive(Debug)]
+pub enum WatchError {
+    Notify(notify::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for WatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Notify(e) => write!(f, "cannot watch stylesheets: {e}"),
+            Self::Io(e) => write!(f, "cannot read stylesheets: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for WatchError {}
+
+impl From<notify::Error> for WatchError {
+    fn from(e: notify::Error) -> Self {
+        Self::Notify(e)
+    }
+}
+impl From<io::Error> for WatchError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// What was asked to be watched.
+enum Target {
+    /// A single file. Its parent directory is watched so that editors
+    /// that save by rename-and-replace are still noticed.
+    File(PathBuf),
+    /// Every `.oxss` file below a directory, recursively.
+    Dir(PathBuf),
+}
+
+impl Target {
+    fn contains(&self, path: &Path) -> bool {
+        match self {
+            Self::File(f) => path == f,
+            Self::Dir(d) => path.starts_with(d) && is_stylesheet(path),
+        }
+    }
+}
+
+fn is_stylesheet(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == stylesheet::EXTENSION)
+}
+
+/// Loaded files in watch order, plus the channel to publish on.
+struct State {
+    targets: Vec<Target>,
+    /// `(path, last good parse)`, ordered by target then path.
+    files: Vec<(PathBuf, Stylesheet)>,
+    combined: Arc<Stylesheet>,
+    /// Paths with an error reported and not yet cleared.
+    failing: HashSet<PathBuf>,
+    tx: Sender<StylesheetEvent>,
+}
+
+impl State {
+    /// Position a file sorts at: by target index, then by path.
+    fn rank(&self, path: &Path) -> Option<(usize, PathBuf)> {
+        let t = self.targets.iter().position(|t| t.contains(path))?;
+        Some((t, path.to_owned()))
+    }
+
+    /// (Re)load one file. Returns `Ok(true)` if the rule set changed; on
+    /// error the file's previous rules are kept.
+    fn load(&mut self, path: &Path) -> Result<bool, LoadError> {
+        let existing = self.files.iter().position(|(p, _)| p == path);
+        let parsed = match std::fs::read_to_string(path) {
+            Ok(src) => Stylesheet::parse(&src).map_err(LoadErrorKind::Parse),
+            // Deleted (or moved away): drop its rules.
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(existing.map(|i| self.files.remove(i)).is_some());
+            }
+            Err(e) => Err(LoadErrorKind::Io(e)),
+        };
+        match (parsed, existing) {
+            (Ok(sheet), Some(i)) => {
+                let changed = self.files[i].1 != sheet;
+                self.files[i].1 = sheet;
+                Ok(changed)
+            }
+            (Ok(sheet), None) => {
+                let Some(rank) = self.rank(path) else {
+                    return Ok(false);
+                };
+                let at = self
+                    .files
+                    .iter()
+                    .position(|(p, _)| self.rank(p).is_some_and(|r| r > rank))
+                    .unwrap_or(self.files.len());
+                self.files.insert(at, (path.to_owned(), sheet));
+                Ok(true)
+            }
+            (Err(kind), _) => Err(LoadError {
+                path: path.to_owned(),
+                kind,
+            }),
+        }
+    }
+
+    /// Send the combined rule set, then the errors cleared and raised by
+    /// loading `loaded`.
+    fn publish(&mut self, loaded: &[PathBuf], errors: Vec<LoadError>) {
+        let rules = self
+            .files
+            .iter()
+            .flat_map(|(_, s)| s.rules.iter().cloned())
+            .collect();
+        self.combined = Arc::new(Stylesheet { rules });
+        let _ = self
+            .tx
+            .send(StylesheetEvent::Reloaded(Arc::clone(&self.combined)));
+        self.report(loaded, errors);
+    }
+
+    /// Clear the errors of the failing files in `loaded`, then send
+    /// `errors`. A watch error names no file that loads, so it is cleared
+    /// by the next batch instead.
+    fn report(&mut self, loaded: &[PathBuf], errors: Vec<LoadError>) {
+        let cleared: Vec<PathBuf> = self
+            .failing
+            .iter()
+            .filter(|p| loaded.contains(p) || !self.targets.iter().any(|t| t.contains(p)))
+            .cloned()
+            .collect();
+        for path in cleared {
+            self.failing.remove(&path);
+            let _ = self.tx.send(StylesheetEvent::Cleared(path));
+        }
+        for err in errors {
+            self.failing.insert(err.path.clone());
+            let _ = self.tx.send(StylesheetEvent::Error(err));
+        }
+    }
+
+    /// Reload thread: batches filesystem events until the files have been
+    /// quiet for [`DEBOUNCE`], then reloads everything touched. Editors
+    /// often truncate before writing, so reacting to the first event would
+    /// briefly publish an empty stylesheet.
+    fn run(mut self, raw: Receiver<notify::Result<Event>>) {
+        // Ends when the notify watcher (and with it the sender) is dropped.
+        while let Ok(first) = raw.recv() {
+            let mut paths = Vec::new();
+            let mut errors = Vec::new();
+            let mut event = Some(first);
+            while let Some(e) = event.take() {
+                match e {
+                    Ok(e)
+                        if matches!(
+                            e.kind,
+                            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                        ) =>
+                    {
+                        paths.extend(e.paths)
+                    }
+                    Ok(_) => {}
+                    Err(e) => errors.push(LoadError {
+                        path: e.paths.first().cloned().unwrap_or_default(),
+                        kind: LoadErrorKind::Io(io::Error::other(e.to_string())),
+                    }),
+                }
+                event = raw.recv_timeout(DEBOUNCE).ok();
+            }
+            paths.sort();
+            paths.dedup();
+
+            let mut changed = false;
+            let mut loaded = Vec::new();
+            for path in paths {
+                if self.targets.iter().any(|t| t.contains(&path)) {
+                    match self.load(&path) {
+                        Ok(c) => {
+                            changed |= c;
+                            loaded.push(path);
+                        }
+                        Err(e) => errors.push(e),
+                    }
+                }
+            }
+            if changed {
+                self.publish(&loaded, errors);
+            } else {
+                self.report(&loaded, errors);
+            }
+        }
+    }
+}
+
+/// How long files must be quiet before a change is reloaded.
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Watches stylesheet files and publishes reloaded rule sets.
+pub struct StylesheetWatcher {
+    // Dropping the watcher stops the notify thread, which in turn ends
+    // the reload thread.
+    _watcher: RecommendedWatcher,
+    rx: Receiver<StylesheetEvent>,
+    current: Arc<Stylesheet>,
+    /// The latest error of each file still failing, oldest first.
+    errors: Vec<LoadError>,
+}
+
+impl StylesheetWatcher {
+    /// Load and start watching `paths`.
+    ///
+    /// Each path is either a stylesheet file or a directory searched
+    /// recursively for `.oxss` files. Rules are combined in the order the
+    /// paths are given (files within a directory sorted by path), so later
+    /// paths override earlier ones at equal specificity.
+    ///
+    /// Files that fail to load initially are reported through
+    /// [`StylesheetWatcher::poll`] like any later failure.
+    pub fn new<I, P>(paths: I) -> Result<Self, WatchError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let mut targets = Vec::new();
+        for p in paths {
+            let p = std::path::absolute(p.as_ref())?;
+            targets.push(if p.is_dir() {
+                Target::Dir(p)
+            } else {
+                Target::File(p)
+            });
+        }
+
+        let mut state = State {
+            targets,
+            files: Vec::new(),
+            combined: Arc::default(),
+            failing: HashSet::new(),
+            tx,
+        };
+        let watch: Vec<(PathBuf, RecursiveMode)> = state
+            .targets
+            .iter()
+            .filter_map(|t| match t {
+                Target::File(f) => f
+                    .parent()
+                    .map(|p| (p.to_owned(), RecursiveMode::NonRecursive)),
+                Target::Dir(d) => Some((d.clone(), RecursiveMode::Recursive)),
+            })
+            .collect();
+
+        let (raw_tx, raw_rx) = crossbeam_channel::unbounded();
+        let mut watcher = notify::recommended_watcher(move |e| {
+            let _ = raw_tx.send(e);
+        })?;
+        for (path, mode) in watch {
+            watcher.watch(&path, mode)?;
+        }
+        // Watching starts before the first load, so an edit made meanwhile
+        // is queued rather than lost.
+        let mut initial = Vec::new();
+        for t in &state.targets {
+            match t {
+                Target::File(f) => initial.push(f.clone()),
+                Target::Dir(d) => {
+                    let mut found = Vec::new();
+                    for entry in WalkDir::new(d).sort_by_file_name() {
+                        let entry = entry.map_err(io::Error::from)?;
+                        if entry.file_type().is_file() && is_stylesheet(entry.path()) {
+                            found.push(entry.into_path());
+                        }
+                    }
+                    initial.extend(found);
+                }
+            }
+        }
+        let mut errors = Vec::new();
+        for f in &initial {
+            if !f.exists() {
+                errors.push(LoadError {
+                    path: f.clone(),
+                    kind: LoadErrorKind::Io(io::ErrorKind::NotFound.into()),
+                });
+            } else if let Err(e) = state.load(f) {
+                errors.push(e);
+            }
+        }
+        state.publish(&[], errors);
+
+        std::thread::Builder::new()
+            .name("oxidui-stylesheets".into())
+            .spawn(move || state.run(raw_rx))?;
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            current: Arc::default(),
+            errors: Vec::new(),
+        })
+    }
+
+    /// The raw event channel, for apps that `select!` over several sources.
+    ///
+    /// Events taken from here are not seen by [`StylesheetWatcher::poll`].
+    pub fn events(&self) -> &Receiver<StylesheetEvent> {
+        &self.rx
+    }
+
+    /// Drain pending events without blocking. Returns `true` if
+    /// [`StylesheetWatcher::current`] changed.
+    ///
+    /// A failed load sets [`StylesheetWatcher::last_error`] and leaves
+    /// the current rule set untouched. The error lasts until that file
+    /// loads cleanly again.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok(event) = self.rx.try_recv() {
+            match event {
+                StylesheetEvent::Reloaded(sheet) => {
+                    self.current = sheet;
+                    changed = true;
+                }
+                StylesheetEvent::Error(err) => {
+                    self.errors.retain(|e| e.path != err.path);
+                    self.errors.push(err);
+                }
+                StylesheetEvent::Cleared(path) => self.errors.retain(|e| e.path != path),
+            }
+        }
+        changed
+    }
+
+    /// The active rule set — the last one that loaded cleanly.
+    pub fn current(&self) -> &Arc<Stylesheet> {
+        &self.current
+    }
+
+    /// The most recent load failure of a file that still fails.
+    pub fn last_error(&self) -> Option<&LoadError> {
+        self.errors.last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxidui_style::color::{Color, NamedColor};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    /// A fresh, empty directory under the system temp dir.
+    fn temp_dir() -> PathBuf {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "oxidui-watcher-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Poll until `done` holds or a generous timeout expires.
+    fn wait_for(w: &mut StylesheetWatcher, done: impl Fn(&StylesheetWatcher) -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while Instant::now() < deadline {
+            w.poll();
+            if done(w) {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        false
+    }
+
+    fn color_of(w: &StylesheetWatcher) -> Option<Color> {
+        w.current().rules.first().and_then(|r| r.style.color)
+    }
+
+    #[test]
+    fn initial_load_is_published() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("b.oxss"), "B { color: blue }").unwrap();
+        std::fs::write(dir.join("a.oxss"), "A { color: red }").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a stylesheet").unwrap();
+
+        let mut w = StylesheetWatcher::new([&dir]).unwrap();
+        assert!(w.poll());
+        let selectors: Vec<_> = w
+            .current()
+            .rules
+            .iter()
+            .map(|r| r.selector.clone())
+            .collect();
+        assert_eq!(selectors, ["A", "B"]);
+        assert!(w.last_error().is_none());
+    }
+
+    #[test]
+    fn reload_keeps_last_good_on_error() {
+        let dir = temp_dir();
+        let file = dir.join("theme.oxss");
+        std::fs::write(&file, "A { color: red }").unwrap();
+
+        let mut w = StylesheetWatcher::new([&file]).unwrap();
+        w.poll();
+        assert_eq!(color_of(&w), Some(Color::Named(NamedColor::Red)));
+
+        std::fs::write(&file, "A { color: rd }").unwrap();
+        assert!(wait_for(&mut w, |w| w.last_error().is_some()));
+        assert_eq!(color_of(&w), Some(Color::Named(NamedColor::Red)));
+        let msg = w.last_error().map(ToString::to_string).unwrap_or_default();
+        assert!(msg.contains("line 1, column 12"), "{msg}");
+
+        std::fs::write(&file, "A { color: green }").unwrap();
+        assert!(wait_for(&mut w, |w| color_of(w)
+            == Some(Color::Named(NamedColor::Green))));
+        assert!(w.last_error().is_none());
+    }
+
+    #[test]
+    fn errors_clear_when_their_file_loads_again() {
+        let dir = temp_dir();
+        let (a, b) = (dir.join("a.oxss"), dir.join("b.oxss"));
+        std::fs::write(&a, "A { color: red }").unwrap();
+        std::fs::write(&b, "B { color: blue }").unwrap();
+
+        let mut w = StylesheetWatcher::new([&a, &b]).unwrap();
+        w.poll();
+        std::fs::write(&a, "A { color: rd }").unwrap();
+        assert!(wait_for(&mut w, |w| w.last_error().is_some()));
+
+        // Another file reloading doesn't fix this one.
+        std::fs::write(&b, "B { color: green }").unwrap();
+        assert!(wait_for(&mut w, |w| w
+            .current()
+            .rules
+            .get(1)
+            .and_then(|r| r.style.color)
+            == Some(Color::Named(NamedColor::Green))));
+        assert!(w.last_error().is_some());
+
+        // Undone, the rules are as before and nothing reloads, but the
+        // error is gone.
+        std::fs::write(&a, "A { color: red }").unwrap();
+        assert!(wait_for(&mut w, |w| w.last_error().is_none()));
+        assert_eq!(color_of(&w), Some(Color::Named(NamedColor::Red)));
+    }
+
+    #[test]
+    fn missing_file_is_reported_not_fatal() {
+        let dir = temp_dir();
+        let mut w = StylesheetWatcher::new([dir.join("missing.oxss")]).unwrap();
+        w.poll();
+        assert!(w.current().rules.is_empty());
+        assert!(w.last_error().is_some());
+    }
+}